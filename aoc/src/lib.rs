@@ -0,0 +1,15 @@
+//! Every year's solutions, and the harness checking them against their recorded answers.
+
+use util::aoc::solution::Day;
+
+pub mod regress;
+
+pub fn solutions() -> Vec<Box<dyn Day>> {
+    let mut solutions = aoc2018::solutions();
+    solutions.extend(aoc2019::solutions());
+    solutions.extend(aoc2020::solutions());
+    solutions.extend(aoc2021::solutions());
+    solutions.extend(aoc2022::solutions());
+    solutions.extend(aoc2024::solutions());
+    solutions
+}
@@ -0,0 +1,249 @@
+//! Runs any day of any year.
+//!
+//! ```text
+//! aoc list [YEAR]
+//! aoc run YEAR DAY [--part N]
+//! aoc run YEAR --all [--part N]
+//! aoc bench YEAR (DAY | --all) [--warmup N] [--samples N]
+//! aoc regress [--record] [--timeout SECONDS] [YEAR...]
+//! ```
+//!
+//! `regress` runs every solved day of each year, with this binary, and checks the answers against
+//! the year's `answers.toml`; build in release mode for it. With no years, every `aocYYYY` crate in
+//! the workspace is checked. `--record` writes the answers of days that don't have any recorded yet.
+
+use aoc::regress::{report, Regression};
+use aoc::solutions;
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Duration;
+use util::aoc::bench::{self, BenchConfig, DayBench, History};
+use util::aoc::solution::{self, Day};
+use util::aoc::AocResult;
+use util::{bail, format_err};
+
+const USAGE: &str = "Usage: aoc list [YEAR] | aoc run YEAR (DAY | --all) [--part N] \
+    | aoc bench YEAR (DAY | --all) [--warmup N] [--samples N] \
+    | aoc regress [--record] [--timeout SECONDS] [YEAR...]";
+
+fn main() {
+    match run() {
+        Ok(true) => {}
+        Ok(false) => exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(2);
+        }
+    }
+}
+
+/// The arguments after `run` or `bench`.
+#[derive(Default)]
+struct Selection {
+    year: Option<u32>,
+    day: Option<u32>,
+    all: bool,
+    part: Option<u32>,
+    bench: BenchConfig,
+}
+
+impl Selection {
+    fn parse(args: &[String]) -> AocResult<Selection> {
+        let mut selection = Selection::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format_err!("{} needs a number", arg))
+                    .and_then(|n| parse_number(n))
+            };
+            match arg.as_str() {
+                "--all" => selection.all = true,
+                "--part" => selection.part = Some(value()?),
+                "--warmup" => selection.bench.warmup = value()? as usize,
+                "--samples" => selection.bench.samples = value()? as usize,
+                n if selection.year.is_none() => selection.year = Some(parse_number(n)?),
+                n if selection.day.is_none() => selection.day = Some(parse_number(n)?),
+                n => bail!("Unexpected argument {}\n{}", n, USAGE),
+            }
+        }
+        Ok(selection)
+    }
+
+    /// The selected days, in order.
+    fn days<'a>(&self, solutions: &'a [Box<dyn Day>]) -> AocResult<Vec<&'a dyn Day>> {
+        let year = self.year.ok_or_else(|| format_err!("{}", USAGE))?;
+        let days = match (self.day, self.all) {
+            (Some(day), false) => vec![solution::find(solutions, year, day)
+                .ok_or_else(|| format_err!("There's no solution for {} day {}", year, day))?],
+            (None, true) => solutions
+                .iter()
+                .filter(|solution| solution.year() == year)
+                .sorted_by_key(|solution| solution.day())
+                .map(|solution| solution.as_ref())
+                .collect(),
+            _ => bail!("{}", USAGE),
+        };
+        if days.is_empty() {
+            bail!("There are no solutions for {}", year);
+        }
+        Ok(days)
+    }
+}
+
+/// Whether every day that ran succeeded.
+fn run() -> AocResult<bool> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let solutions = solutions();
+
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            let year = args.get(1).map(|year| parse_number(year)).transpose()?;
+            list(&solutions, year);
+            Ok(true)
+        }
+        Some("run") => {
+            let selection = Selection::parse(&args[1..])?;
+
+            let mut ok = true;
+            for solution in selection.days(&solutions)? {
+                println!("{} day {}", solution.year(), solution.day());
+                if let Err(e) = solution.run(selection.part) {
+                    eprintln!("{} day {}: {}", solution.year(), solution.day(), e);
+                    ok = false;
+                }
+            }
+            Ok(ok)
+        }
+        Some("bench") => {
+            let selection = Selection::parse(&args[1..])?;
+            let days = selection.days(&solutions)?;
+            bench(&days, &selection.bench)
+        }
+        Some("regress") => regress(&args[1..]),
+        _ => bail!("{}", USAGE),
+    }
+}
+
+/// Benchmarks each day, printing how each stage changed since it was last benchmarked and
+/// recording the results against the current commit.
+fn bench(days: &[&dyn Day], config: &BenchConfig) -> AocResult<bool> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let path = workspace.join(bench::HISTORY_FILE);
+    let commit = bench::current_commit(&workspace);
+    let mut history = History::load(&path)?;
+
+    let mut ok = true;
+    for day in days {
+        println!("{} day {}", day.year(), day.day());
+        let measurements = match day.bench(config) {
+            Ok(measurements) => measurements,
+            Err(e) => {
+                eprintln!("{} day {}: {}", day.year(), day.day(), e);
+                ok = false;
+                continue;
+            }
+        };
+
+        for measurement in &measurements {
+            let previous = history.latest(day.year(), day.day(), &measurement.stage);
+            println!("{}", bench::describe(measurement, previous));
+        }
+        history.record(
+            &commit,
+            DayBench {
+                year: day.year(),
+                day: day.day(),
+                measurements,
+            },
+        );
+    }
+
+    history.save(&path)?;
+    Ok(ok)
+}
+
+/// Whether every recorded answer was reproduced.
+fn regress(args: &[String]) -> AocResult<bool> {
+    let mut record = false;
+    let mut timeout = Duration::from_secs(120);
+    let mut years = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--record" => record = true,
+            "--timeout" => {
+                let seconds = args
+                    .next()
+                    .ok_or_else(|| format_err!("--timeout needs a number of seconds"))?;
+                timeout = Duration::from_secs(parse_number(seconds)?.into());
+            }
+            year => years.push(parse_number(year)?),
+        }
+    }
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let crates = if years.is_empty() {
+        year_crates(&workspace)?
+    } else {
+        years
+            .iter()
+            .map(|year| workspace.join(format!("aoc{}", year)))
+            .collect()
+    };
+
+    let runner = std::env::current_exe()?;
+    let mut outcomes = Vec::new();
+    for crate_dir in crates {
+        if !crate_dir.join("Cargo.toml").is_file() {
+            bail!("{} isn't a crate", crate_dir.display());
+        }
+        let regression = Regression::new(&runner, crate_dir)?.with_timeout(timeout);
+        if record {
+            outcomes.extend(regression.record()?);
+        } else {
+            outcomes.extend(regression.check()?);
+        }
+    }
+
+    println!("{}", report(&outcomes));
+    Ok(!outcomes.iter().any(|outcome| outcome.is_regression()))
+}
+
+fn year_crates(workspace: &Path) -> AocResult<Vec<PathBuf>> {
+    let mut crates = Vec::new();
+    for entry in std::fs::read_dir(workspace)? {
+        let path = entry?.path();
+        let is_year = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("aoc"))
+            .is_some_and(|year| year.parse::<u32>().is_ok());
+        if is_year && path.join("Cargo.toml").is_file() {
+            crates.push(path);
+        }
+    }
+    crates.sort();
+    Ok(crates)
+}
+
+/// Prints each year's days, one year per line.
+fn list(solutions: &[Box<dyn Day>], year: Option<u32>) {
+    let by_year = solutions
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year() == year))
+        .map(|solution| (solution.year(), solution.day()))
+        .sorted()
+        .into_group_map();
+
+    for (year, days) in by_year.into_iter().sorted() {
+        println!("{}: {}", year, days.iter().join(" "));
+    }
+}
+
+fn parse_number(text: &str) -> AocResult<u32> {
+    text.parse()
+        .map_err(|_| format_err!("Expected a number, got {}\n{}", text, USAGE))
+}
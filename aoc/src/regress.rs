@@ -0,0 +1,407 @@
+//! Runs every solved day of a year and checks its answers against the year's `answers.toml`.
+
+use std::fmt::Write;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use util::aoc::answers::{Answers, ANSWERS_FILE};
+use util::aoc::AocResult;
+use util::{bail, format_err};
+
+/// One part's answer as printed by `util::aoc::result`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: u32,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The day printed an answer that hasn't been recorded yet.
+    Unrecorded,
+    /// An answer is recorded but the day didn't print one.
+    Missing,
+    /// The day didn't build, crashed or timed out.
+    Error(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub expected: Option<String>,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+}
+
+impl Outcome {
+    /// Whether a recorded answer is no longer produced.
+    pub fn is_regression(&self) -> bool {
+        self.expected.is_some() && self.status != Status::Pass
+    }
+}
+
+/// Runs each day of a year's crate through an `aoc` runner, compares what it prints against the
+/// crate's `answers.toml`.
+#[derive(Clone, Debug)]
+pub struct Regression {
+    runner: PathBuf,
+    crate_dir: PathBuf,
+    year: u32,
+    timeout: Duration,
+}
+
+impl Regression {
+    /// A harness running days with the `aoc` binary at `runner`, for the crate in `crate_dir`,
+    /// which must be named `aoc{year}`.
+    pub fn new(runner: impl Into<PathBuf>, crate_dir: impl Into<PathBuf>) -> AocResult<Regression> {
+        let crate_dir = crate_dir.into();
+        let year = crate_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("aoc"))
+            .and_then(|year| year.parse().ok())
+            .ok_or_else(|| format_err!("{} isn't an aocYYYY crate", crate_dir.display()))?;
+
+        Ok(Regression {
+            runner: runner.into(),
+            crate_dir,
+            year,
+            timeout: Duration::from_secs(120),
+        })
+    }
+
+    /// How long each day may run before it's killed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.crate_dir.join(ANSWERS_FILE)
+    }
+
+    /// The days the runner has a solution for, in order.
+    pub fn days(&self) -> AocResult<Vec<u32>> {
+        let output = Command::new(&self.runner)
+            .args(["list", &self.year.to_string()])
+            .output()?;
+        if !output.status.success() {
+            bail!("aoc list exited with {}", output.status);
+        }
+
+        let listing = String::from_utf8_lossy(&output.stdout);
+        let days = listing
+            .lines()
+            .filter_map(|line| line.strip_prefix(&format!("{}:", self.year)))
+            .flat_map(|days| days.split_whitespace())
+            .map(|day| day.parse::<u32>())
+            .collect::<Result<_, _>>()?;
+        Ok(days)
+    }
+
+    /// Runs a day, returning the parts it printed.
+    pub fn run_day(&self, day: u32) -> AocResult<Vec<PartRun>> {
+        let mut child = Command::new(&self.runner)
+            .args(["run", &self.year.to_string(), &day.to_string()])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain stdout as it's written, so a chatty day can't fill the pipe and stall.
+        let mut stdout = child.stdout.take().unwrap();
+        let reader = thread::spawn(move || {
+            let mut output = Vec::new();
+            stdout.read_to_end(&mut output).map(|_| output)
+        });
+        let mut stderr = child.stderr.take().unwrap();
+        let errors = thread::spawn(move || {
+            let mut output = String::new();
+            stderr.read_to_string(&mut output).map(|_| output)
+        });
+
+        let start = Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if start.elapsed() > self.timeout {
+                child.kill()?;
+                // Reap it, and its output threads, which finish once the pipes close.
+                let _ = child.wait();
+                let _ = reader.join();
+                let _ = errors.join();
+                bail!("timed out after {:?}", self.timeout);
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        if !status.success() {
+            let errors = errors.join().unwrap()?;
+            match errors.lines().last() {
+                Some(error) => bail!("{}", error),
+                None => bail!("exited with {}", status),
+            }
+        }
+
+        let output = reader.join().unwrap()?;
+        Ok(parse_output(&String::from_utf8_lossy(&output)))
+    }
+
+    /// Runs every day and compares each part against the recorded answers.
+    pub fn check(&self) -> AocResult<Vec<Outcome>> {
+        let answers = Answers::load(&self.answers_path())?;
+        let mut days = self.days()?;
+        days.extend(answers.days());
+        days.sort_unstable();
+        days.dedup();
+
+        let mut outcomes = Vec::new();
+        for day in days {
+            let runs = self.run_day(day);
+            for part in 1..=2 {
+                let expected = answers.get(day, part).map(String::from);
+                let run = runs
+                    .as_ref()
+                    .ok()
+                    .and_then(|runs| runs.iter().find(|run| run.part == part));
+
+                let status = match (&runs, run, &expected) {
+                    (Err(error), _, _) => Status::Error(error.to_string()),
+                    (_, Some(run), Some(expected)) if &run.answer == expected => Status::Pass,
+                    (_, Some(_), Some(_)) => Status::Fail,
+                    (_, Some(_), None) => Status::Unrecorded,
+                    (_, None, Some(_)) => Status::Missing,
+                    (_, None, None) => continue,
+                };
+                outcomes.push(Outcome {
+                    year: self.year,
+                    day,
+                    part,
+                    status,
+                    expected,
+                    answer: run.map(|run| run.answer.clone()),
+                    elapsed: run.map(|run| run.elapsed),
+                });
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Runs every day and records the answers that aren't recorded yet, leaving existing answers
+    /// alone. Returns the outcomes from before recording.
+    pub fn record(&self) -> AocResult<Vec<Outcome>> {
+        let outcomes = self.check()?;
+        let mut answers = Answers::load(&self.answers_path())?;
+        for outcome in &outcomes {
+            if let (Status::Unrecorded, Some(answer)) = (&outcome.status, &outcome.answer) {
+                answers.set(outcome.day, outcome.part, answer.clone());
+            }
+        }
+        answers.save(&self.answers_path())?;
+        Ok(outcomes)
+    }
+}
+
+/// Picks out the `Part N: <elapsed>` / ` => <answer>` pairs printed by `util::aoc::result`. An
+/// answer printed on the lines after an empty ` => `, like a grid of letters, runs until the next
+/// blank or `Part` line.
+pub fn parse_output(stdout: &str) -> Vec<PartRun> {
+    let mut runs = Vec::new();
+    let mut lines = stdout.lines().peekable();
+    while let Some(line) = lines.next() {
+        let timing = line
+            .strip_prefix("Part ")
+            .and_then(|rest| rest.split_once(": "));
+        if let Some((part, elapsed)) = timing {
+            let mut answer = lines
+                .next()
+                .and_then(|line| line.strip_prefix(" =>"))
+                .map(normalize_answer);
+            if answer.as_deref() == Some("") {
+                let mut block = Vec::new();
+                while let Some(line) =
+                    lines.next_if(|line| !line.trim().is_empty() && !line.starts_with("Part "))
+                {
+                    block.push(line);
+                }
+                answer = Some(block.join("\n"));
+            }
+
+            if let (Ok(part), Some(elapsed), Some(answer)) =
+                (part.parse(), parse_duration(elapsed), answer)
+            {
+                runs.push(PartRun {
+                    part,
+                    answer,
+                    elapsed,
+                });
+            }
+        }
+    }
+    runs
+}
+
+/// Strips the `Ok(..)`, `Some(..)` and quotes that `Debug` wraps around answers, so `Ok(42)`,
+/// `Some("abc")` and `"abc"` are recorded as `42`, `abc` and `abc`.
+pub fn normalize_answer(answer: &str) -> String {
+    let mut answer = answer.trim();
+    loop {
+        let inner = ["Ok(", "Some("].iter().find_map(|wrapper| {
+            answer
+                .strip_prefix(wrapper)
+                .and_then(|rest| rest.strip_suffix(')'))
+        });
+        match inner {
+            Some(inner) => answer = inner,
+            None => break,
+        }
+    }
+
+    match answer
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        Some(unquoted) => unquoted.to_string(),
+        None => answer.to_string(),
+    }
+}
+
+/// Parses a `Duration`'s `Debug` output, such as `1.5ms` or `12.3µs`.
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let split = text.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = text.split_at(split);
+    let value: f64 = value.parse().ok()?;
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "µs" | "us" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Lays the outcomes out as a table, with a summary line.
+pub fn report(outcomes: &[Outcome]) -> String {
+    let mut table = format!(
+        "{:<6}{:>4}{:>6}  {:<12}{:>12}  {}\n",
+        "year", "day", "part", "status", "time", "answer"
+    );
+    for outcome in outcomes {
+        // Keep multi-line answers on their row.
+        let answer = outcome
+            .answer
+            .as_deref()
+            .unwrap_or_default()
+            .replace('\n', "\\n");
+        let expected = outcome
+            .expected
+            .as_deref()
+            .unwrap_or_default()
+            .replace('\n', "\\n");
+        let (status, detail) = match &outcome.status {
+            Status::Pass => ("pass", answer),
+            Status::Fail => ("FAIL", format!("{} (expected {})", answer, expected)),
+            Status::Unrecorded => ("unrecorded", answer),
+            Status::Missing => ("MISSING", format!("(expected {})", expected)),
+            Status::Error(error) if outcome.is_regression() => ("ERROR", error.clone()),
+            Status::Error(error) => ("error", error.clone()),
+        };
+        let elapsed = outcome
+            .elapsed
+            .map(|elapsed| format!("{:.1?}", elapsed))
+            .unwrap_or_default();
+        writeln!(
+            table,
+            "{:<6}{:>4}{:>6}  {:<12}{:>12}  {}",
+            outcome.year, outcome.day, outcome.part, status, elapsed, detail
+        )
+        .unwrap();
+    }
+
+    let count = |f: fn(&Outcome) -> bool| outcomes.iter().filter(|o| f(o)).count();
+    write!(
+        table,
+        "{} passed, {} regressed, {} unrecorded",
+        count(|o| o.status == Status::Pass),
+        count(Outcome::is_regression),
+        count(|o| o.status == Status::Unrecorded)
+    )
+    .unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_printed_results() {
+        let stdout = "Parse: 1.2ms\nPart 1: 318ns\n => Ok(442)\nNot a result\n\
+                      Part 2: 2.5s\n => Some(\"xpysn\")\nPart 3: 1s\n => \n#..\n.#.\n\nDone\n";
+
+        assert_eq!(
+            vec![
+                PartRun {
+                    part: 1,
+                    answer: "442".to_string(),
+                    elapsed: Duration::from_nanos(318),
+                },
+                PartRun {
+                    part: 2,
+                    answer: "xpysn".to_string(),
+                    elapsed: Duration::from_millis(2500),
+                },
+                PartRun {
+                    part: 3,
+                    answer: "#..\n.#.".to_string(),
+                    elapsed: Duration::from_secs(1),
+                },
+            ],
+            parse_output(stdout)
+        );
+
+        assert_eq!("Halt(3765464)", normalize_answer("Halt(3765464)"));
+        assert_eq!("(1, 2)", normalize_answer("Some((1, 2))"));
+        assert_eq!(Some(Duration::from_micros(12)), parse_duration("12µs"));
+        assert_eq!(None, parse_duration("soon"));
+    }
+
+    #[test]
+    fn regressions() {
+        let outcome = |status, expected: Option<&str>| Outcome {
+            year: 2021,
+            day: 1,
+            part: 1,
+            status,
+            expected: expected.map(String::from),
+            answer: Some("1".to_string()),
+            elapsed: None,
+        };
+
+        assert!(!outcome(Status::Pass, Some("1")).is_regression());
+        assert!(!outcome(Status::Unrecorded, None).is_regression());
+        assert!(!outcome(Status::Error("doesn't build".to_string()), None).is_regression());
+        assert!(outcome(Status::Error("timed out".to_string()), Some("1")).is_regression());
+        let fail = outcome(Status::Fail, Some("2"));
+        assert!(fail.is_regression());
+
+        let report = report(&[fail]);
+        assert!(report.contains("FAIL"));
+        assert!(report.contains("1 (expected 2)"));
+        assert!(report.ends_with("0 passed, 1 regressed, 0 unrecorded"));
+    }
+}
@@ -0,0 +1,41 @@
+//! Runs every day of each year and checks it still prints the answers in the year's
+//! `answers.toml`. This takes minutes, so it only runs when asked for, in release mode:
+//! `cargo test --release -p aoc -- --ignored`.
+
+use aoc::regress::{report, Regression};
+use std::path::Path;
+
+fn check(year: u32) {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../aoc{}", year));
+    let regression = Regression::new(env!("CARGO_BIN_EXE_aoc"), crate_dir).unwrap();
+    let outcomes = regression.check().unwrap();
+    let report = report(&outcomes);
+    println!("{}", report);
+
+    assert!(
+        !outcomes.iter().any(|outcome| outcome.is_regression()),
+        "{}",
+        report
+    );
+}
+
+macro_rules! recorded_answers {
+    ($($name:ident: $year:literal),*) => {
+        $(
+            #[test]
+            #[ignore = "runs every day; use `cargo test --release -p aoc -- --ignored` or `aoc regress`"]
+            fn $name() {
+                check($year);
+            }
+        )*
+    };
+}
+
+recorded_answers!(
+    answers_2018: 2018,
+    answers_2019: 2019,
+    answers_2020: 2020,
+    answers_2021: 2021,
+    answers_2022: 2022,
+    answers_2024: 2024
+);
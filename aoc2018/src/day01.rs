@@ -0,0 +1,43 @@
+use std::collections::hash_set::HashSet;
+use util::aoc::*;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 1;
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(frequency_deltas: &Vec<i32>) -> AocResult<i32> {
+        Ok(part1(frequency_deltas))
+    }
+
+    fn part2(frequency_deltas: &Vec<i32>) -> AocResult<i32> {
+        Ok(part2(frequency_deltas))
+    }
+}
+
+fn part1(frequency_deltas: &[i32]) -> i32 {
+    frequency_deltas.iter().sum()
+}
+
+fn part2(frequency_deltas: &[i32]) -> i32 {
+    let mut frequencies_seen: HashSet<i32> = HashSet::new();
+    let mut frequency = 0;
+    for delta in frequency_deltas.iter().cycle() {
+        if !frequencies_seen.insert(frequency) {
+            return frequency;
+        }
+
+        frequency += delta;
+    }
+
+    0
+}
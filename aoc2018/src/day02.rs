@@ -1,25 +1,39 @@
 use std::collections::hash_map::HashMap;
 use util::aoc::*;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(2)?;
+pub struct Day02;
 
-    result("Part 1", || part1(&lines));
-    result("Part 2", || part2(&lines));
+impl Solution for Day02 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 2;
 
-    Ok(())
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<u32> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Vec<String>) -> AocResult<String> {
+        part2(lines).ok_or_else(|| AocError::no_solution("No two IDs differ by one character"))
+    }
 }
 
-fn part1(lines: &Vec<String>) -> u32 {
+fn part1(lines: &[String]) -> u32 {
     let counts = lines
         .iter()
-        .map(checksum)
+        .map(|box_id| checksum(box_id))
         .fold((0, 0), |acc, c| (acc.0 + c.0, acc.1 + c.1));
 
     counts.0 * counts.1
 }
 
-fn checksum(box_id: &String) -> (u32, u32) {
+fn checksum(box_id: &str) -> (u32, u32) {
     let mut frequency_map: HashMap<char, u32> = HashMap::new();
 
     for ch in box_id.chars() {
@@ -57,7 +71,7 @@ fn part2(lines: &Vec<String>) -> Option<String> {
     None
 }
 
-fn edited_string(str1: &String, str2: &String) -> Option<String> {
+fn edited_string(str1: &str, str2: &str) -> Option<String> {
     let mut s1_chars = str1.chars();
     let mut s2_chars = str2.chars();
 
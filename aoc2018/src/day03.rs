@@ -0,0 +1,73 @@
+use lazy_static::*;
+use regex::Regex;
+use util::aoc::boxes::Rect;
+use util::aoc::*;
+use util::format_err;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new("#(\\d+) @ (\\d+),(\\d+): (\\d+)x(\\d+)").unwrap();
+}
+
+#[derive(Debug)]
+pub struct Claim {
+    id: String,
+    area: Rect,
+}
+
+impl Claim {
+    fn parse(claim_str: &str) -> Result<Claim, AocError> {
+        if let Some(captures) = RE.captures(claim_str) {
+            return Ok(Claim {
+                id: captures[1].to_owned(),
+                area: Rect::from_size(
+                    captures[2].parse()?,
+                    captures[3].parse()?,
+                    captures[4].parse()?,
+                    captures[5].parse()?,
+                ),
+            });
+        }
+
+        Err(format_err!("Couldn't parse claim: {}", claim_str))
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 3;
+
+    type Input = Vec<Claim>;
+    type Output1 = i64;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<Vec<Claim>> {
+        parsing::parse_lines_with(input, Claim::parse)
+    }
+
+    fn part1(claims: &Vec<Claim>) -> AocResult<i64> {
+        Ok(part1(claims))
+    }
+
+    fn part2(claims: &Vec<Claim>) -> AocResult<String> {
+        part2(claims).ok_or_else(|| AocError::no_solution("Every claim overlaps another"))
+    }
+}
+
+// How many square inches of fabric are within two or more claims?
+fn part1(claims: &[Claim]) -> i64 {
+    Rect::covered_volume(claims.iter().map(|claim| &claim.area), 2)
+}
+
+// What is the ID of the only claim that doesn't overlap?
+fn part2(claims: &[Claim]) -> Option<String> {
+    claims
+        .iter()
+        .find(|claim| {
+            claims
+                .iter()
+                .all(|other| claim.id == other.id || !claim.area.intersects(&other.area))
+        })
+        .map(|claim| claim.id.to_string())
+}
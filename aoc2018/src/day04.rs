@@ -1,10 +1,9 @@
 use chrono::{NaiveDateTime, Timelike};
-use failure::*;
-use itertools::Itertools;
 use lazy_static::*;
 use regex::Regex;
 use std::collections::hash_map::HashMap;
 use util::aoc::*;
+use util::format_err;
 
 lazy_static! {
     static ref RE_SHIFT: Regex = Regex::new("\\[(.+)\\] Guard #(\\d+) begins shift").unwrap();
@@ -30,8 +29,8 @@ impl Event {
         self.timestamp.minute()
     }
 
-    fn parse(event_str: &String) -> Result<Event, Error> {
-        if let Some(captures) = RE_SHIFT.captures(event_str.as_str()) {
+    fn parse(event_str: &str) -> Result<Event, AocError> {
+        if let Some(captures) = RE_SHIFT.captures(event_str) {
             let timestamp = Event::parse_time(&captures[1])?;
             let event = Event {
                 timestamp,
@@ -40,7 +39,7 @@ impl Event {
 
             return Ok(event);
         }
-        if let Some(captures) = RE_WAKES.captures(event_str.as_str()) {
+        if let Some(captures) = RE_WAKES.captures(event_str) {
             let timestamp = Event::parse_time(&captures[1])?;
             let event = Event {
                 timestamp,
@@ -49,7 +48,7 @@ impl Event {
 
             return Ok(event);
         }
-        if let Some(captures) = RE_SLEEP.captures(event_str.as_str()) {
+        if let Some(captures) = RE_SLEEP.captures(event_str) {
             let timestamp = Event::parse_time(&captures[1])?;
             let event = Event {
                 timestamp,
@@ -62,18 +61,18 @@ impl Event {
         Err(format_err!("Couldn't parse event: {}", event_str))
     }
 
-    fn parse_time(time_str: &str) -> Result<NaiveDateTime, chrono::ParseError> {
-        NaiveDateTime::parse_from_str(time_str, "%Y-%m-%d %H:%M")
+    fn parse_time(time_str: &str) -> AocResult<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(time_str, "%Y-%m-%d %H:%M").map_err(AocError::parse)
     }
 }
 
 #[derive(Debug, Default)]
-struct SolutionState {
+pub struct SolutionState {
     guard_to_minute_frequency: HashMap<u32, HashMap<u32, u32>>,
 }
 
 impl SolutionState {
-    fn compute_state(events: &Vec<Event>) -> Result<SolutionState, Error> {
+    fn compute_state(events: &Vec<Event>) -> Result<SolutionState, AocError> {
         let mut state = SolutionState::default();
 
         let mut guard_on_shift = 0u32;
@@ -111,23 +110,29 @@ impl SolutionState {
     }
 }
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(4)?;
-    let events: Vec<Event> = time("Parse events", || {
-        lines
-            .iter()
-            .map(Event::parse)
-            .collect::<Result<Vec<_>, _>>()
-    })?;
-    let events = events
-        .into_iter()
-        .sorted_by_key(|e| e.timestamp.timestamp());
-    let state = time("Compute state", || SolutionState::compute_state(&events))?;
-
-    result("Part 1", || part1(&state));
-    result("Part 2", || part2(&state));
-
-    Ok(())
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 4;
+
+    type Input = SolutionState;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<SolutionState> {
+        let mut events: Vec<Event> = parsing::parse_lines_with(input, Event::parse)?;
+        events.sort_by_key(|e| e.timestamp.and_utc().timestamp());
+        SolutionState::compute_state(&events)
+    }
+
+    fn part1(state: &SolutionState) -> AocResult<u32> {
+        Ok(part1(state))
+    }
+
+    fn part2(state: &SolutionState) -> AocResult<u32> {
+        Ok(part2(state))
+    }
 }
 
 // Strategy 1: Find the guard that has the most minutes asleep.
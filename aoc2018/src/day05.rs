@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use util::aoc::*;
 
-fn main() -> Result<(), Box<std::error::Error>> {
+pub fn main() -> Result<(), Box<std::error::Error>> {
     let lines: Vec<String> = input::read(5)?;
     let string = &lines[0];
     //    let string = &"dacAcCaCBAcCcaDA".to_string();
@@ -1,26 +1,39 @@
-use failure::*;
 use itertools::Itertools;
-use std::cmp::Ordering;
 use std::collections::HashMap;
 use util::aoc::top_k::TopK;
 use util::aoc::*;
+use util::format_err;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(6)?;
-    let points = parse(&lines)?;
-    result("Part 1", || part1(&points));
-    result("Part 2", || part2(&points));
+pub struct Day06;
 
-    Ok(())
+impl Solution for Day06 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 6;
+
+    type Input = Vec<Point>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<Vec<Point>> {
+        parse(&input.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    fn part1(points: &Vec<Point>) -> AocResult<u32> {
+        Ok(part1(points))
+    }
+
+    fn part2(points: &Vec<Point>) -> AocResult<u32> {
+        Ok(part2(points))
+    }
 }
 
-fn parse(lines: &Vec<String>) -> Result<Vec<Point>, Error> {
+fn parse(lines: &[String]) -> Result<Vec<Point>, AocError> {
     lines
         .iter()
         .map(|line| {
             let pieces: Vec<&str> = line.split(", ").collect();
             let x = pieces
-                .get(0)
+                .first()
                 .ok_or(format_err!("Invalid coordinate."))?
                 .parse::<i32>()?;
             let y = pieces
@@ -33,7 +46,7 @@ fn parse(lines: &Vec<String>) -> Result<Vec<Point>, Error> {
         .collect()
 }
 
-fn part1(reference_points: &Vec<Point>) -> u32 {
+fn part1(reference_points: &[Point]) -> u32 {
     // Find the bounds
     let (x_min, x_max) = reference_points
         .iter()
@@ -54,7 +67,7 @@ fn part1(reference_points: &Vec<Point>) -> u32 {
             // Find closest point
             let point = Point::new(x, y);
             if let Some(closest_point) = closest_to(&point, reference_points) {
-                let count = area.entry(closest_point.clone()).or_default();
+                let count = area.entry(*closest_point).or_default();
                 *count += 1;
             }
         }
@@ -66,7 +79,7 @@ fn part1(reference_points: &Vec<Point>) -> u32 {
             // Find closest point
             let point = Point::new(x, *y);
             if let Some(closest_point) = closest_to(&point, reference_points) {
-                area.remove(&closest_point);
+                area.remove(closest_point);
             }
         }
     }
@@ -75,7 +88,7 @@ fn part1(reference_points: &Vec<Point>) -> u32 {
             // Find closest point
             let point = Point::new(*x, y);
             if let Some(closest_point) = closest_to(&point, reference_points) {
-                area.remove(&closest_point);
+                area.remove(closest_point);
             }
         }
     }
@@ -83,7 +96,7 @@ fn part1(reference_points: &Vec<Point>) -> u32 {
     *area.iter().max_by_key(|(_, &area)| area).unwrap().1
 }
 
-fn part2(reference_points: &Vec<Point>) -> u32 {
+fn part2(reference_points: &[Point]) -> u32 {
     // Find the bounds
     let (x_min, x_max) = reference_points
         .iter()
@@ -106,13 +119,7 @@ fn part2(reference_points: &Vec<Point>) -> u32 {
             // Add up the distances.
             let summed_distance: i32 = reference_points
                 .iter()
-                .map(|p| {
-                    ComparablePoint {
-                        point: &point,
-                        reference: p,
-                    }
-                    .manhattan_distance()
-                })
+                .map(|p| point.manhattan_distance(p))
                 .sum();
 
             if summed_distance < 10_000 {
@@ -124,46 +131,15 @@ fn part2(reference_points: &Vec<Point>) -> u32 {
     points_in_region
 }
 
-fn closest_to<'a, 'b>(point: &'a Point, reference_points: &'b Vec<Point>) -> Option<&'b Point> {
-    let top = reference_points
+fn closest_to<'a>(point: &Point, reference_points: &'a [Point]) -> Option<&'a Point> {
+    let closest = reference_points
         .iter()
-        .map(|p| ComparablePoint {
-            point,
-            reference: p,
-        })
-        .topk(2);
+        .bottomk_by_key(2, |p| point.manhattan_distance(p));
 
-    match top.as_slice() {
-        [first, second] if first.manhattan_distance() < second.manhattan_distance() => {
-            Some(first.reference)
+    match closest.as_slice() {
+        [first, second] if point.manhattan_distance(first) < point.manhattan_distance(second) => {
+            Some(first)
         }
         _ => None,
     }
 }
-
-#[derive(Debug, Eq, PartialEq)]
-struct ComparablePoint<'a, 'b> {
-    point: &'b Point,
-    reference: &'a Point,
-}
-
-impl<'a, 'b> ComparablePoint<'a, 'b> {
-    fn manhattan_distance(&self) -> i32 {
-        self.point.manhattan_distance(self.reference)
-    }
-}
-
-impl<'a, 'b> Ord for ComparablePoint<'a, 'b> {
-    fn cmp(&self, other: &ComparablePoint) -> Ordering {
-        let lhs_dist = self.manhattan_distance();
-        let rhs_dist = other.manhattan_distance();
-
-        lhs_dist.cmp(&rhs_dist)
-    }
-}
-
-impl<'a, 'b> PartialOrd for ComparablePoint<'a, 'b> {
-    fn partial_cmp(&self, other: &ComparablePoint) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
@@ -1,11 +1,10 @@
-use failure::*;
 use lazy_static::*;
 use regex::Regex;
-use std::cmp::Ordering;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use util::aoc::*;
+use util::format_err;
 
 lazy_static! {
     static ref RE_STEP: Regex =
@@ -14,29 +13,44 @@ lazy_static! {
 
 type Step = char;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(7)?;
+/// Every step, and the steps each one depends on.
+type Graph = (BTreeSet<Step>, HashMap<Step, BTreeSet<Step>>);
 
-    let mut dependency_graph: HashMap<Step, BTreeSet<Step>> = HashMap::new();
-    let mut all_nodes: BTreeSet<Step> = BTreeSet::new();
-    for edge in lines.iter().map(parse) {
-        let edge = edge?;
+pub struct Day07;
 
-        all_nodes.insert(edge.0);
-        all_nodes.insert(edge.1);
+impl Solution for Day07 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 7;
 
-        // Creates a "step depends on" graph.
-        dependency_graph.entry(edge.1).or_default().insert(edge.0);
+    type Input = Graph;
+    type Output1 = String;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<Graph> {
+        let mut dependency_graph: HashMap<Step, BTreeSet<Step>> = HashMap::new();
+        let mut all_nodes: BTreeSet<Step> = BTreeSet::new();
+        for edge in parsing::parse_lines_with(input, parse)? {
+            all_nodes.insert(edge.0);
+            all_nodes.insert(edge.1);
+
+            // Creates a "step depends on" graph.
+            dependency_graph.entry(edge.1).or_default().insert(edge.0);
+        }
+
+        Ok((all_nodes, dependency_graph))
     }
 
-    result("Part 1", || part1(&all_nodes, &dependency_graph));
-    result("Part 2", || part2(&all_nodes, &dependency_graph));
+    fn part1((all_nodes, dependency_graph): &Graph) -> AocResult<String> {
+        Ok(part1(all_nodes, dependency_graph))
+    }
 
-    Ok(())
+    fn part2((all_nodes, dependency_graph): &Graph) -> AocResult<u32> {
+        Ok(part2(all_nodes, dependency_graph))
+    }
 }
 
-fn parse(line: &String) -> Result<(Step, Step), Error> {
-    if let Some(captures) = RE_STEP.captures(line.as_str()) {
+fn parse(line: &str) -> Result<(Step, Step), AocError> {
+    if let Some(captures) = RE_STEP.captures(line) {
         Ok((
             captures[1]
                 .chars()
@@ -99,21 +113,20 @@ fn part2(all_nodes: &BTreeSet<Step>, dependency_graph: &HashMap<Step, BTreeSet<S
         // Check all steps to see which ones can run.
         for step in all_nodes {
             // Skip it if we've seen it.
-            if complete.contains(&step) {
+            if complete.contains(step) {
                 continue;
             }
 
             // A step can run if all of its parents are seen (or it has no parents).
-            if !dependency_graph.contains_key(step)
+            if (!dependency_graph.contains_key(step)
                 || dependency_graph
                     .get(step)
                     .unwrap()
                     .iter()
-                    .all(|s| complete.contains(s))
+                    .all(|s| complete.contains(s)))
+                && !worker_pool.running(step)
             {
-                if !worker_pool.running(step) {
-                    worker_pool.start(*step);
-                }
+                worker_pool.start(*step);
             }
         }
 
@@ -205,18 +218,13 @@ impl WorkerPool {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
 enum CompletionTime {
+    #[default]
     Idle,
     CompleteAt(u32),
 }
 
-impl Default for CompletionTime {
-    fn default() -> Self {
-        CompletionTime::Idle
-    }
-}
-
 #[derive(Debug, Eq, PartialEq, Hash, Default)]
 struct Worker {
     task: Option<Step>,
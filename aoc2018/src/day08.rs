@@ -1,23 +1,35 @@
-use failure::*;
 use util::aoc::*;
+use util::format_err;
 
 type MetadataEntry = u32;
 
 #[derive(Debug, Default)]
-struct Node {
+pub struct Node {
     child_nodes: Vec<Node>,
     metadata_entry: Vec<MetadataEntry>,
 }
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(8)?;
-    let header = parse(&lines[0])?;
-    println!("{:?}", header);
+pub struct Day08;
 
-    result("Part 1", || part1(&header));
-    result("Part 2", || part2(&header));
+impl Solution for Day08 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 8;
 
-    Ok(())
+    type Input = Node;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<Node> {
+        parse(input.trim())
+    }
+
+    fn part1(header: &Node) -> AocResult<u32> {
+        Ok(part1(header))
+    }
+
+    fn part2(header: &Node) -> AocResult<u32> {
+        Ok(part2(header))
+    }
 }
 
 fn part1(header: &Node) -> u32 {
@@ -55,7 +67,7 @@ A----------------------------------
     B----------- C-----------
                      D-----
 */
-fn parse(line: &str) -> Result<Node, Error> {
+fn parse(line: &str) -> Result<Node, AocError> {
     let pieces: Vec<u32> = line
         .split(' ')
         .map(|p| p.parse::<u32>())
@@ -64,8 +76,8 @@ fn parse(line: &str) -> Result<Node, Error> {
     Ok(parse_header(&pieces)?.0)
 }
 
-fn parse_header(chunk: &[u32]) -> Result<(Node, usize), Error> {
-    let num_child_nodes = *chunk.get(0).ok_or_else(|| format_err!("Bad chunk"))?;
+fn parse_header(chunk: &[u32]) -> Result<(Node, usize), AocError> {
+    let num_child_nodes = *chunk.first().ok_or_else(|| format_err!("Bad chunk"))?;
     let num_metadata_nodes = *chunk.get(1).ok_or_else(|| format_err!("Bad chunk"))?;
 
     let mut node = Node::default();
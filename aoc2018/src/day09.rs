@@ -1,16 +1,43 @@
-use failure::*;
+use lazy_static::*;
+use regex::Regex;
 use std::collections::HashMap;
 use util::aoc::linked_list::*;
 use util::aoc::*;
+use util::format_err;
+
+lazy_static! {
+    static ref RE: Regex =
+        Regex::new("(\\d+) players; last marble is worth (\\d+) points").unwrap();
+}
 
 type Marble = u32;
 type Elf = u32;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    result("Part 1", || part1(459, 72103));
-    result("Part 2", || part1(459, 72103 * 100));
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 9;
+
+    /// The number of players and the value of the last marble.
+    type Input = (u32, Marble);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<(u32, Marble)> {
+        let captures = RE
+            .captures(input.trim())
+            .ok_or_else(|| format_err!("Couldn't parse game: {}", input.trim()))?;
+        Ok((captures[1].parse()?, captures[2].parse()?))
+    }
+
+    fn part1(&(players, last_marble): &(u32, Marble)) -> AocResult<u32> {
+        Ok(part1(players, last_marble))
+    }
 
-    Ok(())
+    fn part2(&(players, last_marble): &(u32, Marble)) -> AocResult<u32> {
+        Ok(part1(players, last_marble * 100))
+    }
 }
 
 fn part1(players: u32, last_marble: Marble) -> u32 {
@@ -32,7 +59,7 @@ struct GameState {
 }
 
 impl GameState {
-    fn new(players: u32, max_marbles: Marble) -> GameState {
+    fn new(_players: u32, _max_marbles: Marble) -> GameState {
         let mut linked_list = UsefulLinkedList::new();
         let idx = linked_list.push_back(0);
 
@@ -66,13 +93,11 @@ impl GameState {
         // the current player's score. The marble located immediately clockwise of the marble that
         // was removed becomes the new current marble.
 
-        if self.current_marble % 23 == 0 {
+        if self.current_marble.is_multiple_of(23) {
             // Find marble 7 steps back.
-            for _ in 0..7 {
-                self.current_marble_idx = self.prev_index();
-            }
+            self.current_marble_idx = self.marbles.step(self.current_marble_idx, -7);
 
-            let next_marble_idx = self.next_index();
+            let next_marble_idx = self.marbles.next_circular(self.current_marble_idx);
 
             // Remove that marble.
             let marble = self.marbles.remove(self.current_marble_idx);
@@ -83,9 +108,10 @@ impl GameState {
 
             self.current_marble_idx = next_marble_idx;
         } else {
-            self.current_marble_idx = self
-                .marbles
-                .insert_after(self.next_index(), self.current_marble);
+            self.current_marble_idx = self.marbles.insert_after(
+                self.marbles.next_circular(self.current_marble_idx),
+                self.current_marble,
+            );
         }
 
         self.current_marble += 1;
@@ -94,24 +120,9 @@ impl GameState {
     fn high_score(&self) -> (&Elf, &u32) {
         self.scores.iter().max_by_key(|(_, &score)| score).unwrap()
     }
-
-    // Finds the next index to insert after by looking at the "next" pointer.
-    fn next_index(&self) -> Idx {
-        match self.marbles[self.current_marble_idx].next {
-            None => self.marbles.head().unwrap(),
-            Some(next) => next,
-        }
-    }
-
-    // Finds the next index to insert after by looking at the "next" pointer.
-    fn prev_index(&self) -> Idx {
-        match self.marbles[self.current_marble_idx].prev {
-            None => self.marbles.tail().unwrap(),
-            Some(prev) => prev,
-        }
-    }
 }
 
+#[cfg(test)]
 mod test {
     #[test]
     fn test() {
@@ -1,9 +1,9 @@
-use failure::*;
 use itertools::Itertools;
 use lazy_static::*;
 use regex::Regex;
 use std::collections::HashMap;
 use util::aoc::*;
+use util::format_err;
 
 // position=<-6, 10> velocity=< 2, -2>
 lazy_static! {
@@ -15,14 +15,14 @@ lazy_static! {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Light {
+pub struct Light {
     position: Point,
     velocity: Point,
 }
 
 impl Light {
     fn step(&mut self) {
-        self.position = self.position + self.velocity;
+        self.position += self.velocity;
     }
 }
 
@@ -44,7 +44,7 @@ impl Sky {
         // Re-index lights by point.
         self.points.clear();
 
-        let mut points = &mut self.points;
+        let points = &mut self.points;
         self.lights.iter().for_each(|l| {
             points.insert(l.position, *l);
         });
@@ -101,50 +101,73 @@ impl Sky {
         false
     }
 
-    fn print(&self) {
+    /// The lights as rows of `#` and `.`, each on its own line.
+    fn render(&self) -> String {
         let bounds = self.bounds();
 
+        let mut output = String::new();
         for y in bounds.0.y..=bounds.1.y {
+            output.push('\n');
             for x in bounds.0.x..=bounds.1.x {
                 if self.points.contains_key(&Point::new(x, y)) {
-                    print!("#");
+                    output.push('#');
                 } else {
-                    print!(".");
+                    output.push('.');
                 }
             }
-            println!();
         }
+        output
+    }
+}
+
+pub struct Day10;
 
-        println!("{}", "=".repeat((bounds.1.x - bounds.0.x) as usize))
+impl Solution for Day10 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 10;
+
+    type Input = Vec<Light>;
+    type Output1 = String;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<Vec<Light>> {
+        parsing::parse_lines_with(input, parse)
+    }
+
+    fn part1(lights: &Vec<Light>) -> AocResult<String> {
+        part1(lights).ok_or_else(|| AocError::no_solution("The lights never spell a message"))
+    }
+
+    fn part2(lights: &Vec<Light>) -> AocResult<u32> {
+        part2(lights).ok_or_else(|| AocError::no_solution("The lights never spell a message"))
     }
 }
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(10)?;
-    let lights = lines
-        .iter()
-        .map(parse)
-        .collect::<Result<Vec<Light>, Error>>()?;
-    result("Part 1", || part1(lights.clone()));
+// The message the lights spell out.
+fn part1(lights: &[Light]) -> Option<String> {
+    find_message(lights).map(|(sky, _)| sky.render())
+}
 
-    Ok(())
+// How many seconds it takes for the message to appear.
+fn part2(lights: &[Light]) -> Option<u32> {
+    find_message(lights).map(|(_, seconds)| seconds)
 }
 
-fn part1(lights: Vec<Light>) {
-    let mut sky = Sky::new(lights);
+fn find_message(lights: &[Light]) -> Option<(Sky, u32)> {
+    let mut sky = Sky::new(lights.to_vec());
     for i in 0..100000 {
         sky.step();
 
         if sky.probably_has_text() {
-            sky.print();
-            println!("Seconds {}", i + 1);
-            break;
+            return Some((sky, i + 1));
         }
     }
+
+    None
 }
 
-fn parse(line: &String) -> Result<Light, Error> {
-    if let Some(captures) = RE.captures(line.as_str()) {
+fn parse(line: &str) -> Result<Light, AocError> {
+    if let Some(captures) = RE.captures(line) {
         Ok(Light {
             position: Point::new(captures[1].trim().parse()?, captures[2].trim().parse()?),
             velocity: Point::new(captures[3].trim().parse()?, captures[4].trim().parse()?),
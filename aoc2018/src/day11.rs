@@ -1,15 +1,35 @@
-use failure::*;
-use itertools::Itertools;
-use lazy_static::*;
-use regex::Regex;
 use std::collections::HashMap;
 use util::aoc::*;
+use util::format_err;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    result("Part 1", || part1(1723));
-    result("Part 2", || part2(1723));
+pub struct Day11;
 
-    Ok(())
+impl Solution for Day11 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 11;
+
+    /// The grid serial number.
+    type Input = i32;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<i32> {
+        Ok(input.trim().parse()?)
+    }
+
+    // Answered as `X,Y`, the top-left fuel cell of the square.
+    fn part1(&serial_number: &i32) -> AocResult<String> {
+        part1(serial_number)
+            .map(|point| format!("{},{}", point.x, point.y))
+            .ok_or_else(|| AocError::no_solution("No square has positive power"))
+    }
+
+    // Answered as `X,Y,size`.
+    fn part2(&serial_number: &i32) -> AocResult<String> {
+        part2(serial_number)
+            .map(|(point, size)| format!("{},{},{}", point.x, point.y, size))
+            .ok_or_else(|| AocError::no_solution("No square has positive power"))
+    }
 }
 
 fn part1(serial_number: i32) -> Option<Point> {
@@ -22,14 +42,11 @@ fn part2(serial_number: i32) -> Option<(Point, usize)> {
     let mut best_point = None;
 
     for grid_size in 1..=30 {
-        println!("Grid size: {}", grid_size);
         if let Some(solution) = solve(serial_number, grid_size) {
             if solution.1 > max_power {
                 best_point = Some(solution.0);
                 max_power = solution.1;
                 best_grid_size = grid_size;
-
-                println!("Best: {:?}, {}", best_point, grid_size);
             }
         }
     }
@@ -57,10 +74,15 @@ fn solve(serial_number: i32, grid_side_length: usize) -> Option<(Point, i32)> {
         for y in min_point..=max_point {
             let point = Point::new(x, y);
             let power = point
-                    .square(grid_side_length)
-                    .iter()
-                    .map(|p| powers.get(p).ok_or_else(|| format_err!("Invalid point: {:?}", p)).unwrap())
-                    .sum::<i32>();
+                .square(grid_side_length)
+                .iter()
+                .map(|p| {
+                    powers
+                        .get(p)
+                        .ok_or_else(|| format_err!("Invalid point: {:?}", p))
+                        .unwrap()
+                })
+                .sum::<i32>();
 
             if power > max_power {
                 max_power = power;
@@ -91,6 +113,7 @@ fn fuel_cell_power(grid_serial_number: i32, coordinate: &Point) -> i32 {
     power_level
 }
 
+#[cfg(test)]
 mod test {
     use super::*;
 
@@ -0,0 +1,123 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::convert::TryInto;
+use util::aoc::cycles::linear_growth;
+use util::aoc::*;
+use util::format_err;
+
+type Pots = BTreeSet<i64>;
+type Rules = HashSet<[bool; 5]>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 12;
+
+    type Input = (Pots, Rules);
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<(Pots, Rules)> {
+        parse(&input.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    fn part1((pots, rules): &(Pots, Rules)) -> AocResult<i64> {
+        Ok(part1(pots, rules))
+    }
+
+    fn part2((pots, rules): &(Pots, Rules)) -> AocResult<i64> {
+        Ok(part2(pots, rules))
+    }
+}
+
+fn part1(pots: &Pots, rules: &Rules) -> i64 {
+    (0..20)
+        .fold(pots.clone(), |pots, _| generation(&pots, rules))
+        .iter()
+        .sum()
+}
+
+fn part2(pots: &Pots, rules: &Rules) -> i64 {
+    // The plants settle into a pattern that drifts along the row, so the sum grows linearly.
+    linear_growth(
+        pots.clone(),
+        |pots| generation(pots, rules),
+        |pots| pots.iter().sum(),
+        100,
+    )
+    .value_at(50_000_000_000)
+}
+
+fn generation(pots: &Pots, rules: &Rules) -> Pots {
+    let (first, last) = match (pots.iter().next(), pots.iter().next_back()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Pots::new(),
+    };
+
+    (first - 2..=last + 2)
+        .filter(|pot| {
+            let pattern = [-2, -1, 0, 1, 2].map(|offset| pots.contains(&(pot + offset)));
+            rules.contains(&pattern)
+        })
+        .collect()
+}
+
+fn parse(lines: &[String]) -> Result<(Pots, Rules), AocError> {
+    let initial = lines
+        .first()
+        .and_then(|line| line.strip_prefix("initial state: "))
+        .ok_or_else(|| format_err!("Missing initial state."))?;
+    let pots = initial
+        .chars()
+        .enumerate()
+        .filter(|&(_, c)| c == '#')
+        .map(|(i, _)| i as i64)
+        .collect();
+
+    let mut rules = Rules::new();
+    for line in lines.iter().skip(1).filter(|line| !line.is_empty()) {
+        let (pattern, result) = line
+            .split_once(" => ")
+            .ok_or_else(|| format_err!("Invalid rule: {}", line))?;
+        let pattern: Vec<bool> = pattern.chars().map(|c| c == '#').collect();
+        let pattern: [bool; 5] = pattern
+            .try_into()
+            .map_err(|_| format_err!("Invalid rule: {}", line))?;
+        if result == "#" {
+            rules.insert(pattern);
+        }
+    }
+
+    Ok((pots, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #";
+
+    #[test]
+    fn part1_sample() {
+        let lines: Vec<String> = SAMPLE_INPUT.lines().map(String::from).collect();
+        let (pots, rules) = parse(&lines).unwrap();
+
+        assert_eq!(325, part1(&pots, &rules));
+    }
+}
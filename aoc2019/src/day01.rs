@@ -1,15 +1,26 @@
-use std::collections::hash_set::HashSet;
-use std::str::FromStr;
 use util::aoc::*;
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let lines: Vec<String> = input::read(1)?;
-    let module_masses = lines.parse::<i32>()?;
+pub struct Day01;
 
-    result("Part 1", || part1(&module_masses));
-    result("Part 2", || part2(&module_masses));
+impl Solution for Day01 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 1;
 
-    Ok(())
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(module_masses: &Vec<i32>) -> AocResult<i32> {
+        Ok(part1(module_masses))
+    }
+
+    fn part2(module_masses: &Vec<i32>) -> AocResult<i32> {
+        Ok(part2(module_masses))
+    }
 }
 
 fn part1(module_masses: &[i32]) -> i32 {
@@ -52,7 +63,7 @@ mod tests {
 
     #[test]
     fn test_calculate_fuel() {
-        assert_eq!(654, calculate_fuel(1969));
-        assert_eq!(33583, calculate_fuel(100756));
+        assert_eq!(Some(654), calculate_fuel(1969));
+        assert_eq!(Some(33583), calculate_fuel(100756));
     }
 }
@@ -0,0 +1,64 @@
+use crate::intcode::*;
+use util::aoc::*;
+use util::format_err;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 2;
+
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parse_program(input)
+    }
+
+    fn part1(memory: &Vec<i64>) -> AocResult<i64> {
+        match part1(memory.clone())? {
+            IOResult::Halt(result) => Ok(result),
+            other => Err(format_err!("Expected the program to halt, got {:?}", other)),
+        }
+    }
+
+    fn part2(memory: &Vec<i64>) -> AocResult<i64> {
+        part2(memory.clone())
+    }
+}
+
+fn part1(memory: Vec<i64>) -> Result<IOResult, AocError> {
+    Computer::init(memory.clone()).execute()
+}
+
+fn part2(memory: Vec<i64>) -> Result<i64, AocError> {
+    for noun in 0..1000 {
+        for verb in 0i64..(memory.len() - 1) as i64 {
+            let mut new_memory = memory.clone();
+            new_memory[1] = noun;
+            new_memory[2] = verb;
+
+            if let IOResult::Halt(result) = Computer::init(new_memory).execute()? {
+                if result == 19690720 {
+                    return Ok(100 * noun + verb);
+                }
+            }
+        }
+    }
+
+    Err(format_err!("Didn't find noun/verb."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_program() {
+        assert!(matches!(
+            part1(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]).unwrap(),
+            IOResult::Halt(3500)
+        ));
+    }
+}
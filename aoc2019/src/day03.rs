@@ -1,23 +1,35 @@
-use failure::{bail, format_err, Error};
-use itertools::Itertools;
-use std::collections::hash_set::HashSet;
 use std::collections::HashMap;
-use std::str::FromStr;
 use util::aoc::*;
+use util::format_err;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let wires: Vec<Wire> = input::read(3)?
-        .iter()
-        .map(|definition| Wire::parse(definition).unwrap())
-        .collect();
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 3;
+
+    type Input = Vec<Wire>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Wire>> {
+        let wires = parsing::parse_lines_with(input, Wire::parse)?;
+        if wires.len() != 2 {
+            return Err(format_err!("Expected two wires, found {}", wires.len()));
+        }
+        Ok(wires)
+    }
 
-    result("Part 1", || part1(&wires[0], &wires[1]).unwrap());
-    result("Part 2", || part2(&wires[0], &wires[1]).unwrap());
+    fn part1(wires: &Vec<Wire>) -> AocResult<i32> {
+        part1(&wires[0], &wires[1])
+    }
 
-    Ok(())
+    fn part2(wires: &Vec<Wire>) -> AocResult<i32> {
+        part2(&wires[0], &wires[1])
+    }
 }
 
-fn part1(wire_one: &Wire, wire_two: &Wire) -> Result<i32, Error> {
+fn part1(wire_one: &Wire, wire_two: &Wire) -> Result<i32, AocError> {
     wire_one
         .intersections(wire_two)
         .into_iter()
@@ -28,12 +40,12 @@ fn part1(wire_one: &Wire, wire_two: &Wire) -> Result<i32, Error> {
         .ok_or_else(|| format_err!("Failed to find intersection"))
 }
 
-fn part2(wire_one: &Wire, wire_two: &Wire) -> Result<i32, Error> {
+fn part2(wire_one: &Wire, wire_two: &Wire) -> Result<i32, AocError> {
     let mut grid_one = HashMap::new();
     let mut grid_two = HashMap::new();
 
-    grid_walk(&mut grid_one, &wire_one);
-    grid_walk(&mut grid_two, &wire_two);
+    grid_walk(&mut grid_one, wire_one);
+    grid_walk(&mut grid_two, wire_two);
 
     grid_one
         .iter()
@@ -55,13 +67,13 @@ fn grid_walk(grid: &mut HashMap<Point, i32>, wire: &Wire) {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Wire {
+pub struct Wire {
     directions: Vec<Direction>,
     segments: Vec<LineSegment>,
 }
 
 impl Wire {
-    pub fn parse(line_definition: &str) -> Result<Wire, Error> {
+    pub fn parse(line_definition: &str) -> Result<Wire, AocError> {
         let mut start = Point::zero();
         let mut segments = Vec::new();
         let directions = Self::parse_directions(line_definition)?;
@@ -106,7 +118,7 @@ impl Wire {
         intersections_with_steps
     }
 
-    fn parse_directions(line_definition: &str) -> Result<Vec<Direction>, Error> {
+    fn parse_directions(line_definition: &str) -> Result<Vec<Direction>, AocError> {
         line_definition
             .split(",")
             .filter(|dir| dir.len() > 1)
@@ -117,12 +129,12 @@ impl Wire {
                 Some('R') => Ok(Direction::Right(dir[1..].parse::<i32>()?)),
                 _ => Err(format_err!("Unknown direction: {}", dir)),
             })
-            .collect::<Result<Vec<Direction>, Error>>()
+            .collect::<Result<Vec<Direction>, AocError>>()
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct LineSegment {
+struct LineSegment {
     start: Point,
     end: Point,
 }
@@ -135,7 +147,7 @@ impl LineSegment {
     pub fn len(&self) -> i32 {
         // As these segments are always horizontal or vertical, we can be very lazy about this.
         let x_len = (self.start.x - self.end.x).abs();
-        let y_len = (self.start.y - self.start.y).abs();
+        let y_len = (self.start.y - self.end.y).abs();
 
         std::cmp::max(x_len, y_len)
     }
@@ -175,8 +187,8 @@ impl LineSegment {
             let u = Self::cross_div(&q_minus_p, &r, r_cross_s);
 
             // are the intersection coordinates both in range?
-            let t_in_range = 0f32 <= t && t <= 1f32;
-            let u_in_range = 0f32 <= u && u <= 1f32;
+            let t_in_range = (0f32..=1f32).contains(&t);
+            let u_in_range = (0f32..=1f32).contains(&u);
 
             if t_in_range && u_in_range {
                 // there is an intersection
@@ -208,7 +220,7 @@ impl LineSegment {
 
 /// The relationship between two line segments.
 #[derive(Debug, PartialEq)]
-pub enum LineRelation {
+enum LineRelation {
     /// The line intervals are not parallel (or anti-parallel), and "meet" each other at exactly
     /// one point.
     DivergentIntersecting(Point),
@@ -266,13 +278,13 @@ impl Iterator for DirectionIterator {
         self.steps -= 1;
 
         match self.direction {
-            Direction::Up(steps) => self.point = self.point + Point::new(0, 1),
-            Direction::Down(steps) => self.point = self.point + Point::new(0, -1),
-            Direction::Left(steps) => self.point = self.point + Point::new(-1, 0),
-            Direction::Right(steps) => self.point = self.point + Point::new(1, 0),
+            Direction::Up(_steps) => self.point += Point::new(0, 1),
+            Direction::Down(_steps) => self.point += Point::new(0, -1),
+            Direction::Left(_steps) => self.point += Point::new(-1, 0),
+            Direction::Right(_steps) => self.point += Point::new(1, 0),
         }
 
-        Some(self.point.clone())
+        Some(self.point)
     }
 }
 
@@ -326,6 +338,6 @@ mod tests {
         let wire_two = dbg!(Wire::parse("U98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap());
 
         assert_eq!(part1(&wire_one, &wire_two).unwrap(), 135);
-        assert_eq!(part1(&wire_one, &wire_two).unwrap(), 410);
+        assert_eq!(part2(&wire_one, &wire_two).unwrap(), 410);
     }
 }
@@ -1,22 +1,32 @@
-use failure::{bail, format_err, Error};
-use itertools::Itertools;
-use std::collections::hash_set::HashSet;
-use std::collections::HashMap;
-use std::str::FromStr;
 use util::aoc::digits::Digits;
 use util::aoc::*;
+use util::format_err;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let ranges: Vec<u32> = input::read(4)?[0]
-        .split("-")
-        .collect::<Vec<_>>()
-        .parse::<u32>()?;
-    let (lo, hi) = (ranges[0], ranges[1]);
+pub struct Day04;
 
-    result("Part 1", || part1(lo, hi));
-    result("Part 2", || part2(lo, hi));
+impl Solution for Day04 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 4;
 
-    Ok(())
+    type Input = (u32, u32);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<(u32, u32)> {
+        let (lo, hi) = input
+            .trim()
+            .split_once('-')
+            .ok_or_else(|| format_err!("Expected a range, got {}", input.trim()))?;
+        Ok((lo.parse()?, hi.parse()?))
+    }
+
+    fn part1(&(lo, hi): &(u32, u32)) -> AocResult<u32> {
+        Ok(part1(lo, hi))
+    }
+
+    fn part2(&(lo, hi): &(u32, u32)) -> AocResult<u32> {
+        Ok(part2(lo, hi))
+    }
 }
 
 fn part1(lo: u32, hi: u32) -> u32 {
@@ -29,7 +39,7 @@ fn part2(lo: u32, hi: u32) -> u32 {
 
 fn count_combinations(lo: u32, hi: u32, predicate: impl Fn(u32) -> bool) -> u32 {
     let mut valid_combinations = 0;
-    'outer: for i in lo..hi + 1 {
+    for i in lo..hi + 1 {
         if check_code(i, &predicate) {
             valid_combinations += 1
         }
@@ -1,12 +1,12 @@
-use aoc2019::intcode::*;
-use failure::{bail, format_err, Error};
+use crate::intcode::*;
+use util::{bail, format_err};
 use itertools::Itertools;
 use std::collections::hash_set::HashSet;
 use std::io::Write;
 use std::str::FromStr;
 use util::aoc::*;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     let lines: Vec<String> = input::read(5)?
         .first()
         .map(|f| f.split(",").map(|s| s.to_string()).collect())
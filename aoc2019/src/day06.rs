@@ -1,10 +1,5 @@
-use aoc2019::intcode::*;
-use failure::{bail, format_err, Error};
-use itertools::Itertools;
-use std::borrow::Borrow;
 use std::collections::hash_set::HashSet;
 use std::collections::HashMap;
-use std::str::FromStr;
 use util::aoc::*;
 
 type Object = String;
@@ -13,36 +8,46 @@ type Edge = (Object, Object);
 const SAN: &str = "SAN";
 const YOU: &str = "YOU";
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines: Vec<String> = input::read(6)?
-        .iter()
-        .map(|f| f.split(",").map(|s| s.to_string()).collect())
-        .collect();
-    let edges: Vec<Edge> = parse(lines);
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 6;
+
+    type Input = Vec<Edge>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Vec<Edge>> {
+        Ok(parse(input.lines().map(String::from).collect()))
+    }
 
-    result("Part 1", || part1(edges.clone()));
-    result("Part 2", || part2(edges.clone()));
+    fn part1(edges: &Vec<Edge>) -> AocResult<usize> {
+        Ok(part1(edges.clone()))
+    }
 
-    Ok(())
+    fn part2(edges: &Vec<Edge>) -> AocResult<usize> {
+        Ok(part2(edges.clone()))
+    }
 }
 
 fn part1(edges: Vec<Edge>) -> usize {
     let (objects, parent_graph) = build_graph(edges);
 
     // Count all paths.
-    return objects
+    objects
         .iter()
         .flat_map(|object| parent_graph.traverse(object))
-        .count();
+        .count()
 }
 
 fn part2(edges: Vec<Edge>) -> usize {
-    let (objects, parent_graph) = build_graph(edges);
+    let (_objects, parent_graph) = build_graph(edges);
 
     // Find the common parent of SAN and YOU.
-    let mut san_chain: Vec<Object> = parent_graph.traverse(&SAN).collect();
-    return parent_graph
-        .traverse(&YOU)
+    let san_chain: Vec<Object> = parent_graph.traverse(SAN).collect();
+    parent_graph
+        .traverse(YOU)
         .enumerate()
         .find_map(|(you_length, object)| {
             san_chain
@@ -50,7 +55,7 @@ fn part2(edges: Vec<Edge>) -> usize {
                 .position(|o| *o == object)
                 .map(|san_length| you_length + san_length)
         })
-        .unwrap_or(0);
+        .unwrap_or(0)
 }
 
 struct ParentGraph {
@@ -61,7 +66,7 @@ impl ParentGraph {
     fn new(object_to_parent: HashMap<Object, Object>) -> ParentGraph {
         ParentGraph { object_to_parent }
     }
-    fn traverse(&self, start_at: &str) -> ParentGraphIterator {
+    fn traverse(&self, start_at: &str) -> ParentGraphIterator<'_> {
         ParentGraphIterator {
             current_object: start_at.to_string(),
             parent_graph: self,
@@ -1,27 +1,33 @@
-use aoc2019::intcode::*;
-use failure::{bail, format_err, Error};
+use crate::intcode::*;
 use itertools::Itertools;
 use permutohedron::Heap;
-use std::borrow::Borrow;
-use std::collections::hash_set::HashSet;
-use std::collections::HashMap;
-use std::str::FromStr;
 use util::aoc::*;
+use util::format_err;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines: Vec<String> = input::read(7)?
-        .first()
-        .map(|f| f.split(",").map(|s| s.to_string()).collect())
-        .unwrap_or(Vec::new());
-    let memory = lines.parse::<i64>()?;
+pub struct Day07;
 
-    result("Part 1", || part1(memory.clone()));
-    result("Part 2", || part2(memory.clone()));
+impl Solution for Day07 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 7;
 
-    Ok(())
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parse_program(input)
+    }
+
+    fn part1(memory: &Vec<i64>) -> AocResult<i64> {
+        part1(memory.clone())
+    }
+
+    fn part2(memory: &Vec<i64>) -> AocResult<i64> {
+        Ok(part2(memory.clone()))
+    }
 }
 
-fn part1(memory: Vec<i64>) -> Result<i64, Error> {
+fn part1(memory: Vec<i64>) -> Result<i64, AocError> {
     let mut phase_settings = vec![4, 3, 2, 1, 0];
     Heap::new(&mut phase_settings)
         .map(|phase_settings| {
@@ -44,7 +50,7 @@ fn part2(memory: Vec<i64>) -> i64 {
         .unwrap()
 }
 
-fn amplify(memory: Vec<i64>, phase_setting: i64, signal: i64) -> Result<i64, Error> {
+fn amplify(memory: Vec<i64>, phase_setting: i64, signal: i64) -> Result<i64, AocError> {
     let mut amplifier = Amplifier::init(memory);
     let mut iter = vec![phase_setting, signal].into_iter();
 
@@ -58,7 +64,7 @@ fn amplify(memory: Vec<i64>, phase_setting: i64, signal: i64) -> Result<i64, Err
     }
 }
 
-fn amplify_with_feedback(memory: Vec<i64>, phase_settings: Vec<i64>) -> Result<i64, Error> {
+fn amplify_with_feedback(memory: Vec<i64>, phase_settings: Vec<i64>) -> Result<i64, AocError> {
     let num_amplifiers = phase_settings.len();
     let mut amplifiers = phase_settings
         .into_iter()
@@ -77,7 +83,7 @@ fn amplify_with_feedback(memory: Vec<i64>, phase_settings: Vec<i64>) -> Result<i
             IOResult::Output(output) => {
                 signal = output;
             }
-            IOResult::Halt(halt) => break,
+            IOResult::Halt(_halt) => break,
         };
         current_amplifier += 1;
     }
@@ -100,11 +106,11 @@ impl Amplifier {
         }
     }
 
-    fn execute(&mut self) -> Result<IOResult, Error> {
+    fn execute(&mut self) -> Result<IOResult, AocError> {
         self.resume(None)
     }
 
-    fn resume(&mut self, input: Option<i64>) -> Result<IOResult, Error> {
+    fn resume(&mut self, input: Option<i64>) -> Result<IOResult, AocError> {
         let result = self.computer.resume(input)?;
         if let IOResult::Halt(_) = result {
             self.halted = true;
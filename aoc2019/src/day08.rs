@@ -1,34 +1,40 @@
-use aoc2019::intcode::*;
-use failure::_core::fmt::Formatter;
-use failure::{bail, format_err, Error};
-use itertools::Itertools;
-use permutohedron::Heap;
-use std::borrow::Borrow;
-use std::collections::hash_set::HashSet;
-use std::collections::HashMap;
 use std::fmt::Display;
-use std::str::FromStr;
+use std::fmt::Formatter;
 use util::aoc::*;
+use util::format_err;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 8;
+
+    type Input = Vec<i32>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        input
+            .trim()
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|digit| digit as i32)
+                    .ok_or_else(|| format_err!("Expected a digit, got {:?}", c))
+            })
+            .collect()
+    }
+
+    fn part1(digits: &Vec<i32>) -> AocResult<usize> {
+        Ok(part1(digits.clone()))
+    }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let digits: Vec<i32> = input::read(8)?
-        .first()
-        .map(|f| {
-            f.split("")
-                .filter(|s| !s.is_empty())
-                .map(|s| s.parse::<i32>().unwrap())
-                .collect()
-        })
-        .unwrap_or(Vec::new());
-
-    result("Part 1", || part1(digits.clone()));
-    result("Part 2", || part2(digits.clone()));
-
-    Ok(())
+    fn part2(digits: &Vec<i32>) -> AocResult<String> {
+        Ok(part2(digits.clone()))
+    }
 }
 
 fn part1(digits: Vec<i32>) -> usize {
-    println!("d: {:?}", digits);
     let layers = build_layers(digits);
 
     let layer_most_zeros = layers.into_iter().min_by_key(|l| l.count(0)).unwrap();
@@ -36,12 +42,10 @@ fn part1(digits: Vec<i32>) -> usize {
     layer_most_zeros.count(1) * layer_most_zeros.count(2)
 }
 
-fn part2(digits: Vec<i32>) -> i32 {
+fn part2(digits: Vec<i32>) -> String {
     let layers = build_layers(digits);
 
-    println!("{}", Layer::compose(&layers));
-
-    0
+    Layer::compose(&layers).to_string()
 }
 
 fn build_layers(digits: Vec<i32>) -> Vec<Layer> {
@@ -94,20 +98,19 @@ impl Layer {
     }
 }
 
+// Each row on its own line, white pixels as `#` and the rest as `.`.
 impl Display for Layer {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
         for h in 0..self.height {
+            f.write_str("\n")?;
             for w in 0..self.width {
                 let pixel = self.pixels[h * self.width + w];
                 let c = match pixel {
-                    0 => " ",
-                    1 => "1",
-                    2 => " ",
-                    _ => "",
+                    1 => "#",
+                    _ => ".",
                 };
-                f.write_str(c);
+                f.write_str(c)?;
             }
-            f.write_str("\n");
         }
 
         Ok(())
@@ -0,0 +1,36 @@
+use crate::intcode::*;
+use util::aoc::*;
+use util::format_err;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 9;
+
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parse_program(input)
+    }
+
+    fn part1(memory: &Vec<i64>) -> AocResult<i64> {
+        solve(memory.clone(), 1)
+    }
+
+    fn part2(memory: &Vec<i64>) -> AocResult<i64> {
+        solve(memory.clone(), 2)
+    }
+}
+
+fn solve(memory: Vec<i64>, input: i64) -> Result<i64, AocError> {
+    let mut computer = Computer::init(memory);
+    let result = computer.resume(Some(input))?;
+    if let IOResult::Output(result) = result {
+        Ok(result)
+    } else {
+        Err(format_err!("Expected output, got: {:?}", result))
+    }
+}
@@ -4,15 +4,15 @@ use std::collections::{HashMap, VecDeque};
 use std::fmt::Write;
 use std::str::FromStr;
 
-use failure::_core::fmt::Formatter;
-use failure::{bail, format_err, Error};
+use std::fmt::Formatter;
+use util::{bail, format_err};
 use itertools::Itertools;
 use std::f32;
 use util::aoc::*;
 
-use aoc2019::intcode::*;
+use crate::intcode::*;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     let lines: Vec<String> = input::read(10)?;
     let grid = Grid::build_grid(lines);
 
@@ -1,41 +1,46 @@
-use std::borrow::Borrow;
-use std::collections::hash_set::HashSet;
-use std::collections::{HashMap, VecDeque};
-use std::fmt::Write;
-use std::str::FromStr;
-
-use failure::_core::fmt::Formatter;
-use failure::{bail, format_err, Error};
+use std::collections::HashMap;
+
 use itertools::{Itertools, MinMaxResult};
-use std::f32;
 use util::aoc::*;
+use util::format_err;
+
+use crate::intcode::*;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 11;
 
-use aoc2019::intcode::*;
+    type Input = Vec<i64>;
+    type Output1 = usize;
+    type Output2 = String;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines: Vec<String> = input::read(11)?
-        .first()
-        .map(|f| f.split(",").map(|s| s.to_string()).collect())
-        .unwrap_or(Vec::new());
-    let memory = lines.parse::<i64>()?;
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parse_program(input)
+    }
 
-    result("Part 1", || part1(memory.clone()));
-    result("Part 2", || part2(memory.clone()));
+    fn part1(memory: &Vec<i64>) -> AocResult<usize> {
+        part1(memory.clone())
+    }
 
-    Ok(())
+    fn part2(memory: &Vec<i64>) -> AocResult<String> {
+        part2(memory.clone())
+    }
 }
 
-fn part1(memory: Vec<i64>) -> Result<usize, Error> {
-    let mut computer = Computer::init(memory);
+fn part1(memory: Vec<i64>) -> Result<usize, AocError> {
+    let computer = Computer::init(memory);
     let mut panels = HashMap::new();
     run(computer, &mut panels)
 }
 
-fn part2(memory: Vec<i64>) -> Result<usize, Error> {
-    let mut computer = Computer::init(memory);
+// The registration identifier the robot paints, starting on a white panel.
+fn part2(memory: Vec<i64>) -> Result<String, AocError> {
+    let computer = Computer::init(memory);
     let mut panels = HashMap::new();
     panels.insert(Point::zero(), Color::White);
-    run(computer, &mut panels);
+    run(computer, &mut panels)?;
 
     let x_mm = if let MinMaxResult::MinMax(x_min, x_max) = panels.keys().map(|p| p.x).minmax() {
         (x_min, x_max)
@@ -49,37 +54,37 @@ fn part2(memory: Vec<i64>) -> Result<usize, Error> {
         (0, 0)
     };
 
+    let mut output = String::new();
     for y in (y_mm.0..=y_mm.1).rev() {
+        output.push('\n');
         for x in x_mm.0..=x_mm.1 {
             let point = Point::new(x, y);
             let color = panels.get(&point).unwrap_or(&Color::Black);
 
-            let output = match color {
-                Color::Black => " ",
-                Color::White => "#",
-            };
-            print!("{}", output);
+            output.push(match color {
+                Color::Black => '.',
+                Color::White => '#',
+            });
         }
-        println!();
     }
 
-    Ok(panels.len())
+    Ok(output)
 }
 
-fn run(mut computer: Computer, panels: &mut HashMap<Point, Color>) -> Result<usize, Error> {
+fn run(mut computer: Computer, panels: &mut HashMap<Point, Color>) -> Result<usize, AocError> {
     let mut point = Point::zero();
     let mut direction = Direction::Up;
     loop {
         let input = panels.get(&point).unwrap_or(&Color::Black);
         let color = match computer.resume(Some(input.as_input()))? {
-            IOResult::Output(o) if o == 0 => Color::Black,
-            IOResult::Output(o) if o == 1 => Color::White,
+            IOResult::Output(0) => Color::Black,
+            IOResult::Output(1) => Color::White,
             IOResult::Halt(_) => break,
             _ => return Err(format_err!("Not an output")),
         };
         let turn = match computer.resume(None)? {
-            IOResult::Output(o) if o == 0 => Turn::Left,
-            IOResult::Output(o) if o == 1 => Turn::Right,
+            IOResult::Output(0) => Turn::Left,
+            IOResult::Output(1) => Turn::Right,
             IOResult::Halt(_) => break,
             _ => return Err(format_err!("Not an output")),
         };
@@ -1,9 +1,9 @@
-use failure::{format_err, Error};
 use itertools::{zip, Itertools};
 use lazy_static::*;
-use num::integer::lcm;
 use regex::Regex;
+use util::aoc::math::lcm_all;
 use util::aoc::*;
+use util::format_err;
 
 type Vector = [i32; 3];
 
@@ -12,26 +12,31 @@ lazy_static! {
         Regex::new("<x=([\\s\\-0-9]+), y=([\\s\\-0-9]+), z=([\\s\\-0-9]+)>").unwrap();
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let positions: Vec<Vector> = input::read(12)?
-        .iter()
-        .map(|f| parse_vector(f))
-        .collect::<Result<Vec<Vector>, _>>()?;
-    let moons = positions
-        .into_iter()
-        .map(|t| Moon::new(t))
-        .collect::<Vec<Moon>>();
+pub struct Day12;
 
-    result("Part 1", || part1(moons.clone()));
-    result("Part 2", || part2(moons.clone()));
+impl Solution for Day12 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 12;
 
-    Ok(())
-}
+    type Input = Vec<Moon>;
+    type Output1 = i32;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<Moon>> {
+        let positions = parsing::parse_lines_with(input, parse_vector)?;
+        Ok(positions.into_iter().map(Moon::new).collect())
+    }
+
+    fn part1(moons: &Vec<Moon>) -> AocResult<i32> {
+        part1(moons.clone())
+    }
 
-fn part1(mut moons: Vec<Moon>) -> Result<i32, Error> {
-    println!("Step 0: =========================");
-    moons.iter().for_each(|m| println!("{:?}", m));
+    fn part2(moons: &Vec<Moon>) -> AocResult<i64> {
+        part2(moons.clone())
+    }
+}
 
+fn part1(mut moons: Vec<Moon>) -> Result<i32, AocError> {
     let combinations = (0..moons.len())
         .combinations(2)
         .collect::<Vec<Vec<usize>>>();
@@ -49,16 +54,13 @@ fn part1(mut moons: Vec<Moon>) -> Result<i32, Error> {
     Ok(moons.iter().map(Moon::total_energy).sum())
 }
 
-fn part2(moons: Vec<Moon>) -> Result<usize, Error> {
+fn part2(moons: Vec<Moon>) -> Result<i64, AocError> {
     let initial_state = moons.clone();
-    println!("Step 0: =========================");
-    moons.iter().for_each(|m| println!("{:?}", m));
-
     let combinations = (0..moons.len())
         .combinations(2)
         .collect::<Vec<Vec<usize>>>();
-    let mut stops = [0; 3];
-    for axis in 0..3 {
+    let mut stops = [0i64; 3];
+    for (axis, stop) in stops.iter_mut().enumerate() {
         let mut moons = moons.clone();
         let mut steps = 0;
         loop {
@@ -75,17 +77,17 @@ fn part2(moons: Vec<Moon>) -> Result<usize, Error> {
             if zip(&initial_state, &moons).all(|(m1, m2)| {
                 m1.position[axis] == m2.position[axis] && m1.velocity[axis] == m2.velocity[axis]
             }) {
-                stops[axis];
+                *stop = steps;
                 break;
             }
         }
     }
 
-    Ok(lcm(lcm(stops[0], stops[1]), lcm(stops[1], stops[2])))
+    Ok(lcm_all(stops))
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Moon {
+pub struct Moon {
     position: Vector,
     velocity: Vector,
 }
@@ -111,7 +113,7 @@ impl Moon {
     }
 
     fn step(&mut self) {
-        (0..3).for_each(|axis| self.position[axis] = self.position[axis] + self.velocity[axis]);
+        (0..3).for_each(|axis| self.position[axis] += self.velocity[axis]);
     }
 
     fn apply_gravity(&mut self, other: &mut Moon) {
@@ -132,7 +134,7 @@ impl Moon {
     }
 }
 
-fn parse_vector(s: &str) -> Result<Vector, Error> {
+fn parse_vector(s: &str) -> Result<Vector, AocError> {
     if let Some(captures) = RE.captures(s) {
         Ok([
             captures[1].trim().parse()?,
@@ -1,31 +1,32 @@
-use std::borrow::Borrow;
-use std::collections::{HashMap, VecDeque};
-use std::collections::hash_set::HashSet;
-use std::f32;
-use std::fmt::Write;
-use std::str::FromStr;
-
-use failure::{bail, Error, format_err};
-use failure::_core::fmt::Formatter;
-use itertools::{Itertools, MinMaxResult};
+use itertools::Itertools;
 use util::aoc::*;
 
-use aoc2019::intcode::*;
+use crate::intcode::*;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines: Vec<String> = input::read(13)?
-        .first()
-        .map(|f| f.split(",").map(|s| s.to_string()).collect())
-        .unwrap_or(Vec::new());
-    let memory = lines.parse::<i64>()?;
+pub struct Day13;
 
-    result("Part 1", || part1(memory.clone()));
-    result("Part 2", || part2(memory.clone()));
+impl Solution for Day13 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 13;
 
-    Ok(())
+    type Input = Vec<i64>;
+    type Output1 = usize;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parse_program(input)
+    }
+
+    fn part1(memory: &Vec<i64>) -> AocResult<usize> {
+        part1(memory.clone())
+    }
+
+    fn part2(memory: &Vec<i64>) -> AocResult<i32> {
+        part2(memory.clone())
+    }
 }
 
-fn part1(memory: Vec<i64>) -> Result<usize, Error> {
+fn part1(memory: Vec<i64>) -> Result<usize, AocError> {
     let mut computer = Computer::init(memory);
     let mut outputs = Vec::new();
     loop {
@@ -36,7 +37,8 @@ fn part1(memory: Vec<i64>) -> Result<usize, Error> {
         }
     }
 
-    let outputs = outputs.chunks(3)
+    let outputs = outputs
+        .chunks(3)
         .map(|o| (Point::new(o[0], o[1]), o[2]))
         .collect::<Vec<_>>();
 
@@ -50,7 +52,7 @@ fn part1(memory: Vec<i64>) -> Result<usize, Error> {
     Ok(blocks)
 }
 
-fn part2(mut memory: Vec<i64>) -> Result<i32, Error> {
+fn part2(mut memory: Vec<i64>) -> Result<i32, AocError> {
     // Set memory address 0 to '2' to play for free.
     memory[0] = 2;
 
@@ -88,8 +90,8 @@ fn part2(mut memory: Vec<i64>) -> Result<i32, Error> {
                         score = output_buffer[2];
                     } else {
                         match tile.tile_type {
-                            TileType::Ball => ball_position = Some(tile.point.clone()),
-                            TileType::Paddle => paddle_position = Some(tile.point.clone()),
+                            TileType::Ball => ball_position = Some(tile.point),
+                            TileType::Paddle => paddle_position = Some(tile.point),
                             _ => {}
                         }
                     }
@@ -126,6 +128,7 @@ enum TileType {
     Block,
     Paddle,
     Ball,
+    #[allow(dead_code)]
     Score(i32),
 }
 
@@ -140,4 +143,4 @@ impl TileType {
             x => TileType::Score(x),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,188 @@
+use util::aoc::search::last_true_from;
+use util::aoc::*;
+use util::format_err;
+
+use std::collections::{HashMap, VecDeque};
+use std::ops::Mul;
+
+const ORE: Chemical = Chemical(0);
+const FUEL: Chemical = Chemical(1);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 14;
+
+    type Input = Vec<Reaction>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<Reaction>> {
+        let mut chemicals = Chemicals::new();
+        let mut reactions =
+            parsing::parse_lines_with(input, |line| Reaction::parse(line, &mut chemicals))?;
+        reactions.push(Reaction {
+            inputs: Vec::new(),
+            output: Reagent::new(ORE, 1),
+        });
+        Ok(reactions)
+    }
+
+    fn part1(reactions: &Vec<Reaction>) -> AocResult<i64> {
+        part1(reactions.clone())
+    }
+
+    fn part2(reactions: &Vec<Reaction>) -> AocResult<i64> {
+        part2(reactions.clone())
+    }
+}
+
+fn part1(reactions: Vec<Reaction>) -> Result<i64, AocError> {
+    let reactant_to_reaction: HashMap<Chemical, Reaction> = reactions
+        .iter()
+        .map(|r| (r.output.chemical, r.clone()))
+        .collect();
+
+    compute_ore_for_fuel(&reactant_to_reaction, 1)
+}
+
+fn part2(reactions: Vec<Reaction>) -> Result<i64, AocError> {
+    let reactant_to_reaction: HashMap<Chemical, Reaction> = reactions
+        .iter()
+        .map(|r| (r.output.chemical, r.clone()))
+        .collect();
+
+    const TARGET: i64 = 1_000_000_000_000;
+
+    // Check the reactions can make FUEL at all before searching for the most we can afford. Any
+    // amount of fuel needs the same reactions, so the search can't fail after this.
+    compute_ore_for_fuel(&reactant_to_reaction, 1)?;
+    let fuel = last_true_from(0, |fuel| {
+        compute_ore_for_fuel(&reactant_to_reaction, fuel).is_ok_and(|ore| ore <= TARGET)
+    });
+    fuel.ok_or_else(|| format_err!("Not enough ore for any fuel"))
+}
+
+fn compute_ore_for_fuel(
+    reactant_to_reaction: &HashMap<Chemical, Reaction>,
+    fuel: i64,
+) -> Result<i64, AocError> {
+    let mut order_q = VecDeque::new();
+    order_q.push_back(Reagent::new(FUEL, fuel));
+    let mut storage = HashMap::new();
+    let mut amounts_produced = HashMap::new();
+    while let Some(order) = order_q.pop_front() {
+        let reaction = reactant_to_reaction
+            .get(&order.chemical)
+            .ok_or_else(|| format_err!("Missing reaction to produce {:?}", order))?;
+        let mut required_amt = order.amount;
+        // Fill from storage.
+        let chem_in_storage = storage.entry(order.chemical).or_insert(0);
+        let amt_from_storage = std::cmp::min(required_amt, *chem_in_storage);
+        *chem_in_storage -= amt_from_storage;
+        required_amt -= amt_from_storage;
+
+        if required_amt > 0 {
+            // Run an integer number of batches, storing the excess.
+            let batches = (required_amt + reaction.output.amount - 1) / reaction.output.amount;
+            let amount_produced = batches * reaction.output.amount;
+
+            // Issue orders for missing reagents.
+            reaction
+                .inputs
+                .iter()
+                .for_each(|input| order_q.push_back(input * batches));
+
+            // Store excess.
+            let extra = amount_produced - required_amt;
+            *chem_in_storage += extra;
+
+            // Log production.
+            amounts_produced
+                .entry(order.chemical)
+                .and_modify(|e| *e += amount_produced)
+                .or_insert(amount_produced);
+        }
+    }
+
+    Ok(*amounts_produced.get(&ORE).unwrap_or(&0))
+}
+
+#[derive(Clone, Debug)]
+pub struct Reaction {
+    inputs: Vec<Reagent>,
+    output: Reagent,
+}
+
+impl Reaction {
+    fn parse(s: &str, chemicals: &mut Chemicals) -> Result<Reaction, AocError> {
+        let (inputs, output) = s
+            .split_once(" => ")
+            .ok_or_else(|| format_err!("Expected a reaction, got {}", s))?;
+        let inputs = inputs
+            .split(", ")
+            .map(|input| Reagent::parse(input, chemicals))
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = Reagent::parse(output, chemicals)?;
+
+        Ok(Reaction { inputs, output })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct Reagent {
+    chemical: Chemical,
+    amount: i64,
+}
+
+impl Reagent {
+    fn new(chemical: Chemical, amount: i64) -> Reagent {
+        Reagent { chemical, amount }
+    }
+
+    fn with_amount(&self, amount: i64) -> Reagent {
+        Reagent::new(self.chemical, amount)
+    }
+
+    fn parse(s: &str, chemicals: &mut Chemicals) -> Result<Reagent, AocError> {
+        let (amount, name) = s
+            .split_once(' ')
+            .ok_or_else(|| format_err!("Expected an amount and a chemical, got {}", s))?;
+
+        Ok(Reagent {
+            chemical: chemicals.intern(name),
+            amount: amount.parse()?,
+        })
+    }
+}
+
+impl Mul<i64> for &Reagent {
+    type Output = Reagent;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.with_amount(self.amount * rhs)
+    }
+}
+
+/// A chemical, numbered in the order it first appears.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Chemical(usize);
+
+/// Numbers each chemical's name, starting with `ORE` and `FUEL`.
+struct Chemicals {
+    names: HashMap<String, Chemical>,
+}
+
+impl Chemicals {
+    fn new() -> Chemicals {
+        Chemicals {
+            names: HashMap::from([("ORE".to_string(), ORE), ("FUEL".to_string(), FUEL)]),
+        }
+    }
+
+    fn intern(&mut self, name: &str) -> Chemical {
+        let next = Chemical(self.names.len());
+        *self.names.entry(name.to_string()).or_insert(next)
+    }
+}
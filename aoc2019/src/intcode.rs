@@ -1,8 +1,9 @@
 use crate::intcode::Argument::{Immediate, Position, Relative};
-use failure::{format_err, Error};
 use itertools::repeat_n;
 use std::ops::Index;
 use util::aoc::digits::Digits;
+use util::aoc::{AocError, AocResult};
+use util::format_err;
 
 const ADD: i64 = 1;
 const MUL: i64 = 2;
@@ -22,6 +23,15 @@ pub enum IOResult {
     Halt(i64),
 }
 
+/// Reads a program written as comma-separated integers.
+pub fn parse_program(input: &str) -> AocResult<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|value| Ok(value.trim().parse()?))
+        .collect()
+}
+
 pub struct Computer {
     memory: Memory,
     pc: usize,
@@ -38,13 +48,13 @@ impl Computer {
         }
     }
 
-    pub fn execute(&mut self) -> Result<IOResult, Error> {
+    pub fn execute(&mut self) -> Result<IOResult, AocError> {
         self.resume(None)
     }
 
-    pub fn resume(&mut self, mut resume_input: Option<i64>) -> Result<IOResult, Error> {
+    pub fn resume(&mut self, mut resume_input: Option<i64>) -> Result<IOResult, AocError> {
         loop {
-            let operation = Operation::parse_op(&self.memory.get_op(self.pc..))?;
+            let operation = Operation::parse_op(self.memory.get_op(self.pc..))?;
             match operation {
                 Operation::Add(left, right, output) => {
                     let value = self.memory.get(left) + self.memory.get(right);
@@ -130,8 +140,8 @@ impl Memory {
             Immediate(value) => value,
             Relative(addr) => {
                 let addr = (addr + self.relative_base) as usize;
-                self.extend_if_necessary(addr as usize);
-                self.memory[addr as usize]
+                self.extend_if_necessary(addr);
+                self.memory[addr]
             }
         }
     }
@@ -196,7 +206,7 @@ enum Operation {
 }
 
 impl Operation {
-    pub fn parse_op(memory: &[i64]) -> Result<Operation, Error> {
+    pub fn parse_op(memory: &[i64]) -> Result<Operation, AocError> {
         let opcode = OpCodeWithModes::parse(memory[0]);
         match opcode.opcode {
             ADD => Ok(Operation::Add(
@@ -248,9 +258,7 @@ struct OpCodeWithModes {
 
 impl OpCodeWithModes {
     fn parse(full_opcode: i64) -> OpCodeWithModes {
-        let mut opcode_parts: Vec<i64> =
-            Digits::new(full_opcode as u32).map(|d| d as i64).collect();
-        opcode_parts.reverse();
+        let opcode_parts: Vec<i64> = Digits::new(full_opcode).rev().collect();
 
         let opcode = if opcode_parts.len() >= 2 {
             opcode_parts[0] + opcode_parts[1] * 10
@@ -285,10 +293,6 @@ impl OpCodeWithModes {
     fn m(&self, offset: usize, argument: i64) -> Argument {
         Argument::from(self.modes[offset], argument)
     }
-
-    fn opcode(&self) -> i64 {
-        self.opcode
-    }
 }
 
 #[cfg(test)]
@@ -298,9 +302,7 @@ mod tests {
     #[test]
     fn parse_opcode() {
         let expected = OpCodeWithModes {
-            left: 0,
-            right: 1,
-            output: 0,
+            modes: vec![0, 1, 0],
             opcode: 2,
         };
 
@@ -310,9 +312,7 @@ mod tests {
     #[test]
     fn parse_opcode_short() {
         let expected = OpCodeWithModes {
-            left: 1,
-            right: 0,
-            output: 0,
+            modes: vec![1, 0, 0],
             opcode: 89,
         };
 
@@ -322,9 +322,7 @@ mod tests {
     #[test]
     fn parse_opcode_short2() {
         let expected = OpCodeWithModes {
-            left: 0,
-            right: 0,
-            output: 0,
+            modes: vec![0, 0, 0],
             opcode: 2,
         };
 
@@ -334,9 +332,7 @@ mod tests {
     #[test]
     fn example_program() {
         let expected = OpCodeWithModes {
-            left: 0,
-            right: 0,
-            output: 0,
+            modes: vec![0, 0, 0],
             opcode: 2,
         };
 
@@ -1 +1,36 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+// Day 5 is unfinished and doesn't build yet.
+// pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+// Day 10 is unfinished and doesn't build yet.
+// pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
 pub mod intcode;
+
+use util::aoc::solution::Day;
+
+pub fn solutions() -> Vec<Box<dyn Day>> {
+    util::solution_days![
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day11::Day11,
+        day12::Day12,
+        day13::Day13,
+        day14::Day14,
+    ]
+}
@@ -2,14 +2,27 @@ use std::collections::hash_set::HashSet;
 
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let lines: Vec<String> = input::read(1)?;
-    let expenses = lines.parse::<i32>()?;
+pub struct Day01;
 
-    result("Part 1", || part1(&expenses));
-    result("Part 2", || part2(&expenses));
+impl Solution for Day01 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 1;
 
-    Ok(())
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(expenses: &Vec<i32>) -> AocResult<i32> {
+        Ok(part1(expenses))
+    }
+
+    fn part2(expenses: &Vec<i32>) -> AocResult<i32> {
+        Ok(part2(expenses))
+    }
 }
 
 fn part1(expenses: &[i32]) -> i32 {
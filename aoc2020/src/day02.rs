@@ -7,16 +7,27 @@ lazy_static! {
     static ref RE: Regex = Regex::new("(\\d+)-(\\d+) ([a-z]): ([a-z]+)").unwrap();
 }
 
-fn main() -> AocResult<()> {
-    let passwords: Vec<Password> = input::read(2)?
-        .into_iter()
-        .map(|line| Password::parse(line))
-        .collect();
+pub struct Day02;
 
-    result("Part 1", || part1(&passwords));
-    result("Part 2", || part2(&passwords));
+impl Solution for Day02 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 2;
 
-    Ok(())
+    type Input = Vec<Password>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Password>> {
+        Ok(input.lines().map(Password::parse).collect())
+    }
+
+    fn part1(passwords: &Vec<Password>) -> AocResult<i32> {
+        Ok(part1(passwords))
+    }
+
+    fn part2(passwords: &Vec<Password>) -> AocResult<i32> {
+        Ok(part2(passwords))
+    }
 }
 
 fn part1(passwords: &[Password]) -> i32 {
@@ -42,14 +53,14 @@ fn part2(passwords: &[Password]) -> i32 {
 }
 
 #[derive(Debug)]
-struct Password {
+pub struct Password {
     password: Vec<char>,
     policy: PasswordPolicy,
 }
 
 impl Password {
-    fn parse(line: String) -> Password {
-        let captures = RE.captures(line.as_str()).unwrap();
+    fn parse(line: &str) -> Password {
+        let captures = RE.captures(line).unwrap();
         Password {
             password: captures[4].to_owned().chars().collect(),
             policy: PasswordPolicy {
@@ -75,9 +86,9 @@ mod tests {
     #[test]
     fn example_p1() {
         let passwords = vec![
-            Password::parse(String::from("1-3 a: abcde")),
-            Password::parse(String::from("1-3 b: cdefg")),
-            Password::parse(String::from("2-9 c: ccccccccc")),
+            Password::parse("1-3 a: abcde"),
+            Password::parse("1-3 b: cdefg"),
+            Password::parse("2-9 c: ccccccccc"),
         ];
 
         assert_eq!(2, part1(&passwords));
@@ -86,9 +97,9 @@ mod tests {
     #[test]
     fn example_p2() {
         let passwords = vec![
-            Password::parse(String::from("1-3 a: abcde")),
-            Password::parse(String::from("1-3 b: cdefg")),
-            Password::parse(String::from("2-9 c: ccccccccc")),
+            Password::parse("1-3 a: abcde"),
+            Password::parse("1-3 b: cdefg"),
+            Password::parse("2-9 c: ccccccccc"),
         ];
 
         assert_eq!(1, part2(&passwords));
@@ -1,17 +1,26 @@
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let geology: Vec<Vec<Entity>> = input::read(3)?
-        .into_iter()
-        .map(|line| parse_line(line))
-        .collect();
+pub struct Day03;
 
-    println!("{:?}", geology);
+impl Solution for Day03 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 3;
 
-    result("Part 1", || part1(&geology));
-    result("Part 2", || part2(&geology));
+    type Input = Vec<Vec<Entity>>;
+    type Output1 = i32;
+    type Output2 = i64;
 
-    Ok(())
+    fn parse(input: &str) -> AocResult<Vec<Vec<Entity>>> {
+        Ok(input.lines().map(parse_line).collect())
+    }
+
+    fn part1(geology: &Vec<Vec<Entity>>) -> AocResult<i32> {
+        Ok(part1(geology))
+    }
+
+    fn part2(geology: &Vec<Vec<Entity>>) -> AocResult<i64> {
+        Ok(part2(geology))
+    }
 }
 
 fn part1(geology: &[Vec<Entity>]) -> i32 {
@@ -19,7 +28,7 @@ fn part1(geology: &[Vec<Entity>]) -> i32 {
 }
 
 fn part2(geology: &[Vec<Entity>]) -> i64 {
-    let slopes = vec![vec![1, 1], vec![3, 1], vec![5, 1], vec![7, 1], vec![1, 2]];
+    let slopes = [vec![1, 1], vec![3, 1], vec![5, 1], vec![7, 1], vec![1, 2]];
 
     slopes
         .iter()
@@ -42,7 +51,7 @@ fn count_trees_on_slope(geology: &[Vec<Entity>], right: usize, down: usize) -> i
     trees
 }
 
-fn parse_line(line: String) -> Vec<Entity> {
+fn parse_line(line: &str) -> Vec<Entity> {
     line.chars()
         .map(|c| match c {
             '.' => Entity::Open,
@@ -53,7 +62,7 @@ fn parse_line(line: String) -> Vec<Entity> {
 }
 
 #[derive(Debug)]
-enum Entity {
+pub enum Entity {
     Open,
     Tree,
 }
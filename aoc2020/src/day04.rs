@@ -1,8 +1,8 @@
-use failure::_core::str::FromStr;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashSet;
+use std::str::FromStr;
 
 use util::aoc::*;
 
@@ -27,26 +27,36 @@ lazy_static! {
     .collect();
 }
 
-fn main() -> AocResult<()> {
-    let input = input::read_all(4)?;
-    let passports: Vec<Passport> = input
-        .split("\n\n")
-        .map(|passport_block| {
-            passport_block
-                .split(|c| c == '\n' || c == ' ')
-                .collect::<Vec<_>>()
-                .parse::<PassportProperty>()
-                .unwrap()
-        })
-        .map(Passport::new)
-        .collect();
-
-    println!("{:?}", passports);
-
-    result("Part 1", || part1(&passports));
-    result("Part 2", || part2(&passports));
-
-    Ok(())
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 4;
+
+    type Input = Vec<Passport>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Passport>> {
+        input
+            .split("\n\n")
+            .map(|passport_block| {
+                passport_block
+                    .split_whitespace()
+                    .map(str::parse::<PassportProperty>)
+                    .collect::<AocResult<Vec<_>>>()
+                    .map(Passport::new)
+            })
+            .collect()
+    }
+
+    fn part1(passports: &Vec<Passport>) -> AocResult<i32> {
+        Ok(part1(passports))
+    }
+
+    fn part2(passports: &Vec<Passport>) -> AocResult<i32> {
+        Ok(part2(passports))
+    }
 }
 
 fn part1(passports: &[Passport]) -> i32 {
@@ -58,7 +68,7 @@ fn part2(passports: &[Passport]) -> i32 {
 }
 
 #[derive(Debug)]
-struct Passport {
+pub struct Passport {
     properties: Vec<PassportProperty>,
     property_types: HashSet<PassportPropertyType>,
 }
@@ -98,6 +108,7 @@ enum PassportPropertyType {
 enum Height {
     Centimeters(u32),
     Inches(u32),
+    #[allow(dead_code)]
     Unknown(String),
 }
 
@@ -110,6 +121,7 @@ enum PassportProperty {
     HairColor(String),
     EyeColor(String),
     PassportId(String),
+    #[allow(dead_code)]
     CountryId(String),
 }
 
@@ -146,12 +158,12 @@ impl PassportProperty {
 }
 
 impl FromStr for PassportProperty {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts = s.split(":").collect::<Vec<_>>();
         if parts.len() != 2 {
-            failure::bail!("Expected key:value pair, found: {:?}", parts);
+            util::bail!("Expected key:value pair, found: {:?}", parts);
         }
 
         let kv = match parts[0] {
@@ -163,7 +175,7 @@ impl FromStr for PassportProperty {
             "ecl" => PassportProperty::EyeColor(parts[1].to_string()),
             "pid" => PassportProperty::PassportId(parts[1].to_string()),
             "cid" => PassportProperty::CountryId(parts[1].to_string()),
-            _ => failure::bail!("Unknown key: {}", parts[0]),
+            _ => util::bail!("Unknown key: {}", parts[0]),
         };
 
         Ok(kv)
@@ -171,7 +183,7 @@ impl FromStr for PassportProperty {
 }
 
 impl FromStr for Height {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let chars = s.chars().collect_vec();
@@ -179,7 +191,7 @@ impl FromStr for Height {
         let height = match chars[chars.len() - 2..].iter().join("").as_str() {
             "in" => Height::Inches(chars[..chars.len() - 2].iter().join("").parse()?),
             "cm" => Height::Centimeters(chars[..chars.len() - 2].iter().join("").parse()?),
-            input @ _ => Height::Unknown(input.to_string()),
+            input => Height::Unknown(input.to_string()),
         };
 
         Ok(height)
@@ -2,21 +2,30 @@ use itertools::{Itertools, MinMaxResult};
 
 use std::collections::HashSet;
 
-use failure::bail;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let occupied_seats: Vec<Seat> = input::read(5)?
-        .iter()
-        .map(|pass_code| decode_pass(pass_code))
-        .collect::<AocResult<Vec<_>>>()?;
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 5;
+
+    type Input = Vec<Seat>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    println!("Occupied size: {}", occupied_seats.len());
+    fn parse(input: &str) -> AocResult<Vec<Seat>> {
+        parsing::parse_lines_with(input, decode_pass)
+    }
 
-    result("Part 1", || part1(&occupied_seats));
-    result("Part 2", || part2(&occupied_seats))?;
+    fn part1(occupied_seats: &Vec<Seat>) -> AocResult<i32> {
+        Ok(part1(occupied_seats))
+    }
 
-    Ok(())
+    fn part2(occupied_seats: &Vec<Seat>) -> AocResult<i32> {
+        part2(occupied_seats)
+    }
 }
 
 fn part1(occupied_seats: &[Seat]) -> i32 {
@@ -30,14 +39,10 @@ fn part1(occupied_seats: &[Seat]) -> i32 {
 fn part2(occupied_seats: &[Seat]) -> AocResult<i32> {
     if let MinMaxResult::MinMax(min, max) = occupied_seats.iter().minmax_by_key(|k| k.seat_id()) {
         let possible_seats: HashSet<_> = (0..128)
-            .into_iter()
-            .flat_map(|row| {
-                let row = row.clone();
-                (0..8).into_iter().map(move |column| Seat::new(row, column))
-            })
+            .flat_map(|row| (0..8).map(move |column| Seat::new(row, column)))
             .filter(|seat| seat.seat_id() >= min.seat_id() && seat.seat_id() <= max.seat_id())
             .collect();
-        let occupied_seats: HashSet<_> = occupied_seats.into_iter().cloned().collect();
+        let occupied_seats: HashSet<_> = occupied_seats.iter().cloned().collect();
         let seat_candidates: Vec<_> = possible_seats.difference(&occupied_seats).collect_vec();
 
         if seat_candidates.len() == 1 {
@@ -71,17 +76,14 @@ fn decode_chunk(
             match *char {
                 c if c == lower_char => Ok((min, max - difference, min)),
                 c if c == upper_char => Ok((min + difference, max, max)),
-                _ => Err(failure::format_err!(
-                    "unexpected character in chunk: {}",
-                    char
-                )),
+                _ => Err(util::format_err!("unexpected character in chunk: {}", char)),
             }
         })
         .map(|(_, _, last)| last)
 }
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-struct Seat {
+pub struct Seat {
     row: i32,
     column: i32,
 }
@@ -106,6 +108,6 @@ mod tests {
         assert_eq!(Seat::new(70, 7), decode_pass("BFFFBBFRRR").unwrap());
         assert_eq!(Seat::new(14, 7), decode_pass("FFFBBBFRRR").unwrap());
         assert_eq!(Seat::new(102, 4), decode_pass("BBFFBBFRLL").unwrap());
-        assert_eq!(true, decode_pass("BABFFBBFRLL").is_err());
+        assert!(decode_pass("BABFFBBFRLL").is_err());
     }
 }
@@ -4,27 +4,41 @@ use util::aoc::*;
 
 type Answers = HashSet<char>;
 
-fn main() -> AocResult<()> {
-    let groups_with_answers: Vec<Group> = input::read_all(6)?
-        .split("\n\n")
-        .map(|group| {
-            group
-                .split("\n")
-                .map(|answers| {
-                    answers
-                        .chars()
-                        .filter(|c| c.is_alphabetic())
-                        .collect::<HashSet<_>>()
-                })
-                .collect()
-        })
-        .map(|answers| Group::new(answers))
-        .collect();
+pub struct Day06;
 
-    result("Part 1", || part1(&groups_with_answers));
-    result("Part 2", || part2(&groups_with_answers));
+impl Solution for Day06 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 6;
 
-    Ok(())
+    type Input = Vec<Group>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Vec<Group>> {
+        Ok(input
+            .split("\n\n")
+            .map(|group| {
+                group
+                    .lines()
+                    .map(|answers| {
+                        answers
+                            .chars()
+                            .filter(|c| c.is_alphabetic())
+                            .collect::<HashSet<_>>()
+                    })
+                    .collect()
+            })
+            .map(Group::new)
+            .collect())
+    }
+
+    fn part1(groups_with_answers: &Vec<Group>) -> AocResult<usize> {
+        Ok(part1(groups_with_answers))
+    }
+
+    fn part2(groups_with_answers: &Vec<Group>) -> AocResult<usize> {
+        Ok(part2(groups_with_answers))
+    }
 }
 
 fn part1(group_answers: &[Group]) -> usize {
@@ -42,7 +56,7 @@ fn part2(group_answers: &[Group]) -> usize {
 }
 
 #[derive(Debug)]
-struct Group {
+pub struct Group {
     form_answers: Vec<Answers>,
 }
 
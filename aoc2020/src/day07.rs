@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use util::aoc::*;
+use util::format_err;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new("(\\d+) ([a-z]+ [a-z]+) bag").unwrap();
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 7;
+
+    type Input = HashMap<String, Vec<Bag>>;
+    type Output1 = usize;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<HashMap<String, Vec<Bag>>> {
+        input
+            .lines()
+            .map(|line| {
+                let (bag_type, contents) = line
+                    .split_once(" bags contain ")
+                    .ok_or_else(|| format_err!("Expected a bag's contents, got {}", line))?;
+                let contained_bags = RE
+                    .captures_iter(contents)
+                    .map(|c| {
+                        Ok(Bag {
+                            count: c[1].parse()?,
+                            bag_type: c[2].to_string(),
+                        })
+                    })
+                    .collect::<AocResult<Vec<_>>>()?;
+
+                Ok((bag_type.to_string(), contained_bags))
+            })
+            .collect()
+    }
+
+    fn part1(bag_graph: &HashMap<String, Vec<Bag>>) -> AocResult<usize> {
+        Ok(part1(bag_graph))
+    }
+
+    fn part2(bag_graph: &HashMap<String, Vec<Bag>>) -> AocResult<i32> {
+        Ok(part2(bag_graph))
+    }
+}
+
+#[derive(Debug)]
+pub struct Bag {
+    count: i32,
+    bag_type: String,
+}
+
+fn part1(bag_graph: &HashMap<String, Vec<Bag>>) -> usize {
+    fn search<'a: 'b, 'b>(
+        bag_graph: &'a HashMap<String, Vec<Bag>>,
+        has_gold_bag: &'b mut HashMap<&'a str, bool>,
+        bag: &str,
+    ) -> bool {
+        if let Some(contains_bag) = has_gold_bag.get(bag) {
+            return *contains_bag;
+        }
+
+        if let Some(held_bags) = bag_graph.get(bag) {
+            for bag in held_bags {
+                if bag.bag_type == "shiny gold" || search(bag_graph, has_gold_bag, &bag.bag_type) {
+                    has_gold_bag.insert(&bag.bag_type, true);
+                    return true;
+                } else {
+                    has_gold_bag.insert(&bag.bag_type, false);
+                }
+            }
+        }
+
+        false
+    }
+
+    let mut has_gold_bag: HashMap<&str, bool> = HashMap::new();
+    bag_graph
+        .keys()
+        .filter(|&key| search(bag_graph, &mut has_gold_bag, key))
+        .count()
+}
+
+fn part2(bag_graph: &HashMap<String, Vec<Bag>>) -> i32 {
+    fn count_bags(bag_graph: &HashMap<String, Vec<Bag>>, key: &str) -> i32 {
+        if let Some(bags) = bag_graph.get(key) {
+            bags.iter()
+                .map(|bag| bag.count + bag.count * count_bags(bag_graph, &bag.bag_type))
+                .sum()
+        } else {
+            0
+        }
+    }
+
+    count_bags(bag_graph, "shiny gold")
+}
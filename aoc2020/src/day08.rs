@@ -1,20 +1,31 @@
 use std::collections::HashSet;
 
-use failure::_core::str::FromStr;
-use failure::bail;
 use itertools::Itertools;
+use std::str::FromStr;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let instructions: Vec<Instruction> = input::read(8)?
-        .iter()
-        .map(|line| line.parse())
-        .collect::<AocResult<Vec<_>>>()?;
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 8;
 
-    result("Part 1", || part1(&instructions))?;
-    result("Part 2", || part2(&instructions))?;
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Instruction>> {
+        parsing::parse_lines(input)
+    }
 
-    Ok(())
+    fn part1(instructions: &Vec<Instruction>) -> AocResult<i32> {
+        part1(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> AocResult<i32> {
+        part2(instructions)
+    }
 }
 
 fn part1(instructions: &[Instruction]) -> AocResult<i32> {
@@ -71,6 +82,7 @@ pub enum TracerAction {
     Halt(i32),
 }
 
+#[allow(dead_code)]
 #[derive(Default)]
 struct Printer;
 
@@ -162,7 +174,7 @@ pub enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split(" ");
@@ -1,17 +1,28 @@
-use failure::bail;
 use itertools::{Itertools, MinMaxResult};
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let outputs: Vec<i64> = input::read(9)?
-        .iter()
-        .map(|line| line.parse::<i64>().map_err(|e| e.into()))
-        .collect::<AocResult<Vec<_>>>()?;
+pub struct Day09;
 
-    result("Part 1", || part1(&outputs))?;
-    result("Part 2", || part2(&outputs))?;
+impl Solution for Day09 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 9;
 
-    Ok(())
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(outputs: &Vec<i64>) -> AocResult<i64> {
+        part1(outputs)
+    }
+
+    fn part2(outputs: &Vec<i64>) -> AocResult<i64> {
+        part2(outputs)
+    }
 }
 
 fn part1(outputs: &[i64]) -> AocResult<i64> {
@@ -48,7 +59,7 @@ fn part2(outputs: &[i64]) -> AocResult<i64> {
 
 fn find_weak_number(outputs: &[i64], preamble_size: usize) -> AocResult<i64> {
     for i in preamble_size..outputs.len() {
-        if let None = find_pair(&outputs[i - preamble_size..i], outputs[i]) {
+        if find_pair(&outputs[i - preamble_size..i], outputs[i]).is_none() {
             return Ok(outputs[i]);
         }
     }
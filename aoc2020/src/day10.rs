@@ -1,18 +1,29 @@
-use failure::bail;
 use itertools::Itertools;
 use std::collections::HashMap;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let adapters: Vec<i64> = input::read(10)?
-        .iter()
-        .map(|line| line.parse::<i64>().map_err(|e| e.into()))
-        .collect::<AocResult<Vec<_>>>()?;
+pub struct Day10;
 
-    result("Part 1", || part1(&adapters))?;
-    result("Part 2", || part2(&adapters));
+impl Solution for Day10 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 10;
 
-    Ok(())
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(adapters: &Vec<i64>) -> AocResult<i64> {
+        part1(adapters)
+    }
+
+    fn part2(adapters: &Vec<i64>) -> AocResult<i64> {
+        Ok(part2(adapters))
+    }
 }
 
 fn part1(adapters: &[i64]) -> AocResult<i64> {
@@ -1,17 +1,31 @@
-use failure::bail;
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::str::FromStr;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let grid: Grid = input::read(11)?.into_iter().collect();
+pub struct Day11;
 
-    result("Part 1", || part1(&grid));
-    result("Part 2", || part2(&grid));
+impl Solution for Day11 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 11;
 
-    Ok(())
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Grid> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(grid: &Grid) -> AocResult<usize> {
+        Ok(part1(grid))
+    }
+
+    fn part2(grid: &Grid) -> AocResult<usize> {
+        Ok(part2(grid))
+    }
 }
 
 fn part1(grid: &Grid) -> usize {
@@ -33,7 +47,7 @@ fn part2(grid: &Grid) -> usize {
 }
 
 #[derive(Debug, Clone)]
-struct Grid {
+pub struct Grid {
     states: Vec<Vec<State>>,
 }
 
@@ -60,7 +74,7 @@ impl Grid {
         self.states[point.y as usize][point.x as usize] = state;
     }
 
-    fn point_iterator(&self) -> PointIterator {
+    fn point_iterator(&self) -> PointIterator<'_> {
         PointIterator {
             grid: self,
             x: 0,
@@ -146,6 +160,7 @@ impl Grid {
         false
     }
 
+    #[allow(dead_code)]
     fn print_grid(&self) {
         for row in &self.states {
             for column in row {
@@ -216,7 +231,7 @@ impl State {
 }
 
 impl FromStr for State {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -1,16 +1,30 @@
-use failure::bail;
 use itertools::Itertools;
+use util::bail;
 
 use std::str::FromStr;
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let actions: Vec<Action> = input::read(12)?.parse()?;
+pub struct Day12;
 
-    result("Part 1", || part1(&actions));
-    result("Part 2", || part2(&actions));
+impl Solution for Day12 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 12;
 
-    Ok(())
+    type Input = Vec<Action>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Action>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(actions: &Vec<Action>) -> AocResult<i32> {
+        Ok(part1(actions))
+    }
+
+    fn part2(actions: &Vec<Action>) -> AocResult<i32> {
+        Ok(part2(actions))
+    }
 }
 
 fn part1(actions: &[Action]) -> i32 {
@@ -58,7 +72,6 @@ impl Ship {
             }
             Action::Forward(_steps) => panic!("untranslated forward action!"),
         };
-        println!("A: pos: {:?}, dir: {:?}", self.position, self.direction);
     }
 
     fn apply_p2(&mut self, action: Action) {
@@ -83,7 +96,7 @@ impl Ship {
 }
 
 #[derive(Debug, Copy, Clone)]
-enum Action {
+pub enum Action {
     North(i32),
     South(i32),
     East(i32),
@@ -110,7 +123,7 @@ impl Action {
 }
 
 impl FromStr for Action {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let chars = s.chars().collect_vec();
@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use util::{bail, format_err};
+
+use util::aoc::math::crt;
+use util::aoc::*;
+
+/// The earliest timestamp you could leave, and each bus in service with its offset in the list.
+type Notes = (i64, Vec<(usize, i64)>);
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 13;
+
+    type Input = Notes;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Notes> {
+        let mut lines = input.lines();
+        let (Some(earliest_timestamp), Some(buses)) = (lines.next(), lines.next()) else {
+            bail!("Expected a timestamp and a list of buses");
+        };
+        let active_buses_with_offset = buses
+            .split(',')
+            .enumerate()
+            .filter_map(|(index, str)| str.parse::<i64>().ok().map(|bus_id| (index, bus_id)))
+            .collect_vec();
+
+        Ok((earliest_timestamp.parse()?, active_buses_with_offset))
+    }
+
+    fn part1((earliest_timestamp, active_buses_with_offset): &Notes) -> AocResult<i64> {
+        part1(*earliest_timestamp, active_buses_with_offset)
+    }
+
+    fn part2((_, active_buses_with_offset): &Notes) -> AocResult<i64> {
+        part2(active_buses_with_offset)
+    }
+}
+
+fn part1(earliest_timestamp: i64, active_buses: &[(usize, i64)]) -> AocResult<i64> {
+    if let Some((bus_id, minutes_to_departure)) = active_buses
+        .iter()
+        .map(|&(_, bus_id)| {
+            let next_departure_time =
+                ((earliest_timestamp as f64) / (bus_id as f64)).ceil() as i64 * bus_id;
+            let minutes_to_departure = next_departure_time - earliest_timestamp;
+
+            (bus_id, minutes_to_departure)
+        })
+        .min_by_key(|(_, next)| *next)
+    {
+        Ok(bus_id * minutes_to_departure)
+    } else {
+        bail!("Did not find a bus.");
+    }
+}
+
+fn part2(bus_lines: &[(usize, i64)]) -> AocResult<i64> {
+    // Whoo, chinese remainder theorem.
+    // We want to setup a system of linear congruences such that:
+    // (time + index) mod bus_id = 0, which we can rewrite as
+    // time mod bus_id = bus_id - index. For the CRT to work, all Ns
+    // need to be the same (the time), so we move the index to the
+    // right side of the equation (modular arithmetic style, of course).
+    //
+    // ex, for the sample problem: 67,x,7,59,61
+    // time = 67 - 0 (mod 67)
+    // time = 7 - 2  (mod 7)
+    // time = 59 - 3 (mod 59)
+    // time = 61 - 4 (mod 61)
+
+    let congruences = bus_lines
+        .iter()
+        .map(|&(index, bus_id)| (bus_id - index as i64, bus_id))
+        .collect_vec();
+
+    crt(&congruences)
+        .map(|(time, _)| time)
+        .ok_or_else(|| format_err!("bus schedules never line up"))
+}
@@ -1,10 +1,10 @@
-use failure::{bail, format_err};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
 use std::str::FromStr;
 use util::aoc::*;
+use util::{bail, format_err};
 
 type MemoryWrite = (usize, i64);
 
@@ -14,17 +14,31 @@ lazy_static! {
 
 const MASK_BITS: usize = 36;
 
-fn main() -> AocResult<()> {
-    let input = input::read_all(14)?
-        .split("mask = ")
-        .filter(|s| !s.is_empty())
-        .map(|s| s.parse())
-        .collect::<AocResult<Vec<Stanza>>>()?;
+pub struct Day14;
 
-    result("Part 1", || part1(&input))?;
-    result("Part 2", || part2(&input))?;
+impl Solution for Day14 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 14;
 
-    Ok(())
+    type Input = Vec<Stanza>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<Stanza>> {
+        input
+            .split("mask = ")
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse())
+            .collect()
+    }
+
+    fn part1(stanzas: &Vec<Stanza>) -> AocResult<i64> {
+        part1(stanzas)
+    }
+
+    fn part2(stanzas: &Vec<Stanza>) -> AocResult<i64> {
+        part2(stanzas)
+    }
 }
 
 fn part1(stanzas: &[Stanza]) -> AocResult<i64> {
@@ -88,8 +102,7 @@ pub fn translate_address(mask: &[Option<u8>], address: usize) -> Vec<i64> {
     for (idx, mask_bit) in mask.iter().enumerate().take(MASK_BITS) {
         if mask_bit.is_none() {
             for i in 0..addresses.len() {
-                addresses.push(addresses[i] & !(1 << idx));
-                addresses.push(addresses[i] | 1 << idx);
+                addresses.push(addresses[i] ^ 1 << idx);
             }
         }
     }
@@ -98,13 +111,13 @@ pub fn translate_address(mask: &[Option<u8>], address: usize) -> Vec<i64> {
 }
 
 #[derive(Debug)]
-struct Stanza {
+pub struct Stanza {
     mask: Vec<Option<u8>>,
     writes: Vec<MemoryWrite>,
 }
 
 impl FromStr for Stanza {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines = s.lines().collect_vec();
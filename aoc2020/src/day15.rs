@@ -2,24 +2,35 @@ use std::collections::HashMap;
 
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let input = input::read(15)?[0]
-        .split(',')
-        .map(|s| s.parse::<i64>().map_err(|e| e.into()))
-        .collect::<AocResult<Vec<i64>>>()?;
+pub struct Day15;
 
-    result("Part 1", || part1(&input));
-    result("Part 2", || part2(&input));
+impl Solution for Day15 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 15;
 
-    Ok(())
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<i64>> {
+        input.trim().split(',').map(|s| Ok(s.parse()?)).collect()
+    }
+
+    fn part1(starting_numbers: &Vec<i64>) -> AocResult<i64> {
+        Ok(part1(starting_numbers))
+    }
+
+    fn part2(starting_numbers: &Vec<i64>) -> AocResult<i64> {
+        Ok(part2(starting_numbers))
+    }
 }
 
 fn part1(starting_numbers: &[i64]) -> i64 {
-    solve(&starting_numbers, 2020)
+    solve(starting_numbers, 2020)
 }
 
 fn part2(starting_numbers: &[i64]) -> i64 {
-    solve(&starting_numbers, 30000000)
+    solve(starting_numbers, 30000000)
 }
 
 fn solve(starting_numbers: &[i64], end_turn: usize) -> i64 {
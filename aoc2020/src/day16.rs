@@ -1,48 +1,79 @@
-use failure::format_err;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
-use std::ops::RangeInclusive;
 use std::str::FromStr;
+use util::aoc::intervals::RangeSet;
 use util::aoc::*;
+use util::format_err;
 
 lazy_static! {
     static ref RE: Regex = Regex::new("([^:]+): (\\d+)-(\\d+) or (\\d+)-(\\d+)").unwrap();
 }
 
-fn main() -> AocResult<()> {
-    let input = input::read_all(16)?;
-    let sections = input.split("\n\n").collect_vec();
-    let properties = sections[0]
-        .lines()
-        .map(|l| l.parse::<Property>().map_err(|e| e.into()))
-        .collect::<AocResult<Vec<Property>>>()?;
-    let my_ticket: Vec<i64> = sections[1].lines().collect_vec()[1]
-        .split(",")
-        .collect_vec()
-        .parse()?;
-    let nearby_tickets: Vec<Vec<i64>> = sections[2].lines().collect_vec()[1..]
-        .iter()
-        .map(|ticket| {
-            ticket
-                .split(",")
-                .map(|v| v.parse::<i64>().map_err(|e| e.into()))
-                .collect::<AocResult<Vec<i64>>>()
+/// The ticket rules, your ticket and the tickets nearby.
+pub struct Notes {
+    properties: Vec<Property>,
+    my_ticket: Vec<i64>,
+    nearby_tickets: Vec<Vec<i64>>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 16;
+
+    type Input = Notes;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Notes> {
+        let sections = input.split("\n\n").collect_vec();
+        if sections.len() != 3 {
+            return Err(format_err!(
+                "Expected three sections, found {}",
+                sections.len()
+            ));
+        }
+        let parse_ticket = |ticket: &str| -> AocResult<Vec<i64>> {
+            ticket.split(',').map(|v| Ok(v.parse()?)).collect()
+        };
+
+        Ok(Notes {
+            properties: parsing::parse_lines(sections[0])?,
+            my_ticket: sections[1]
+                .lines()
+                .nth(1)
+                .map(parse_ticket)
+                .ok_or_else(|| format_err!("Missing your ticket"))??,
+            nearby_tickets: sections[2]
+                .lines()
+                .skip(1)
+                .map(parse_ticket)
+                .collect::<AocResult<_>>()?,
         })
-        .collect::<AocResult<Vec<Vec<i64>>>>()?;
+    }
 
-    result("Part 1", || part1(&properties, &nearby_tickets));
-    result("Part 2", || part2(&properties, &my_ticket, &nearby_tickets))?;
+    fn part1(notes: &Notes) -> AocResult<i64> {
+        Ok(part1(&notes.properties, &notes.nearby_tickets))
+    }
 
-    Ok(())
+    fn part2(notes: &Notes) -> AocResult<i64> {
+        part2(&notes.properties, &notes.my_ticket, &notes.nearby_tickets)
+    }
 }
 
 fn part1(properties: &[Property], nearby_tickets: &[Vec<i64>]) -> i64 {
+    let mut any_property = RangeSet::new();
+    properties
+        .iter()
+        .for_each(|property| any_property.merge(&property.bounds));
+
     nearby_tickets
         .iter()
         .flat_map(|ticket| ticket.iter())
-        .filter(|&&v| !properties.iter().any(|property| property.contains(v)))
+        .filter(|&v| !any_property.contains(v))
         .sum()
 }
 
@@ -105,26 +136,26 @@ fn part2(
     let result = column_to_property
         .iter()
         .filter(|(_, name)| name.starts_with("departure"))
-        .map(|(&column, _)| my_ticket[column as usize])
+        .map(|(&column, _)| my_ticket[column])
         .product();
 
     Ok(result)
 }
 
 #[derive(Debug)]
-struct Property {
+pub struct Property {
     name: String,
-    bounds: Vec<RangeInclusive<i64>>,
+    bounds: RangeSet<i64>,
 }
 
 impl Property {
     fn contains(&self, value: i64) -> bool {
-        self.bounds.iter().any(|b| b.contains(&value))
+        self.bounds.contains(&value)
     }
 }
 
 impl FromStr for Property {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let c = RE
@@ -134,9 +165,11 @@ impl FromStr for Property {
         Ok(Property {
             name: c[1].to_string(),
             bounds: vec![
-                RangeInclusive::new(c[2].parse::<i64>()?, c[3].parse::<i64>()?),
-                RangeInclusive::new(c[4].parse::<i64>()?, c[5].parse::<i64>()?),
-            ],
+                c[2].parse::<i64>()?..=c[3].parse::<i64>()?,
+                c[4].parse::<i64>()?..=c[5].parse::<i64>()?,
+            ]
+            .into_iter()
+            .collect(),
         })
     }
 }
@@ -1,43 +1,47 @@
-
-
-
 use std::collections::HashMap;
 
-
-use util::aoc::*;
 use itertools::Itertools;
-use failure::_core::hash::Hash;
-
-fn main() -> AocResult<()> {
-    let input: HashMap<Point3D, bool> = Itertools::flatten(input::read(17)?
-        .into_iter()
-        .enumerate()
-        .map(|(row, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(column, char)| {
-                    let active = match char {
-                        '.' => false,
-                        '#' => true,
-                        _ => panic!("unexpected character: {}", char),
-                    };
-
-                    (Point3D::new(row as i32, column as i32, 0), active)
-                })
-                .collect::<Vec<_>>()
-        }))
-        .collect();
-
-    let input_p2: HashMap<Point4D, bool> = input
-        .iter()
-        .map(|(k, &v)| (Point4D::new(k.x, k.y, k.z, 0), v))
-        .collect();
-    
+use std::hash::Hash;
+use util::aoc::*;
+use util::bail;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 17;
+
+    type Input = HashMap<Point3D, bool>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<HashMap<Point3D, bool>> {
+        let mut state = HashMap::new();
+        for (row, line) in input.lines().enumerate() {
+            for (column, char) in line.chars().enumerate() {
+                let active = match char {
+                    '.' => false,
+                    '#' => true,
+                    _ => bail!("unexpected character: {}", char),
+                };
+
+                state.insert(Point3D::new(row as i32, column as i32, 0), active);
+            }
+        }
+        Ok(state)
+    }
 
-    result("Part 1", || part1(&input));
-    result("Part 2", || part2(&input_p2));
+    fn part1(state: &HashMap<Point3D, bool>) -> AocResult<usize> {
+        Ok(part1(state))
+    }
 
-    Ok(())
+    fn part2(state: &HashMap<Point3D, bool>) -> AocResult<usize> {
+        let state = state
+            .iter()
+            .map(|(k, &v)| (Point4D::new(k.x, k.y, k.z, 0), v))
+            .collect();
+        Ok(part2(&state))
+    }
 }
 
 fn part1(state: &HashMap<Point3D, bool>) -> usize {
@@ -51,8 +55,6 @@ fn part2(state: &HashMap<Point4D, bool>) -> usize {
 fn solve<T: Cube>(state: &HashMap<T, bool>) -> usize {
     let mut state = state.clone();
     for _ in 0..6 {
-        println!("active cubes: {}", state.values().filter(|&&v| v).count());
-
         let old_state = state.clone();
 
         // find cubes to disable
@@ -69,15 +71,15 @@ fn solve<T: Cube>(state: &HashMap<T, bool>) -> usize {
                 !(active_neighbors == 2 || active_neighbors == 3)
             })
             .for_each(|(p, _)| {
-                state.insert(p.clone(), false);
+                state.insert(*p, false);
             });
 
         // find cubes to activate by looking at all the neighbors of all active points
         old_state
-            .iter()
-            .flat_map(|(p, _)| {
+            .keys()
+            .flat_map(|p| {
                 let mut vec = p.neighbors();
-                vec.push(p.clone());
+                vec.push(*p);
                 vec
             })
             .unique()
@@ -90,7 +92,7 @@ fn solve<T: Cube>(state: &HashMap<T, bool>) -> usize {
                     == 3
             })
             .for_each(|p| {
-                state.insert(p.clone(), true);
+                state.insert(p, true);
             });
     }
 
@@ -98,7 +100,9 @@ fn solve<T: Cube>(state: &HashMap<T, bool>) -> usize {
 }
 
 trait Cube: Eq + Hash + Copy + Clone + PartialEq {
-    fn neighbors(&self) -> Vec<Self> where Self: Sized;
+    fn neighbors(&self) -> Vec<Self>
+    where
+        Self: Sized;
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -118,8 +122,9 @@ impl Cube for Point3D {
     fn neighbors(&self) -> Vec<Point3D> {
         (self.x - 1..=(self.x + 1))
             .flat_map(move |x| {
-                (self.y - 1..=(self.y + 1))
-                    .flat_map(move |y| (self.z - 1..=(self.z + 1)).map(move |z| Point3D::new(x, y, z)))
+                (self.y - 1..=(self.y + 1)).flat_map(move |y| {
+                    (self.z - 1..=(self.z + 1)).map(move |z| Point3D::new(x, y, z))
+                })
             })
             .filter(|p| p != self)
             .collect()
@@ -144,16 +149,13 @@ impl Cube for Point4D {
     fn neighbors(&self) -> Vec<Point4D> {
         (self.x - 1..=(self.x + 1))
             .flat_map(move |x| {
-                (self.y - 1..=(self.y + 1))
-                    .flat_map(move |y| {
-                        (self.z - 1..=(self.z + 1)).flat_map(move |z| {
-                            (self.w - 1..=(self.w + 1)).map(move |w| {
-                                Point4D::new(x, y, z, w)
-                            })
-                        })
+                (self.y - 1..=(self.y + 1)).flat_map(move |y| {
+                    (self.z - 1..=(self.z + 1)).flat_map(move |z| {
+                        (self.w - 1..=(self.w + 1)).map(move |w| Point4D::new(x, y, z, w))
                     })
+                })
             })
             .filter(|p| p != self)
             .collect()
     }
-}
\ No newline at end of file
+}
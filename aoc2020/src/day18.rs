@@ -0,0 +1,78 @@
+use pest::iterators::{Pair, Pairs};
+use pest::pratt_parser::{Assoc, Op, PrattParser};
+use pest::Parser;
+use pest_derive::Parser;
+use util::aoc::*;
+
+#[derive(Parser)]
+#[grammar = "day18.pest"]
+struct MathParser;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 18;
+
+    type Input = Vec<String>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<String>> {
+        parsing::parse_lines_with(input, |expr| {
+            MathParser::parse(Rule::expr, expr).map(|_| expr.to_string())
+        })
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<i64> {
+        Ok(part1(expressions(lines)))
+    }
+
+    fn part2(lines: &Vec<String>) -> AocResult<i64> {
+        Ok(part2(expressions(lines)))
+    }
+}
+
+/// Tokenizes expressions that `parse` has already checked.
+fn expressions(lines: &[String]) -> Vec<Pairs<'_, Rule>> {
+    lines
+        .iter()
+        .map(|expr| MathParser::parse(Rule::expr, expr).expect("checked by parse"))
+        .collect()
+}
+
+fn part1(expressions: Vec<Pairs<Rule>>) -> i64 {
+    let pratt_parser = PrattParser::new()
+        .op(Op::infix(Rule::add, Assoc::Left) | Op::infix(Rule::multiply, Assoc::Left));
+
+    expressions
+        .into_iter()
+        .map(|e| eval(&pratt_parser, e))
+        .sum()
+}
+
+fn part2(expressions: Vec<Pairs<Rule>>) -> i64 {
+    let pratt_parser = PrattParser::new()
+        .op(Op::infix(Rule::multiply, Assoc::Left))
+        .op(Op::infix(Rule::add, Assoc::Left));
+
+    expressions
+        .into_iter()
+        .map(|e| eval(&pratt_parser, e))
+        .sum()
+}
+
+fn eval(pratt_parser: &PrattParser<Rule>, expression: Pairs<Rule>) -> i64 {
+    pratt_parser
+        .map_primary(|pair: Pair<Rule>| match pair.as_rule() {
+            Rule::num => pair.as_str().parse::<i64>().unwrap(),
+            Rule::expr => eval(pratt_parser, pair.into_inner()),
+            _ => unreachable!(),
+        })
+        .map_infix(|lhs: i64, op: Pair<Rule>, rhs: i64| match op.as_rule() {
+            Rule::add => lhs + rhs,
+            Rule::multiply => lhs * rhs,
+            _ => unreachable!(),
+        })
+        .parse(expression)
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use regex::Regex;
+use util::aoc::*;
+use util::{bail, format_err};
+
+// 0: 4 1 5
+// 1: 2 3 | 3 2
+// 2: 4 4 | 5 5
+// 3: 4 5 | 5 4
+// 4: "a"
+// 5: "b"
+//
+// ababbb
+// bababa
+// abbbab
+// aaabbb
+// aaaabbb
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 19;
+
+    type Input = (Regex, Vec<String>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PARTS: &'static [u32] = &[1];
+
+    fn parse(input: &str) -> AocResult<(Regex, Vec<String>)> {
+        let (rules, messages) = input
+            .split_once("\n\n")
+            .ok_or_else(|| format_err!("Expected rules, a blank line, then messages"))?;
+        let rules: HashMap<usize, Vec<String>> = rules
+            .lines()
+            .map(|l| {
+                let (rule, sub_rules) = l
+                    .split_once(": ")
+                    .ok_or_else(|| format_err!("Expected a rule, got {}", l))?;
+                let sub_rules = sub_rules
+                    .replace('"', "")
+                    .split(" ")
+                    .map(|v| v.to_string())
+                    .collect_vec();
+                Ok((rule.parse()?, sub_rules))
+            })
+            .collect::<AocResult<_>>()?;
+
+        // recurse into each number token, look up the underlying pattern. Apply parens.
+        // a((aa|bb)|(45|54)| 3 2)b
+        let start = rules.get(&0).ok_or_else(|| format_err!("Missing rule 0"))?;
+        let regex = ["^", replace_rules2(&rules, start).as_str(), "$"].join("");
+
+        Ok((
+            Regex::new(&regex)?,
+            messages.lines().map(String::from).collect(),
+        ))
+    }
+
+    fn part1((re, messages): &(Regex, Vec<String>)) -> AocResult<usize> {
+        Ok(part1(re, messages))
+    }
+
+    fn part2(_: &(Regex, Vec<String>)) -> AocResult<usize> {
+        bail!("Part 2 isn't solved yet")
+    }
+}
+
+fn part1(re: &Regex, input: &[String]) -> usize {
+    input.iter().filter(|f| re.is_match(f)).count()
+}
+
+#[allow(dead_code)]
+fn replace_rules(rules: &[Vec<char>], starting_rule: &[char]) -> String {
+    let mut rule = starting_rule.to_vec();
+    while rule.iter().any(|v| v.is_numeric()) {
+        let mut rule_part = Vec::new();
+        for part in rule {
+            if part.is_numeric() {
+                rule_part.push('(');
+                rule_part.extend(rules[part.to_digit(10).unwrap() as usize].clone());
+                rule_part.push(')');
+            } else {
+                rule_part.push(part)
+            }
+        }
+        rule = rule_part;
+    }
+
+    rule.iter().join("")
+}
+
+fn replace_rules2(rules: &HashMap<usize, Vec<String>>, starting_rule: &[String]) -> String {
+    let mut regex = String::new();
+    for c in starting_rule {
+        match c.parse::<usize>() {
+            Ok(v) => {
+                regex.push('(');
+                regex.push_str(&replace_rules2(rules, &rules[&v]));
+                regex.push(')');
+            }
+            Err(_) => regex.push_str(c),
+        }
+    }
+
+    regex
+}
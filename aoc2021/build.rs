@@ -0,0 +1,3 @@
+fn main() {
+    util::aoc::examples::build_script();
+}
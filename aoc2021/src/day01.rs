@@ -0,0 +1,41 @@
+use itertools::Itertools;
+use util::aoc::*;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(depths: &Vec<i32>) -> AocResult<i32> {
+        Ok(part1(depths))
+    }
+
+    fn part2(depths: &Vec<i32>) -> AocResult<i32> {
+        Ok(part2(depths))
+    }
+}
+
+fn part1(depths: &[i32]) -> i32 {
+    depths
+        .iter()
+        .tuple_windows()
+        .filter(|&(a, b)| a < b)
+        .count() as i32
+}
+
+fn part2(depths: &[i32]) -> i32 {
+    depths
+        .iter()
+        .tuple_windows()
+        .filter(|&(a, b, c, d)| (a + b + c) < (b + c + d))
+        .count() as i32
+}
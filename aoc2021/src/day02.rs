@@ -1,15 +1,28 @@
-use failure::bail;
-use itertools::Itertools;
 use std::str::FromStr;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let course: Vec<CourseStep> = input::read(2)?.parse()?;
+pub struct Day02;
 
-    result("Part 1", || part1(&course));
-    result("Part 2", || part2(&course));
+impl Solution for Day02 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 2;
 
-    Ok(())
+    type Input = Vec<CourseStep>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<CourseStep>> {
+        parsing::parse_lines(input)
+    }
+
+    fn part1(course: &Vec<CourseStep>) -> AocResult<i32> {
+        Ok(part1(course))
+    }
+
+    fn part2(course: &Vec<CourseStep>) -> AocResult<i32> {
+        Ok(part2(course))
+    }
 }
 
 fn part1(course: &[CourseStep]) -> i32 {
@@ -45,7 +58,7 @@ fn part2(course: &[CourseStep]) -> i32 {
 }
 
 #[derive(Debug, Copy, Clone)]
-enum CourseStep {
+pub enum CourseStep {
     Forward(i32),
     Down(i32),
     Up(i32),
@@ -54,7 +67,7 @@ enum CourseStep {
 impl CourseStep {}
 
 impl FromStr for CourseStep {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some((action, units)) = s.split_once(" ") {
@@ -70,44 +83,3 @@ impl FromStr for CourseStep {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn final_depth_and_position_p1() {
-        let course = vec![
-            "forward 5",
-            "down 5",
-            "forward 8",
-            "up 3",
-            "down 8",
-            "forward 2",
-        ];
-        let course = course
-            .iter()
-            .map(|s| s.parse::<CourseStep>().unwrap())
-            .collect_vec();
-
-        assert_eq!(150, part1(&course));
-    }
-
-    #[test]
-    fn final_depth_and_position_p2() {
-        let course = vec![
-            "forward 5",
-            "down 5",
-            "forward 8",
-            "up 3",
-            "down 8",
-            "forward 2",
-        ];
-        let course = course
-            .iter()
-            .map(|s| s.parse::<CourseStep>().unwrap())
-            .collect_vec();
-
-        assert_eq!(900, part2(&course));
-    }
-}
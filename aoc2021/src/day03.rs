@@ -1,17 +1,30 @@
-use failure::bail;
-use std::cmp::Ordering;
 use std::hash::Hash;
+use util::aoc::digits::from_digits;
 use util::aoc::frequency::FrequencyMap;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let diagnostic_bits: Vec<String> = input::read(3)?;
-    let columns = columnize(diagnostic_bits);
+pub struct Day03;
 
-    result("Part 1", || part1(&columns));
-    result("Part 2", || part2(&columns));
+impl Solution for Day03 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 3;
 
-    Ok(())
+    type Input = ColumnValues<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<ColumnValues<i32>> {
+        Ok(columnize(input.lines().map(String::from).collect()))
+    }
+
+    fn part1(columns: &ColumnValues<i32>) -> AocResult<i32> {
+        Ok(part1(columns))
+    }
+
+    fn part2(columns: &ColumnValues<i32>) -> AocResult<i32> {
+        Ok(part2(columns))
+    }
 }
 
 fn columnize(input: Vec<String>) -> ColumnValues<i32> {
@@ -68,13 +81,9 @@ fn find_common_values(values: &[Vec<&i32>], index: usize, default_if_equal: i32)
     let mut columns = ColumnValues::new(values[0].len());
     columns.add_rows(values).unwrap();
 
-    let zero_freq = columns.column_frequency(index).count(&&0);
-    let one_freq = columns.column_frequency(index).count(&&1);
-
-    match zero_freq.cmp(&one_freq) {
-        Ordering::Less => (1, 0),
-        Ordering::Equal => (default_if_equal, default_if_equal),
-        Ordering::Greater => (0, 1),
+    match columns.column_frequency(index).max_keys().as_slice() {
+        [&&most_common] => (most_common, 1 - most_common),
+        _ => (default_if_equal, default_if_equal),
     }
 }
 
@@ -89,12 +98,7 @@ fn filter_rows(rows: Vec<Vec<&i32>>, i: usize, most_common: i32) -> Vec<Vec<&i32
 }
 
 fn vec_to_digit(row: &[&i32]) -> i32 {
-    let mut value = 0;
-    for i in 0..row.len() {
-        let exp = row.len() - i - 1;
-        value += row[i] * 2i32.pow(exp as u32);
-    }
-    value
+    from_digits(row.iter().map(|&&bit| bit), 2)
 }
 
 pub struct ColumnValues<T: Clone + Eq + Hash> {
@@ -172,22 +176,11 @@ mod tests {
     use super::*;
     use itertools::Itertools;
 
-    const SAMPLE_INPUT: &'static str = r#"00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010"#;
+    const SAMPLE_INPUT: &str = include_str!("../puzzle_examples/day3/example.txt");
 
     #[test]
     fn column_values() {
-        let sample = SAMPLE_INPUT.split("\n").map(|s| s.to_string());
+        let sample = SAMPLE_INPUT.lines().map(|s| s.to_string());
         let columns: ColumnValues<i32> = columnize(sample.collect());
 
         assert_eq!(vec![0, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0], columns.column(0));
@@ -196,7 +189,7 @@ mod tests {
 
     #[test]
     fn rows() {
-        let sample = SAMPLE_INPUT.split("\n").map(|s| s.to_string());
+        let sample = SAMPLE_INPUT.lines().map(|s| s.to_string());
         let columns: ColumnValues<i32> = columnize(sample.collect());
 
         let rows = columns.rows();
@@ -205,20 +198,4 @@ mod tests {
             rows[0].iter().map(|f| **f).collect_vec()
         );
     }
-
-    #[test]
-    fn part1_sample() {
-        let sample = SAMPLE_INPUT.split("\n").map(|s| s.to_string());
-        let columns: ColumnValues<i32> = columnize(sample.collect());
-
-        assert_eq!(198, part1(&columns));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let sample = SAMPLE_INPUT.split("\n").map(|s| s.to_string());
-        let columns: ColumnValues<i32> = columnize(sample.collect());
-
-        assert_eq!(230, part2(&columns));
-    }
 }
@@ -1,18 +1,34 @@
-use crate::Spot::{Marked, Unmarked};
+use self::Spot::{Marked, Unmarked};
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let input: String = input::read_all(4)?;
-    let (drawn_numbers, boards) = parse(&input);
+pub struct Day04;
 
-    result("Part 1", || part1(&drawn_numbers, boards.clone()));
-    result("Part 2", || part2(&drawn_numbers, boards.clone()));
+impl Solution for Day04 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 4;
 
-    Ok(())
+    type Input = (Vec<i32>, Vec<BingoBoard>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<(Vec<i32>, Vec<BingoBoard>)> {
+        Ok(parse(input))
+    }
+
+    fn part1(bingo: &(Vec<i32>, Vec<BingoBoard>)) -> AocResult<i32> {
+        let (drawn_numbers, boards) = bingo;
+        Ok(part1(drawn_numbers, boards.clone()))
+    }
+
+    fn part2(bingo: &(Vec<i32>, Vec<BingoBoard>)) -> AocResult<i32> {
+        let (drawn_numbers, boards) = bingo;
+        Ok(part2(drawn_numbers, boards.clone()))
+    }
 }
 
 fn parse(input: &str) -> (Vec<i32>, Vec<BingoBoard>) {
-    let mut parts = input.split("\r\n\r\n");
+    let input = input.replace("\r\n", "\n");
+    let mut parts = input.split("\n\n");
     let drawn_numbers = parts
         .next()
         .unwrap()
@@ -73,7 +89,7 @@ impl Spot {
 }
 
 #[derive(Clone, Debug)]
-struct BingoBoard {
+pub struct BingoBoard {
     numbers: Vec<Spot>,
 }
 
@@ -129,42 +145,3 @@ impl BingoBoard {
         false
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7"#;
-
-    #[test]
-    fn part1_sample() {
-        let (drawn_numbers, boards) = parse(SAMPLE_INPUT);
-
-        assert_eq!(4512, part1(&drawn_numbers, boards));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let (drawn_numbers, boards) = parse(SAMPLE_INPUT);
-
-        assert_eq!(1924, part2(&drawn_numbers, boards));
-    }
-}
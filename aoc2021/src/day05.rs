@@ -0,0 +1,64 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use util::aoc::lines::{LineSegment, SegmentSweep};
+use util::aoc::*;
+use util::bail;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new("(\\d+),(\\d+) -> (\\d+),(\\d+)").unwrap();
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 5;
+
+    type Input = Vec<LineSegment>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> AocResult<Vec<LineSegment>> {
+        parse(input.lines().map(String::from).collect())
+    }
+
+    fn part1(vents: &Vec<LineSegment>) -> AocResult<u64> {
+        part1(vents)
+    }
+
+    fn part2(vents: &Vec<LineSegment>) -> AocResult<u64> {
+        part2(vents)
+    }
+}
+
+fn parse(input: Vec<String>) -> AocResult<Vec<LineSegment>> {
+    input
+        .iter()
+        .map(|line| {
+            if let Some(captures) = RE.captures(line.as_str()) {
+                Ok(LineSegment::new(
+                    Point::new(captures[1].trim().parse()?, captures[2].trim().parse()?),
+                    Point::new(captures[3].trim().parse()?, captures[4].trim().parse()?),
+                ))
+            } else {
+                bail!("Didn't match input: {}", line);
+            }
+        })
+        .collect::<AocResult<_>>()
+}
+
+fn part1(vents: &[LineSegment]) -> AocResult<u64> {
+    solve(
+        vents
+            .iter()
+            .filter(|vent| vent.start.x == vent.end.x || vent.start.y == vent.end.y),
+    )
+}
+
+fn part2(vents: &[LineSegment]) -> AocResult<u64> {
+    solve(vents.iter())
+}
+
+fn solve<'a>(vents: impl Iterator<Item = &'a LineSegment>) -> AocResult<u64> {
+    Ok(SegmentSweep::new(vents)?.count_covered(2))
+}
@@ -0,0 +1,56 @@
+use std::num::ParseIntError;
+use util::aoc::algebra::Matrix;
+use util::aoc::frequency::FrequencyMap;
+use util::aoc::*;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 6;
+
+    type Input = Vec<i32>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        input
+            .trim()
+            .split(',')
+            .map(|value| value.parse().map_err(|e: ParseIntError| e.into()))
+            .collect()
+    }
+
+    fn part1(fish: &Vec<i32>) -> AocResult<u64> {
+        Ok(part1(fish))
+    }
+
+    fn part2(fish: &Vec<i32>) -> AocResult<u64> {
+        Ok(part2(fish))
+    }
+}
+
+fn part1(input: &[i32]) -> u64 {
+    solve(input, 80)
+}
+
+fn part2(input: &[i32]) -> u64 {
+    solve(input, 256)
+}
+
+fn solve(input: &[i32], days: u64) -> u64 {
+    let frequency_map = FrequencyMap::from_iter(input);
+    let fish = (0..9)
+        .map(|timer| frequency_map.count(&&timer) as i128)
+        .collect::<Vec<_>>();
+
+    // Each day every timer counts down, and fish at zero reset to 6 and spawn a fish at 8.
+    let mut day = Matrix::zeros(9, 9);
+    for timer in 1..9 {
+        day[(timer - 1, timer)] = 1;
+    }
+    day[(6, 0)] = 1;
+    day[(8, 0)] = 1;
+
+    day.pow(days).mul_vector(&fish).iter().sum::<i128>() as u64
+}
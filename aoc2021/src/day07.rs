@@ -0,0 +1,53 @@
+use std::num::ParseIntError;
+use util::aoc::statistics::Statistics;
+use util::aoc::*;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 7;
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        input
+            .trim()
+            .split(',')
+            .map(|value| value.parse().map_err(|e: ParseIntError| e.into()))
+            .collect()
+    }
+
+    fn part1(positions: &Vec<i32>) -> AocResult<i32> {
+        Ok(part1(positions))
+    }
+
+    fn part2(positions: &Vec<i32>) -> AocResult<i32> {
+        Ok(part2(positions))
+    }
+}
+
+fn part1(inputs: &[i32]) -> i32 {
+    // Any position between the two middle crabs minimises the total distance.
+    let median = inputs.iter().copied().median().unwrap().raw().floor() as i32;
+    cost(inputs, median, |distance| distance)
+}
+
+fn part2(inputs: &[i32]) -> i32 {
+    // The triangular cost is minimised within half a step of the mean.
+    let mean = inputs.iter().copied().mean().unwrap().raw();
+    [mean.floor() as i32, mean.ceil() as i32]
+        .into_iter()
+        .map(|position| cost(inputs, position, |distance| distance * (distance + 1) / 2))
+        .min()
+        .unwrap()
+}
+
+fn cost(inputs: &[i32], position: i32, cost_fn: fn(i32) -> i32) -> i32 {
+    inputs
+        .iter()
+        .map(|input| cost_fn((position - input).abs()))
+        .sum()
+}
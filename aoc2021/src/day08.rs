@@ -1,17 +1,28 @@
-use itertools::{Itertools, MinMaxResult};
 use std::collections::{HashMap, HashSet};
-use std::num::ParseIntError;
-use std::str::FromStr;
 use util::aoc::frequency::FrequencyMap;
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let input: Vec<(Vec<String>, Vec<String>)> = parse(input::read(8)?);
+pub struct Day08;
 
-    result("Part 1", || part1(&input));
-    result("Part 2", || part2(&input));
+impl Solution for Day08 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 8;
 
-    Ok(())
+    type Input = Vec<(Vec<String>, Vec<String>)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<(Vec<String>, Vec<String>)>> {
+        Ok(parse(input.lines().map(String::from).collect()))
+    }
+
+    fn part1(patterns: &Vec<(Vec<String>, Vec<String>)>) -> AocResult<i32> {
+        Ok(part1(patterns))
+    }
+
+    fn part2(patterns: &Vec<(Vec<String>, Vec<String>)>) -> AocResult<i32> {
+        Ok(part2(patterns))
+    }
 }
 
 fn parse(lines: Vec<String>) -> Vec<(Vec<String>, Vec<String>)> {
@@ -104,31 +115,6 @@ fn intersection_count(
 mod tests {
     use super::*;
 
-    const SAMPLE_INPUT: &str = r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#;
-
-    #[test]
-    fn part1_sample() {
-        let patterns = parse(SAMPLE_INPUT.lines().map(|s| s.to_string()).collect_vec());
-
-        assert_eq!(26, part1(&patterns));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let patterns = parse(SAMPLE_INPUT.lines().map(|s| s.to_string()).collect_vec());
-
-        assert_eq!(61229, part2(&patterns));
-    }
-
     #[test]
     fn decode_test() {
         let samples = parse(vec![
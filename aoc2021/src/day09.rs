@@ -4,23 +4,37 @@ use std::collections::HashMap;
 use util::aoc::grid::Grid;
 use util::aoc::*;
 
-type HeightMap = Grid<i32>;
+pub type HeightMap = Grid<i32>;
 
-fn main() -> AocResult<()> {
-    let input: Vec<Vec<i32>> = input::read(9)?
-        .iter()
-        .map(|s| {
-            s.chars()
-                .map(|c| c.to_digit(10).unwrap() as i32)
-                .collect_vec()
-        })
-        .collect();
-    let height_map = Grid::new(input);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 9;
 
-    result("Part 1", || part1(&height_map));
-    result("Part 2", || part2(&height_map));
+    type Input = HeightMap;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    Ok(())
+    fn parse(input: &str) -> AocResult<HeightMap> {
+        let input = input
+            .lines()
+            .map(|s| {
+                s.chars()
+                    .map(|c| c.to_digit(10).unwrap() as i32)
+                    .collect_vec()
+            })
+            .collect();
+        Ok(Grid::new(input))
+    }
+
+    fn part1(height_map: &HeightMap) -> AocResult<i32> {
+        Ok(part1(height_map))
+    }
+
+    fn part2(height_map: &HeightMap) -> AocResult<i32> {
+        Ok(part2(height_map))
+    }
 }
 
 fn part1(height_map: &HeightMap) -> i32 {
@@ -87,44 +101,3 @@ fn part2(height_map: &HeightMap) -> i32 {
         .take(3)
         .product()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"2199943210
-3987894921
-9856789892
-8767896789
-9899965678"#;
-
-    #[test]
-    fn part1_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| {
-                s.chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect_vec()
-            })
-            .collect_vec();
-        let height_map = Grid::new(inputs);
-
-        assert_eq!(15, part1(&height_map));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| {
-                s.chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect_vec()
-            })
-            .collect_vec();
-        let height_map = Grid::new(inputs);
-
-        assert_eq!(1134, part2(&height_map));
-    }
-}
@@ -1,16 +1,27 @@
 use itertools::Itertools;
 use util::aoc::*;
 
-fn main() -> AocResult<()> {
-    let inputs: Vec<Vec<char>> = input::read(10)?
-        .iter()
-        .map(|s| s.chars().collect_vec())
-        .collect();
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 10;
 
-    result("Part 1", || part1(&inputs));
-    result("Part 2", || part2(&inputs));
+    type Input = Vec<Vec<char>>;
+    type Output1 = i32;
+    type Output2 = i64;
 
-    Ok(())
+    fn parse(input: &str) -> AocResult<Vec<Vec<char>>> {
+        Ok(input.lines().map(|s| s.chars().collect_vec()).collect())
+    }
+
+    fn part1(lines: &Vec<Vec<char>>) -> AocResult<i32> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Vec<Vec<char>>) -> AocResult<i64> {
+        Ok(part2(lines))
+    }
 }
 
 fn part1(inputs: &[Vec<char>]) -> i32 {
@@ -49,8 +60,6 @@ fn part2(inputs: &[Vec<char>]) -> i64 {
         .sorted()
         .collect::<Vec<_>>();
 
-    println!("Scores: {:?}", scores);
-
     scores[scores.len() / 2]
 }
 
@@ -102,41 +111,10 @@ fn closing_character(c: char) -> char {
 mod tests {
     use super::*;
 
-    const SAMPLE_INPUT: &str = r#"[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]"#;
-
     #[test]
     fn test_find_first_illegal_close() {
         let input = "{([(<{}[<>[]}>{[]{[(<()>".chars().collect_vec();
 
         assert_eq!(Some('}'), find_first_illegal_close(&input))
     }
-
-    #[test]
-    fn part1_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| s.chars().collect_vec())
-            .collect_vec();
-
-        assert_eq!(26397, part1(&inputs));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| s.chars().collect_vec())
-            .collect_vec();
-
-        assert_eq!(288957, part2(&inputs));
-    }
 }
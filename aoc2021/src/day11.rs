@@ -1,26 +1,38 @@
 use itertools::Itertools;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 use util::aoc::grid::Grid;
 use util::aoc::*;
 
-type OctoGrid = Grid<i32>;
+pub type OctoGrid = Grid<i32>;
 
-fn main() -> AocResult<()> {
-    let input: Vec<Vec<i32>> = input::read(11)?
-        .iter()
-        .map(|s| {
-            s.chars()
-                .map(|c| c.to_digit(10).unwrap() as i32)
-                .collect_vec()
-        })
-        .collect();
-    let octopi = Grid::new(input);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 11;
+
+    type Input = OctoGrid;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<OctoGrid> {
+        let input = input
+            .lines()
+            .map(|s| {
+                s.chars()
+                    .map(|c| c.to_digit(10).unwrap() as i32)
+                    .collect_vec()
+            })
+            .collect();
+        Ok(Grid::new(input))
+    }
 
-    result("Part 1", || part1(octopi.clone()));
-    result("Part 2", || part2(octopi.clone()));
+    fn part1(octopi: &OctoGrid) -> AocResult<i32> {
+        Ok(part1(octopi.clone()))
+    }
 
-    Ok(())
+    fn part2(octopi: &OctoGrid) -> AocResult<i32> {
+        Ok(part2(octopi.clone()))
+    }
 }
 
 fn part1(mut octopi: OctoGrid) -> i32 {
@@ -30,7 +42,7 @@ fn part1(mut octopi: OctoGrid) -> i32 {
         .collect_vec();
 
     let mut flashes = 0;
-    for step in 0..100 {
+    for _step in 0..100 {
         let mut to_check = points.clone();
         while let Some(point) = to_check.pop() {
             if octopi.lookup(&point).is_none() {
@@ -86,49 +98,3 @@ fn part2(mut octopi: OctoGrid) -> i32 {
         })
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"5483143223
-2745854711
-5264556173
-6141336146
-6357385478
-4167524645
-2176841721
-6882881134
-4846848554
-5283751526"#;
-
-    #[test]
-    fn part1_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| {
-                s.chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect_vec()
-            })
-            .collect_vec();
-        let octopi = Grid::new(inputs);
-
-        assert_eq!(1656, part1(octopi));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let inputs = SAMPLE_INPUT
-            .lines()
-            .map(|s| {
-                s.chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect_vec()
-            })
-            .collect_vec();
-        let octopi = Grid::new(inputs);
-
-        assert_eq!(195, part2(octopi));
-    }
-}
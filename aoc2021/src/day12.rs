@@ -3,12 +3,12 @@ use itertools::Itertools;
 use std::collections::HashMap;
 use util::aoc::*;
 
-use crate::Cave::{Big, Small};
+use self::Cave::{Big, Small};
 
-type CaveMap = HashMap<Cave, Vec<Cave>>;
+pub type CaveMap = HashMap<Cave, Vec<Cave>>;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-enum Cave {
+pub enum Cave {
     Start,
     End,
     Big(usize),
@@ -17,17 +17,11 @@ enum Cave {
 
 impl Cave {
     fn is_big(&self) -> bool {
-        match self {
-            Big(_) => true,
-            _ => false,
-        }
+        matches!(self, Big(_))
     }
 
     fn is_small(&self) -> bool {
-        match self {
-            Small(_) => true,
-            _ => false,
-        }
+        matches!(self, Small(_))
     }
 
     fn index(&self) -> usize {
@@ -58,13 +52,27 @@ impl Cave {
     }
 }
 
-fn main() -> AocResult<()> {
-    let input = parse(input::read(12)?);
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 12;
 
-    result("Part 1", || part1(&input));
-    result("Part 2", || part2(&input));
+    type Input = CaveMap;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    Ok(())
+    fn parse(input: &str) -> AocResult<CaveMap> {
+        Ok(parse(input.lines().map(String::from).collect()))
+    }
+
+    fn part1(cave_map: &CaveMap) -> AocResult<i64> {
+        Ok(part1(cave_map))
+    }
+
+    fn part2(cave_map: &CaveMap) -> AocResult<i64> {
+        Ok(part2(cave_map))
+    }
 }
 
 fn parse(input: Vec<String>) -> CaveMap {
@@ -74,15 +82,12 @@ fn parse(input: Vec<String>) -> CaveMap {
 
     input
         .into_iter()
-        .flat_map(
-            |line| match line.split('-').collect_tuple().expect("Unexpected line") {
-                (start, end) => {
-                    let start = Cave::parse_node(start, &mut string_to_index, &mut current_index);
-                    let end = Cave::parse_node(end, &mut string_to_index, &mut current_index);
-                    vec![(start.clone(), end.clone()), (end, start)]
-                }
-            },
-        )
+        .flat_map(|line| {
+            let (start, end) = line.split('-').collect_tuple().expect("Unexpected line");
+            let start = Cave::parse_node(start, &mut string_to_index, &mut current_index);
+            let end = Cave::parse_node(end, &mut string_to_index, &mut current_index);
+            vec![(start.clone(), end.clone()), (end, start)]
+        })
         .filter(|(start, end)| *end != Cave::Start && *start != Cave::End)
         .for_each(|(start, end)| {
             cave_map.entry(start).or_default().push(end);
@@ -113,7 +118,7 @@ impl<'a> CaveWithPath<'a> {
     }
 
     pub fn last_step(&self) -> Option<&Cave> {
-        self.path.last().map(|&c| c)
+        self.path.last().copied()
     }
 
     pub fn is_visited(&self, cave: &Cave) -> bool {
@@ -143,14 +148,14 @@ impl<'a> CaveWithPath<'a> {
 fn part1(cave_map: &CaveMap) -> i64 {
     solve(cave_map, |path, next_cave| {
         // Only visit small caves once.
-        next_cave.is_big() || !path.is_visited(&next_cave)
+        next_cave.is_big() || !path.is_visited(next_cave)
     })
 }
 
 fn part2(cave_map: &CaveMap) -> i64 {
     solve(cave_map, |path, next_cave| {
         // Allow visiting a single small cave at least once.
-        next_cave.is_big() || !path.is_visited(&next_cave) || !path.has_visited_twice
+        next_cave.is_big() || !path.is_visited(next_cave) || !path.has_visited_twice
     })
 }
 
@@ -163,13 +168,13 @@ fn solve(cave_map: &CaveMap, can_visit: fn(&CaveWithPath, &Cave) -> bool) -> i64
             continue;
         }
 
-        for next_cave in cave_map.get(&path.last_cave).unwrap() {
+        for next_cave in cave_map.get(path.last_cave).unwrap() {
             // Avoid cycles.
             if Some(next_cave) == path.last_step() {
                 continue;
             }
 
-            if can_visit(&path, &next_cave) {
+            if can_visit(&path, next_cave) {
                 frontier.push(path.append(next_cave));
             }
         }
@@ -177,34 +182,3 @@ fn solve(cave_map: &CaveMap, can_visit: fn(&CaveWithPath, &Cave) -> bool) -> i64
 
     paths
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end"#;
-
-    #[test]
-    fn part1_sample() {
-        let input = parse(SAMPLE_INPUT.lines().map(|s| s.to_string()).collect());
-
-        println!("{:?}", input);
-
-        assert_eq!(10, part1(&input));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let input = parse(SAMPLE_INPUT.lines().map(|s| s.to_string()).collect());
-
-        println!("{:?}", input);
-
-        assert_eq!(36, part2(&input));
-    }
-}
@@ -1,31 +1,46 @@
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 
-use failure::bail;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use util::aoc::*;
+use util::bail;
 
-fn main() -> AocResult<()> {
-    let (grid, folds) = parse(input::read_all(13)?)?;
+pub struct Day13;
 
-    result("Part 1", || part1(&grid, &folds));
-    result("Part 2", || part2(grid.clone(), &folds));
+impl Solution for Day13 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 13;
 
-    Ok(())
+    type Input = (HashGrid, Vec<Fold>);
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<(HashGrid, Vec<Fold>)> {
+        parse(input.to_string())
+    }
+
+    fn part1(manual: &(HashGrid, Vec<Fold>)) -> AocResult<i32> {
+        let (grid, folds) = manual;
+        Ok(part1(grid, folds))
+    }
+
+    fn part2(manual: &(HashGrid, Vec<Fold>)) -> AocResult<String> {
+        let (grid, folds) = manual;
+        Ok(part2(grid.clone(), folds))
+    }
 }
 
 fn part1(foldable_grid: &HashGrid, folds: &[Fold]) -> i32 {
     fold_grid(foldable_grid, &folds[0]).grid.keys().len() as i32
 }
 
-fn part2(foldable_grid: HashGrid, folds: &[Fold]) -> i32 {
+fn part2(foldable_grid: HashGrid, folds: &[Fold]) -> String {
     folds
         .iter()
         .fold(foldable_grid, |grid, fold| fold_grid(&grid, fold))
-        .print_grid();
-
-    0
+        .to_string()
 }
 
 fn fold_grid(foldable_grid: &HashGrid, fold: &Fold) -> HashGrid {
@@ -50,7 +65,7 @@ fn fold_grid(foldable_grid: &HashGrid, fold: &Fold) -> HashGrid {
 
         if let Some(translation) = translation {
             let translated_point = point + translation;
-            for p in vec![point, translated_point] {
+            for p in [point, translated_point] {
                 if foldable_grid.grid.contains_key(&p) {
                     new_grid.insert(point, true);
                 }
@@ -62,7 +77,7 @@ fn fold_grid(foldable_grid: &HashGrid, fold: &Fold) -> HashGrid {
 }
 
 #[derive(Debug, Clone)]
-struct HashGrid {
+pub struct HashGrid {
     grid: HashMap<Point, bool>,
     max_x: i32,
     max_y: i32,
@@ -88,19 +103,23 @@ impl HashGrid {
             max_y: self.max_y,
         }
     }
+}
 
-    fn print_grid(&self) {
+impl Display for HashGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for y in 0..=self.max_y {
+            f.write_str("\n")?;
             for x in 0..=self.max_x {
                 let point = Point::new(x, y);
-                if self.grid.contains_key(&point) {
-                    print!("#")
+                f.write_str(if self.grid.contains_key(&point) {
+                    "#"
                 } else {
-                    print!(".")
-                }
+                    "."
+                })?;
             }
-            println!();
         }
+
+        Ok(())
     }
 }
 
@@ -115,7 +134,7 @@ impl Iterator for PointIterator {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let point = Point::new(self.x as i32, self.y as i32);
+        let point = Point::new(self.x, self.y);
         if self.x < self.max_x {
             self.x += 1;
         } else if self.y < self.max_y {
@@ -130,7 +149,7 @@ impl Iterator for PointIterator {
 }
 
 #[derive(Debug, Copy, Clone)]
-enum Fold {
+pub enum Fold {
     X(i32),
     Y(i32),
 }
@@ -163,38 +182,3 @@ fn parse(s: String) -> AocResult<(HashGrid, Vec<Fold>)> {
 
     Ok((HashGrid::new(points), folds))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5"#;
-
-    #[test]
-    fn part1_sample() {
-        let input = parse(SAMPLE_INPUT.lines().map(|s| s.to_string()).collect());
-        let (grid, folds) = parse(SAMPLE_INPUT.to_string()).unwrap();
-
-        assert_eq!(17, part1(grid, &folds));
-    }
-}
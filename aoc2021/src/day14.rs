@@ -1,21 +1,36 @@
 use std::collections::HashMap;
 
-use failure::bail;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use util::aoc::frequency::FrequencyMap;
 use util::aoc::*;
 
-type InsertionRules = HashMap<String, String>;
+pub type InsertionRules = HashMap<String, String>;
 
-fn main() -> AocResult<()> {
-    let (template, insertion_rules) = parse(input::read_all(14)?)?;
+pub struct Day14;
 
-    result("Part 1", || part1(template.clone(), &insertion_rules));
-    result("Part 2", || part2(template.clone(), &insertion_rules));
+impl Solution for Day14 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 14;
 
-    Ok(())
+    type Input = (String, InsertionRules);
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<(String, InsertionRules)> {
+        parse(input.to_string())
+    }
+
+    fn part1(manual: &(String, InsertionRules)) -> AocResult<i64> {
+        let (template, insertion_rules) = manual;
+        Ok(part1(template.clone(), insertion_rules))
+    }
+
+    fn part2(manual: &(String, InsertionRules)) -> AocResult<i64> {
+        let (template, insertion_rules) = manual;
+        Ok(part2(template.clone(), insertion_rules))
+    }
 }
 
 fn part1(polymer_template: String, insertion_rules: &InsertionRules) -> i64 {
@@ -41,7 +56,7 @@ fn solve(polymer_template: String, insertion_rules: &InsertionRules, steps: usiz
             .collect();
         for (pair, count) in pairs {
             if let Some(rule) = insertion_rules.get(&pair) {
-                let new_pair1 = format!("{}{}", pair.chars().nth(0).unwrap(), rule);
+                let new_pair1 = format!("{}{}", pair.chars().next().unwrap(), rule);
                 let new_pair2 = format!("{}{}", rule, pair.chars().nth(1).unwrap());
                 new_pair_counts.multi_add(new_pair1, count);
                 new_pair_counts.multi_add(new_pair2, count);
@@ -55,15 +70,14 @@ fn solve(polymer_template: String, insertion_rules: &InsertionRules, steps: usiz
     let mut frequencies = FrequencyMap::new();
     pair_counts
         .entries()
-        .map(|(pair, count)| (pair.chars().nth(0).unwrap(), *count))
+        .map(|(pair, count)| (pair.chars().next().unwrap(), *count))
         .for_each(|(c, count)| {
             frequencies.multi_add(c, count);
         });
 
-    if let (Some(min), Some(max)) = (frequencies.min(), frequencies.max()) {
-        frequencies.count(max) as i64 - frequencies.count(min) as i64
-    } else {
-        0
+    match (frequencies.min_count(), frequencies.max_count()) {
+        (Some(min), Some(max)) => max as i64 - min as i64,
+        _ => 0,
     }
 }
 
@@ -83,41 +97,3 @@ fn parse(s: String) -> AocResult<(String, InsertionRules)> {
 
     Ok((parts[0].to_string(), insertion_rules))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C"#;
-
-    #[test]
-    fn part1_sample() {
-        let (polymer_template, rules) = parse(SAMPLE_INPUT.to_string()).unwrap();
-
-        assert_eq!(1588, part1(polymer_template, &rules));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let (polymer_template, rules) = parse(SAMPLE_INPUT.to_string()).unwrap();
-
-        assert_eq!(2188189693529, part2(polymer_template, &rules));
-    }
-}
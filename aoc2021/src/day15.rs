@@ -1,20 +1,32 @@
-use failure::bail;
 use itertools::Itertools;
 use util::aoc::astar::AStarResult;
 use util::aoc::grid::Grid;
 use util::aoc::*;
+use util::bail;
 
-type RiskMap = Grid<i32>;
+pub type RiskMap = Grid<i32>;
 
-fn main() -> AocResult<()> {
-    let input: Vec<Vec<i32>> = parse(input::read(15)?);
+pub struct Day15;
 
-    let risk_map = Grid::new(input);
+impl Solution for Day15 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 15;
 
-    result("Part 1", || part1(&risk_map).expect("Failed to find path"));
-    result("Part 2", || part2(&risk_map).expect("Failed to find path"));
+    type Input = RiskMap;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    Ok(())
+    fn parse(input: &str) -> AocResult<RiskMap> {
+        Ok(Grid::new(parse(input.lines().map(String::from).collect())))
+    }
+
+    fn part1(risk_map: &RiskMap) -> AocResult<i32> {
+        part1(risk_map)
+    }
+
+    fn part2(risk_map: &RiskMap) -> AocResult<i32> {
+        part2(risk_map)
+    }
 }
 
 fn parse(lines: Vec<String>) -> Vec<Vec<i32>> {
@@ -87,7 +99,7 @@ fn tile_map(risk_map: &RiskMap) -> RiskMap {
         let untiled_point = Point::new(point.x % tile_size_x, point.y % tile_size_y);
 
         // The number of tiles to the left+above this tile, to adjust the risk level.
-        let tile = point.x / tile_size_x + point.y / tile_size_y as i32;
+        let tile = point.x / tile_size_x + point.y / tile_size_y;
 
         // Shifts all values by 1 to conform to the 1-9 range.
         let new_risk_level = (risk_map.lookup(&untiled_point).unwrap() + tile - 1) % 9 + 1;
@@ -97,35 +109,3 @@ fn tile_map(risk_map: &RiskMap) -> RiskMap {
 
     grid
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = r#"1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581"#;
-
-    #[test]
-    fn part1_sample() {
-        let inputs = parse(SAMPLE_INPUT.lines().map(|l| l.to_string()).collect_vec());
-        let risk_map = Grid::new(inputs);
-
-        assert_eq!(40, part1(&risk_map).unwrap());
-    }
-
-    #[test]
-    fn part2_sample() {
-        let inputs = parse(SAMPLE_INPUT.lines().map(|l| l.to_string()).collect_vec());
-        let risk_map = Grid::new(inputs);
-
-        assert_eq!(315, part2(&risk_map).unwrap());
-    }
-}
@@ -1,4 +1,3 @@
-use failure::format_err;
 use itertools::Itertools;
 use nom::bits::bits;
 use nom::bits::complete::take;
@@ -7,16 +6,31 @@ use nom::sequence::tuple;
 use nom::{Err, IResult};
 use std::num::ParseIntError;
 use util::aoc::*;
+use util::format_err;
 
 type BitResult<'a, T> = IResult<(&'a [u8], usize), T>;
 
-fn main() -> AocResult<()> {
-    let input: Packet = parse(input::read_all(16)?)?;
+pub struct Day16;
 
-    result("Part 1", || part1(&input));
-    result("Part 2", || part2(&input));
+impl Solution for Day16 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 16;
 
-    Ok(())
+    type Input = Packet;
+    type Output1 = i32;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Packet> {
+        parse(input.trim().to_string())
+    }
+
+    fn part1(packet: &Packet) -> AocResult<i32> {
+        Ok(part1(packet))
+    }
+
+    fn part2(packet: &Packet) -> AocResult<i64> {
+        Ok(part2(packet))
+    }
 }
 
 fn part1(packet: &Packet) -> i32 {
@@ -117,7 +131,7 @@ fn parse(encoded: String) -> AocResult<Packet> {
     x
 }
 
-fn parse_packet(i: (&[u8], usize)) -> BitResult<Packet> {
+fn parse_packet(i: (&[u8], usize)) -> BitResult<'_, Packet> {
     let (i, (version, type_id)) = tuple((take(3usize), take(3usize)))(i)?;
     let (mut i, (value, sub_packet_frame)) = match type_id {
         4 => parse_literal(i)?,
@@ -156,7 +170,7 @@ fn bits_remaining((bytes, offset): (&[u8], usize)) -> usize {
     bytes.len() * 8 - offset
 }
 
-fn parse_literal(mut i: (&[u8], usize)) -> BitResult<(PacketValue, SubPacketFrame)> {
+fn parse_literal(mut i: (&[u8], usize)) -> BitResult<'_, (PacketValue, SubPacketFrame)> {
     // Literal packets:
     // Literal value packets encode a single binary number. To do this, the binary number is padded
     // with leading zeroes until its length is a multiple of four bits, and then it is broken into
@@ -193,7 +207,7 @@ fn parse_literal(mut i: (&[u8], usize)) -> BitResult<(PacketValue, SubPacketFram
     Ok((i, (PacketValue::Literal(number), SubPacketFrame::Count(0))))
 }
 
-fn parse_operator(i: (&[u8], usize), type_id: i32) -> BitResult<(PacketValue, SubPacketFrame)> {
+fn parse_operator(i: (&[u8], usize), type_id: i32) -> BitResult<'_, (PacketValue, SubPacketFrame)> {
     // Operator packets:
     // An operator packet contains one or more packets. To indicate which subsequent binary data
     // represents its sub-packets, an operator packet can use one of two modes indicated by the bit
@@ -238,29 +252,3 @@ fn decode_hex(s: &str) -> Result<Vec<u8>, ParseIntError> {
         .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_sample() {
-        let input = parse("A0016C880162017C3686B18A3D4780".to_string()).unwrap();
-
-        assert_eq!(31, part1(&input));
-    }
-
-    #[test]
-    fn part2_sample() {
-        let input = parse("04005AC33890".to_string()).unwrap();
-
-        assert_eq!(54, part2(&input));
-    }
-
-    #[test]
-    fn part2_sample_2() {
-        let input = parse("9C0141080250320F1802104A08".to_string()).unwrap();
-
-        assert_eq!(1, part2(&input));
-    }
-}
@@ -1,8 +1,30 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
 use util::aoc::top_k::TopK;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 1;
+
+    type Input = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Vec<i32>>> {
+        Ok(parse(input))
+    }
+
+    fn part1(carried_calories: &Vec<Vec<i32>>) -> AocResult<i32> {
+        Ok(part1(carried_calories))
+    }
+
+    fn part2(carried_calories: &Vec<Vec<i32>>) -> AocResult<i32> {
+        Ok(part2(carried_calories))
+    }
+}
 
-#[aoc_generator(day1)]
 fn parse(input: &str) -> Vec<Vec<i32>> {
     input
         .lines()
@@ -11,7 +33,7 @@ fn parse(input: &str) -> Vec<Vec<i32>> {
                 .take_while(|line| !line.is_empty())
                 .map(|line| line.parse().unwrap())
                 .collect();
-            if elf_calories.len() > 0 {
+            if !elf_calories.is_empty() {
                 Some(elf_calories)
             } else {
                 None
@@ -20,7 +42,6 @@ fn parse(input: &str) -> Vec<Vec<i32>> {
         .collect()
 }
 
-#[aoc(day1, part1)]
 fn part1(carried_calories: &[Vec<i32>]) -> i32 {
     carried_calories
         .iter()
@@ -29,7 +50,6 @@ fn part1(carried_calories: &[Vec<i32>]) -> i32 {
         .unwrap_or(-1)
 }
 
-#[aoc(day1, part2)]
 fn part2(carried_calories: &[Vec<i32>]) -> i32 {
     carried_calories
         .iter()
@@ -38,34 +58,3 @@ fn part2(carried_calories: &[Vec<i32>]) -> i32 {
         .iter()
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn calorie_counting_p1() {
-        let carried_calories = vec![
-            vec![1000, 2000, 3000],
-            vec![4000],
-            vec![5000, 6000],
-            vec![7000, 8000, 9000],
-            vec![10_000],
-        ];
-
-        assert_eq!(24000, part1(&carried_calories));
-    }
-
-    #[test]
-    fn calorie_counting_p2() {
-        let carried_calories = vec![
-            vec![1000, 2000, 3000],
-            vec![4000],
-            vec![5000, 6000],
-            vec![7000, 8000, 9000],
-            vec![10_000],
-        ];
-
-        assert_eq!(45000, part2(&carried_calories));
-    }
-}
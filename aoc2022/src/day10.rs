@@ -1,17 +1,42 @@
 use crate::day10::Instruction::Noop;
-use failure::bail;
 use itertools::Itertools;
 use std::fmt::Write;
 use std::str::FromStr;
+use util::aoc::parsing::parse_lines;
+use util::aoc::{AocError, AocResult, Solution};
+use util::bail;
 use Instruction::Addx;
 
-enum Instruction {
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 10;
+
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<Vec<Instruction>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> AocResult<i32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> AocResult<String> {
+        Ok(part2(input))
+    }
+}
+
+pub enum Instruction {
     Noop,
     Addx(i32),
 }
 
 impl FromStr for Instruction {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.split_whitespace().collect_vec().as_slice() {
@@ -22,15 +47,10 @@ impl FromStr for Instruction {
     }
 }
 
-#[aoc_generator(day10)]
-fn parse(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|instruction| instruction.parse().unwrap())
-        .collect()
+fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    parse_lines(input)
 }
 
-#[aoc(day10, part1)]
 fn part1(input: &[Instruction]) -> i32 {
     let sample_cycles = [20, 60, 100, 140, 180, 220];
     let mut signal_strengths = vec![];
@@ -43,7 +63,6 @@ fn part1(input: &[Instruction]) -> i32 {
     signal_strengths.iter().sum()
 }
 
-#[aoc(day10, part2)]
 fn part2(input: &[Instruction]) -> String {
     let mut output = String::new();
     execute(input, &mut |cycle, x| {
@@ -53,7 +72,7 @@ fn part2(input: &[Instruction]) -> String {
 
         let pixel = (cycle - 1) % 40;
         if pixel == 0 {
-            writeln!(output, "").unwrap();
+            writeln!(output).unwrap();
         }
 
         if (pixel - x).abs() <= 1 || (pixel + x).abs() <= 1 {
@@ -82,6 +101,11 @@ fn execute(input: &[Instruction], sample_fn: &mut impl FnMut(i32, i32)) {
                 Addx(delta) => x += *delta,
             }
 
+            // Stop once the last instruction retires, so the cycles it takes are still sampled.
+            if pc == input.len() {
+                break;
+            }
+
             next_instruction = match input[pc] {
                 Noop => (Noop, cycle + 1),
                 Addx(v) => (Addx(v), cycle + 2),
@@ -90,171 +114,6 @@ fn execute(input: &[Instruction], sample_fn: &mut impl FnMut(i32, i32)) {
             pc += 1;
         }
 
-        if pc == input.len() {
-            break;
-        }
-
         cycle += 1;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop";
-
-    #[test]
-    fn p1() {
-        assert_eq!(13140, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        println!("{}", part2(&parse(INPUT)));
-    }
-}
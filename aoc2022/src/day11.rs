@@ -3,15 +3,38 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::char,
-    character::complete::{digit0, digit1, multispace0, space1},
-    character::streaming::space0,
-    combinator::{map, map_res},
-    multi::separated_list1,
+    character::complete::{space0, space1},
+    combinator::map,
     sequence::preceded,
-    sequence::{delimited, tuple},
+    sequence::tuple,
     IResult,
 };
 use num::Integer;
+use util::aoc::parsers::{blocks, comma_separated, labeled, parse_all, unsigned};
+use util::aoc::{AocError, AocResult, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 11;
+
+    type Input = Vec<Monkey>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Vec<Monkey>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Monkey>) -> AocResult<i64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Monkey>) -> AocResult<i64> {
+        Ok(part2(input))
+    }
+}
 
 #[derive(Clone)]
 enum Value {
@@ -51,7 +74,7 @@ impl Operation {
 }
 
 #[derive(Clone)]
-struct Monkey {
+pub struct Monkey {
     items: Vec<i64>,
     operation: Operation,
     test_divisor: i64,
@@ -59,44 +82,30 @@ struct Monkey {
     false_monkey: usize,
 }
 
-#[aoc_generator(day11)]
-fn parse(input: &str) -> Vec<Monkey> {
-    let monkey_inputs = input.split("\n\n").collect_vec();
-    monkey_inputs
-        .iter()
-        .map(|&input| parse_monkey(input).unwrap().1)
-        .collect()
+fn parse(input: &str) -> Result<Vec<Monkey>, AocError> {
+    parse_all(blocks(parse_monkey), input)
 }
 
 fn parse_monkey(i: &str) -> IResult<&str, Monkey> {
-    let (i, _) = tuple((tag("Monkey"), space0, digit0, char(':'), multispace0))(i)?;
-    let (i, items) = preceded(
-        tuple((multispace0, tag("Starting items: "))),
-        separated_list1(delimited(multispace0, char(','), multispace0), parse_i64),
-    )(i)?;
-    let (i, operation) = preceded(
-        tuple((multispace0, tag("Operation: new = old"), space0)),
-        alt((
-            map(tuple((char('+'), parse_source)), |(_, val)| {
-                Operation::Add(val)
-            }),
-            map(tuple((char('*'), parse_source)), |(_, val)| {
-                Operation::Mul(val)
-            }),
-        )),
-    )(i)?;
-    let (i, test_divisor) = preceded(
-        tuple((multispace0, tag("Test: divisible by"), space0)),
-        parse_i64,
-    )(i)?;
-    let (i, true_monkey) = preceded(
-        tuple((multispace0, tag("If true: throw to monkey"), space0)),
-        parse_usize,
-    )(i)?;
-    let (i, false_monkey) = preceded(
-        tuple((multispace0, tag("If false: throw to monkey"), space0)),
-        parse_usize,
+    let (i, _) = tuple((tag("Monkey"), space0, unsigned::<usize>, char(':')))(i)?;
+    let (i, items) = labeled("Starting items", comma_separated(unsigned))(i)?;
+    let (i, operation) = labeled(
+        "Operation",
+        preceded(
+            tuple((tag("new = old"), space0)),
+            alt((
+                map(tuple((char('+'), parse_source)), |(_, val)| {
+                    Operation::Add(val)
+                }),
+                map(tuple((char('*'), parse_source)), |(_, val)| {
+                    Operation::Mul(val)
+                }),
+            )),
+        ),
     )(i)?;
+    let (i, test_divisor) = labeled("Test", preceded(tag("divisible by "), unsigned))(i)?;
+    let (i, true_monkey) = labeled("If true", preceded(tag("throw to monkey "), unsigned))(i)?;
+    let (i, false_monkey) = labeled("If false", preceded(tag("throw to monkey "), unsigned))(i)?;
 
     Ok((
         i,
@@ -114,23 +123,14 @@ fn parse_source(i: &str) -> IResult<&str, Value> {
     preceded(
         space1,
         alt((
-            map(parse_i64, Value::Literal),
+            map(unsigned, Value::Literal),
             map(tag("old"), |_| Value::Old),
         )),
     )(i)
 }
 
-fn parse_i64(i: &str) -> IResult<&str, i64> {
-    map_res(digit1, |s: &str| s.parse::<i64>())(i)
-}
-
-fn parse_usize(i: &str) -> IResult<&str, usize> {
-    map_res(digit1, |s: &str| s.parse::<usize>())(i)
-}
-
-#[aoc(day11, part1)]
-fn part1(input: &Vec<Monkey>) -> i64 {
-    perform_monkey_business(|item| item / 3, 20, &mut input.clone())
+fn part1(input: &[Monkey]) -> i64 {
+    perform_monkey_business(|item| item / 3, 20, &mut input.to_owned())
         .iter()
         .sorted()
         .rev()
@@ -138,9 +138,8 @@ fn part1(input: &Vec<Monkey>) -> i64 {
         .product()
 }
 
-#[aoc(day11, part2)]
-fn part2(input: &Vec<Monkey>) -> i64 {
-    perform_monkey_business(|item| item, 10000, &mut input.clone())
+fn part2(input: &[Monkey]) -> i64 {
+    perform_monkey_business(|item| item, 10000, &mut input.to_owned())
         .iter()
         .sorted()
         .rev()
@@ -151,7 +150,7 @@ fn part2(input: &Vec<Monkey>) -> i64 {
 fn perform_monkey_business(
     item_fn: fn(i64) -> i64,
     rounds: usize,
-    input: &mut Vec<Monkey>,
+    input: &mut [Monkey],
 ) -> Vec<i64> {
     let lcm = input
         .iter()
@@ -187,45 +186,3 @@ fn perform_monkey_business(
 
     inspect_count
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1";
-
-    #[test]
-    fn p1() {
-        assert_eq!(10605, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(2713310158, part2(&parse(INPUT)));
-    }
-}
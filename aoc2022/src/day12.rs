@@ -2,9 +2,33 @@ use itertools::Itertools;
 use util::aoc::astar::AStarResult;
 use util::aoc::grid::Grid;
 use util::aoc::{astar, Point};
+use util::aoc::{AocResult, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 12;
+
+    type Input = Grid<GridSquare>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Grid<GridSquare>> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &Grid<GridSquare>) -> AocResult<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Grid<GridSquare>) -> AocResult<usize> {
+        Ok(part2(input))
+    }
+}
 
 #[derive(Copy, Clone, Debug)]
-enum GridSquare {
+pub enum GridSquare {
     Start,
     End,
     Height(i32),
@@ -20,7 +44,6 @@ impl GridSquare {
     }
 }
 
-#[aoc_generator(day12)]
 fn parse(input: &str) -> Grid<GridSquare> {
     Grid::new(
         input
@@ -38,7 +61,6 @@ fn parse(input: &str) -> Grid<GridSquare> {
     )
 }
 
-#[aoc(day12, part1)]
 fn part1(input: &Grid<GridSquare>) -> usize {
     let (start, _) = input
         .point_iterator()
@@ -52,7 +74,6 @@ fn part1(input: &Grid<GridSquare>) -> usize {
     compute_path(input, &start, &end).unwrap_or(0)
 }
 
-#[aoc(day12, part2)]
 fn part2(input: &Grid<GridSquare>) -> usize {
     let (end, _) = input
         .point_iterator()
@@ -102,27 +123,3 @@ fn compute_path(input: &Grid<GridSquare>, start: &Point, end: &Point) -> Option<
         None
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi";
-
-    #[test]
-    fn p1() {
-        // assert_eq!(31, part1(&parse(INPUT)));
-        assert_eq!(
-            447,
-            part1(&parse(util::aoc::input::read_all(12).unwrap().as_str()))
-        );
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(29, part2(&parse(INPUT)));
-    }
-}
@@ -1,22 +1,35 @@
 use crate::day13::Outcome::{Continue, OutOfOrder};
 use itertools::{EitherOrBoth, Itertools};
-use nom::{
-    branch::alt,
-    character::complete::char,
-    character::complete::digit1,
-    character::streaming::space0,
-    combinator::cut,
-    combinator::{map, map_res},
-    error::context,
-    multi::separated_list0,
-    sequence::preceded,
-    sequence::terminated,
-    IResult,
-};
+use nom::{character::complete::line_ending, combinator::map, sequence::separated_pair, IResult};
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
+use util::aoc::parsers::{blocks, nested, parse_all, unsigned, Nested};
+use util::aoc::{AocError, AocResult, Solution};
 use Outcome::InOrder;
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 13;
+
+    type Input = Vec<PacketPair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Vec<PacketPair>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<PacketPair>) -> AocResult<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<PacketPair>) -> AocResult<usize> {
+        Ok(part2(input))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum PacketData {
     List(Vec<PacketData>),
@@ -33,7 +46,7 @@ impl Display for PacketData {
 }
 
 #[derive(Clone, Debug)]
-struct PacketPair {
+pub struct PacketPair {
     left: PacketData,
     right: PacketData,
 }
@@ -63,62 +76,30 @@ impl Outcome {
     }
 }
 
-#[aoc_generator(day13)]
-fn parse(input: &str) -> Vec<PacketPair> {
-    input.split("\n\n").map(parse_pair).collect()
-}
-
-fn parse_pair(input: &str) -> PacketPair {
-    let vec = input
-        .lines()
-        .map(|i| {
-            let (_, packet) = parse_packet(i).unwrap();
-            packet
-        })
-        .collect_vec();
-    PacketPair {
-        left: vec[0].clone(),
-        right: vec[1].clone(),
+impl From<Nested<i32>> for PacketData {
+    fn from(value: Nested<i32>) -> Self {
+        match value {
+            Nested::Item(v) => PacketData::Int(v),
+            Nested::List(l) => PacketData::List(l.into_iter().map(PacketData::from).collect()),
+        }
     }
 }
 
-fn parse_packet(i: &str) -> IResult<&str, PacketData> {
-    let (i, v) = value(i)?;
-
-    Ok((i, v))
-}
-
-fn value(i: &str) -> IResult<&str, PacketData> {
-    context(
-        "value",
-        preceded(
-            space0,
-            alt((
-                map(parse_i32, PacketData::Int),
-                map(array, PacketData::List),
-            )),
-        ),
-    )(i)
+fn parse(input: &str) -> Result<Vec<PacketPair>, AocError> {
+    parse_all(blocks(parse_pair), input)
 }
 
-fn array(i: &str) -> IResult<&str, Vec<PacketData>> {
-    context(
-        "array",
-        preceded(
-            char('['),
-            cut(terminated(
-                separated_list0(preceded(space0, char(',')), value),
-                preceded(space0, char(']')),
-            )),
-        ),
+fn parse_pair(i: &str) -> IResult<&str, PacketPair> {
+    map(
+        separated_pair(parse_packet, line_ending, parse_packet),
+        |(left, right)| PacketPair { left, right },
     )(i)
 }
 
-fn parse_i32(i: &str) -> IResult<&str, i32> {
-    map_res(digit1, |s: &str| s.parse::<i32>())(i)
+fn parse_packet(i: &str) -> IResult<&str, PacketData> {
+    map(nested(unsigned::<i32>), PacketData::from)(i)
 }
 
-#[aoc(day13, part1)]
 fn part1(input: &[PacketPair]) -> usize {
     input
         .iter()
@@ -131,7 +112,6 @@ fn part1(input: &[PacketPair]) -> usize {
         .sum()
 }
 
-#[aoc(day13, part2)]
 fn part2(input: &[PacketPair]) -> usize {
     let divider_packets = [
         PacketData::List(vec![PacketData::List(vec![PacketData::Int(2)])]),
@@ -186,41 +166,3 @@ fn in_order(left: &PacketData, right: &PacketData) -> Outcome {
         },
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]";
-
-    #[test]
-    fn p1() {
-        assert_eq!(13, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(140, part2(&parse(INPUT)));
-    }
-}
@@ -3,9 +3,34 @@ use std::fmt::{Debug, Display, Formatter};
 use util::aoc::grid::HashGrid;
 use util::aoc::lines::LineSegment;
 use util::aoc::Point;
+use util::aoc::{AocResult, Solution};
 
-#[derive(Copy, Clone, Debug)]
-enum Item {
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 14;
+
+    type Input = HashGrid<Item>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<HashGrid<Item>> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &HashGrid<Item>) -> AocResult<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &HashGrid<Item>) -> AocResult<usize> {
+        Ok(part2(input))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub enum Item {
+    #[default]
     Air,
     Rock,
     Sand,
@@ -21,15 +46,8 @@ impl Display for Item {
     }
 }
 
-impl Default for Item {
-    fn default() -> Self {
-        Self::Air
-    }
-}
-
 const ORIGIN: Point = Point::new(500, 0);
 
-#[aoc_generator(day14)]
 fn parse(input: &str) -> HashGrid<Item> {
     // Turn into LineSegments.
     let segments = input
@@ -53,7 +71,6 @@ fn parse(input: &str) -> HashGrid<Item> {
     grid
 }
 
-#[aoc(day14, part1)]
 fn part1(input: &HashGrid<Item>) -> usize {
     let mut grid = input.clone();
     let mut iterations = 0;
@@ -71,7 +88,6 @@ fn part1(input: &HashGrid<Item>) -> usize {
     iterations
 }
 
-#[aoc(day14, part2)]
 fn part2(input: &HashGrid<Item>) -> usize {
     let mut grid = input.clone();
     let mut iterations = 0;
@@ -104,7 +120,7 @@ fn simulate_particle(grid: &mut HashGrid<Item>) -> bool {
         }
     }
 
-    let _ = grid.update(point, Item::Sand);
+    grid.update(point, Item::Sand);
 
     false
 }
@@ -139,23 +155,3 @@ fn next_move(point: Point) -> [Point; 3] {
         point + Point::new(1, 1),  // down-right
     ]
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9";
-
-    #[test]
-    fn p1() {
-        assert_eq!(24, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(
-            31722,
-            part2(&parse(util::aoc::input::read_all(14).unwrap().as_str()))
-        );
-    }
-}
@@ -1,21 +1,41 @@
-use aoc_runner_derive::{aoc, aoc_generator};
-use failure::bail;
 use itertools::Itertools;
 use std::str::FromStr;
 use util::aoc::*;
+use util::bail;
 
-#[aoc_generator(day2)]
-fn parse(input: &str) -> Result<Vec<(Move, String)>, failure::Error> {
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 2;
+
+    type Input = Vec<(Move, String)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<(Move, String)>> {
+        parse(input)
+    }
+
+    fn part1(strategy_guide: &Vec<(Move, String)>) -> AocResult<i32> {
+        Ok(part1(strategy_guide))
+    }
+
+    fn part2(strategy_guide: &Vec<(Move, String)>) -> AocResult<i32> {
+        Ok(part2(strategy_guide))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(Move, String)>, AocError> {
     input
         .lines()
         .map(|line| match line.split_whitespace().collect_vec()[..] {
             [l, r] => Ok((l.parse::<Move>()?, r.to_string())),
             _ => bail!("Unsupported move pair: {:?}", line),
         })
-        .collect::<Result<Vec<_>, failure::Error>>()
+        .collect::<Result<Vec<_>, AocError>>()
 }
 
-#[aoc(day2, part1)]
 fn part1(strategy_guide: &[(Move, String)]) -> i32 {
     strategy_guide
         .iter()
@@ -24,7 +44,6 @@ fn part1(strategy_guide: &[(Move, String)]) -> i32 {
         .unwrap()
 }
 
-#[aoc(day2, part2)]
 fn part2(strategy_guide: &[(Move, String)]) -> i32 {
     strategy_guide
         .iter()
@@ -78,7 +97,7 @@ impl Outcome {
 }
 
 impl FromStr for Outcome {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -91,7 +110,7 @@ impl FromStr for Outcome {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Move {
+pub enum Move {
     Rock,
     Paper,
     Scissors,
@@ -126,7 +145,7 @@ impl Move {
 }
 
 impl FromStr for Move {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -137,28 +156,3 @@ impl FromStr for Move {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn rps_p1() {
-        let input = "A Y
-B X
-C Z";
-
-        let vec = parse(input).unwrap();
-        assert_eq!(15, part1(&vec));
-    }
-
-    #[test]
-    fn rps_p2() {
-        let input = "A Y
-B X
-C Z";
-
-        let vec = parse(input).unwrap();
-        assert_eq!(12, part2(&vec));
-    }
-}
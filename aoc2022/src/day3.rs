@@ -1,15 +1,37 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use bit_set::BitSet;
 use itertools::Itertools;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Rucksack>> {
+        Ok(parse(input))
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> AocResult<i32> {
+        Ok(part1(rucksacks))
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>) -> AocResult<i32> {
+        Ok(part2(rucksacks))
+    }
+}
 
 #[derive(Debug)]
-struct Rucksack {
+pub struct Rucksack {
     contents: BitSet,
     compartment_one: BitSet,
     compartment_two: BitSet,
 }
 
-#[aoc_generator(day3)]
 fn parse(input: &str) -> Vec<Rucksack> {
     input
         .lines()
@@ -27,15 +49,10 @@ fn parse(input: &str) -> Vec<Rucksack> {
         .collect()
 }
 
-#[aoc(day3, part1)]
 fn part1(rucksacks: &[Rucksack]) -> i32 {
-    rucksacks
-        .iter()
-        .map(|rucksack| sum_intersected_priorities(rucksack))
-        .sum()
+    rucksacks.iter().map(sum_intersected_priorities).sum()
 }
 
-#[aoc(day3, part2)]
 fn part2(rucksacks: &[Rucksack]) -> i32 {
     rucksacks
         .iter()
@@ -89,30 +106,4 @@ mod tests {
         assert_eq!(27, priority('A'));
         assert_eq!(52, priority('Z'));
     }
-
-    #[test]
-    fn rucksack_reorg_p1() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
-        let rucksacks = parse(input);
-
-        assert_eq!(157, part1(&rucksacks));
-    }
-
-    #[test]
-    fn rucksack_reorg_p2() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
-        let rucksacks = parse(input);
-
-        assert_eq!(70, part2(&rucksacks));
-    }
 }
@@ -1,13 +1,35 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::ops::RangeInclusive;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 4;
+
+    type Input = Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>> {
+        Ok(parse(input))
+    }
+
+    fn part1(assignments: &Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>) -> AocResult<i32> {
+        Ok(part1(assignments))
+    }
+
+    fn part2(assignments: &Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>) -> AocResult<i32> {
+        Ok(part2(assignments))
+    }
+}
 
 lazy_static! {
     static ref RE: Regex = Regex::new("(\\d+)-(\\d+),(\\d+)-(\\d+)").unwrap();
 }
 
-#[aoc_generator(day4)]
 fn parse(input: &str) -> Vec<(RangeInclusive<i32>, RangeInclusive<i32>)> {
     input
         .lines()
@@ -22,7 +44,6 @@ fn parse(input: &str) -> Vec<(RangeInclusive<i32>, RangeInclusive<i32>)> {
         .collect::<Vec<_>>()
 }
 
-#[aoc(day4, part1)]
 fn part1(assignments: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
     assignments
         .iter()
@@ -33,7 +54,6 @@ fn part1(assignments: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
         .count() as i32
 }
 
-#[aoc(day4, part2)]
 fn part2(assignments: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
     assignments
         .iter()
@@ -43,34 +63,3 @@ fn part2(assignments: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
         })
         .count() as i32
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn p1() {
-        let input = "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
-        let assignments = parse(input);
-
-        assert_eq!(2, part1(&assignments));
-    }
-
-    #[test]
-    fn p2() {
-        let input = "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
-        let assignments = parse(input);
-
-        assert_eq!(4, part2(&assignments));
-    }
-}
@@ -1,8 +1,31 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::VecDeque;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 5;
+
+    type Input = Input;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> AocResult<Input> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &Input) -> AocResult<String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Input) -> AocResult<String> {
+        Ok(part2(input))
+    }
+}
 
 lazy_static! {
     static ref RE: Regex = Regex::new("move (\\d+) from (\\d+) to (\\d+)").unwrap();
@@ -15,7 +38,7 @@ struct Move {
 }
 
 #[derive(Clone, Debug)]
-struct Input {
+pub struct Input {
     stacks: Vec<VecDeque<char>>,
     move_ops: Vec<Move>,
 }
@@ -33,7 +56,6 @@ impl Input {
     }
 }
 
-#[aoc_generator(day5)]
 fn parse(input: &str) -> Input {
     let parts = input.split("\n\n").collect_vec();
     Input {
@@ -42,7 +64,6 @@ fn parse(input: &str) -> Input {
     }
 }
 
-#[aoc(day5, part1)]
 fn part1(input: &Input) -> String {
     let mut input = input.clone();
     for move_op in &input.move_ops {
@@ -56,7 +77,6 @@ fn part1(input: &Input) -> String {
     input.top_of_stacks()
 }
 
-#[aoc(day5, part2)]
 fn part2(input: &Input) -> String {
     let mut input = input.clone();
     for move_op in &input.move_ops {
@@ -109,28 +129,3 @@ fn parse_move_ops(input: &str) -> Vec<Move> {
         })
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2";
-
-    #[test]
-    fn p1() {
-        assert_eq!("CMZ", part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!("MCD", part2(&parse(INPUT)));
-    }
-}
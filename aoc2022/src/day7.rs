@@ -1,6 +1,30 @@
 use crate::day7::Node::{Directory, File};
 use itertools::Itertools;
 use std::collections::HashMap;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 7;
+
+    type Input = DirectoryTree;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> AocResult<DirectoryTree> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &DirectoryTree) -> AocResult<u64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &DirectoryTree) -> AocResult<u64> {
+        Ok(part2(input))
+    }
+}
 
 type NodeHandle = usize;
 
@@ -47,14 +71,14 @@ impl Node {
     }
 }
 
-struct DirectoryTree {
+pub struct DirectoryTree {
     root: NodeHandle,
     cwd: NodeHandle,
     nodes: Vec<Node>,
 }
 
 impl DirectoryTree {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Self {
             root: 0,
             cwd: 0,
@@ -116,7 +140,6 @@ impl DirectoryTree {
     }
 }
 
-#[aoc_generator(day7)]
 fn parse(input: &str) -> DirectoryTree {
     let mut root = DirectoryTree::new();
 
@@ -145,7 +168,6 @@ fn parse(input: &str) -> DirectoryTree {
     root
 }
 
-#[aoc(day7, part1)]
 fn part1(input: &DirectoryTree) -> u64 {
     calculate_directory_sizes(input)
         .values()
@@ -153,7 +175,6 @@ fn part1(input: &DirectoryTree) -> u64 {
         .sum()
 }
 
-#[aoc(day7, part2)]
 fn part2(input: &DirectoryTree) -> u64 {
     let sizes = calculate_directory_sizes(input);
     let free_space = 70000000 - sizes.get(input.node(input.root)).unwrap();
@@ -183,44 +204,3 @@ fn calculate_directory_sizes(input: &DirectoryTree) -> HashMap<&Node, u64> {
     }
     dir_sizes
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k";
-
-    #[test]
-    fn p1() {
-        let node = parse(INPUT);
-        assert_eq!(95437, part1(&node));
-    }
-
-    #[test]
-    fn p2() {
-        let node = parse(INPUT);
-        assert_eq!(24933642, part2(&node));
-    }
-}
@@ -2,8 +2,31 @@ use itertools::Itertools;
 use std::collections::HashSet;
 use util::aoc::grid::Grid;
 use util::aoc::Point;
+use util::aoc::{AocResult, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 8;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Grid<u8>> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &Grid<u8>) -> AocResult<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Grid<u8>) -> AocResult<usize> {
+        Ok(part2(input))
+    }
+}
 
-#[aoc_generator(day8)]
 fn parse(input: &str) -> Grid<u8> {
     Grid::new(
         input
@@ -17,7 +40,6 @@ fn parse(input: &str) -> Grid<u8> {
     )
 }
 
-#[aoc(day8, part1)]
 fn part1(input: &Grid<u8>) -> usize {
     // For each exterior point, scan the row/column.
     let perimeter = input.perimeter_iterator().collect_vec();
@@ -49,7 +71,6 @@ fn part1(input: &Grid<u8>) -> usize {
     visible_trees.len()
 }
 
-#[aoc(day8, part2)]
 fn part2(input: &Grid<u8>) -> usize {
     // For each point, scan in each direction.
     // Ignore corners
@@ -59,7 +80,6 @@ fn part2(input: &Grid<u8>) -> usize {
     // If X is 0, scan right.
     input
         .point_iterator()
-        .into_iter()
         .map(|(point, start_height)| {
             vec![
                 Point::new(0, 1),
@@ -106,23 +126,3 @@ fn scan(start: Point, delta: Point, input: &Grid<u8>) -> Vec<Point> {
 
     points
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "30373
-25512
-65332
-33549
-35390";
-
-    #[test]
-    fn p1() {
-        assert_eq!(21, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(8, part2(&parse(INPUT)));
-    }
-}
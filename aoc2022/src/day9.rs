@@ -1,9 +1,32 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 use util::aoc::grid::Direction;
+use util::aoc::{AocResult, Solution};
 use util::aoc::{Point, SliceExt};
 
-#[aoc_generator(day9)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 9;
+
+    type Input = Vec<(u32, Direction)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Vec<(u32, Direction)>> {
+        Ok(parse(input))
+    }
+
+    fn part1(input: &Vec<(u32, Direction)>) -> AocResult<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<(u32, Direction)>) -> AocResult<usize> {
+        Ok(part2(input))
+    }
+}
+
 fn parse(input: &str) -> Vec<(u32, Direction)> {
     input
         .lines()
@@ -24,12 +47,10 @@ fn to_direction(part: &str) -> Direction {
     }
 }
 
-#[aoc(day9, part1)]
 fn part1(input: &[(u32, Direction)]) -> usize {
     simulate_rope(input, 2).len()
 }
 
-#[aoc(day9, part2)]
 fn part2(input: &[(u32, Direction)]) -> usize {
     simulate_rope(input, 10).len()
 }
@@ -47,7 +68,7 @@ fn simulate_rope(input: &[(u32, Direction)], rope_size: usize) -> HashSet<Point>
                 }
 
                 if head.distance(tail) as i32 > 1 {
-                    std::mem::swap(tail, &mut move_tail(head, tail));
+                    *tail = move_tail(head, tail);
                 }
 
                 if i + 2 == knot_count {
@@ -73,35 +94,3 @@ fn move_tail(head: &Point, tail: &Point) -> Point {
         })
         .unwrap()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-    const INPUT2: &str = "R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
-
-    #[test]
-    fn p1() {
-        assert_eq!(13, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn p2() {
-        assert_eq!(1, part2(&parse(INPUT)));
-        assert_eq!(36, part2(&parse(INPUT2)));
-    }
-}
@@ -1,67 +1,47 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
 use util::aoc::frequency::FrequencyMap;
+use util::aoc::*;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|c| c.parse::<i32>())
+                    .collect_tuple()
+                    .ok_or_else(|| util::format_err!("Expected two numbers: {}", l))
+                    .and_then(|(a, b)| Ok((a?, b?)))
+            })
+            .collect::<AocResult<Vec<_>>>()
+            .map(|pairs| pairs.into_iter().unzip())
+    }
 
-#[aoc_generator(day1)]
-fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
-    input
-        .lines()
-        .map(|l| {
-            l.split_whitespace()
-                .map(|c| c.parse().unwrap())
-                .tuples::<(i32, i32)>()
-                .next()
-                .unwrap()
-        })
-        .unzip()
-}
-
-#[aoc(day1, part1)]
-fn part1(lists: &(Vec<i32>, Vec<i32>)) -> i32 {
-    let (a, b) = lists;
-    let sorted_a = a.iter().cloned().sorted().collect_vec();
-    let sorted_b = b.iter().cloned().sorted().collect_vec();
-
-    sorted_a
-        .iter()
-        .zip(sorted_b.iter())
-        .map(|(a, b)| (a - b).abs())
-        .sum()
-}
-
-#[aoc(day1, part2)]
-fn part2(lists: &(Vec<i32>, Vec<i32>)) -> i32 {
-    let (a, b) = lists;
-    let b_frequency: FrequencyMap<i32> = b.iter().cloned().collect();
-
-    a.iter().map(|v| v * b_frequency.count(v) as i32).sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn list_distances_p1() {
-        let lists = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
+    fn part1(lists: &Self::Input) -> AocResult<i32> {
+        let (a, b) = lists;
+        let sorted_a = a.iter().cloned().sorted().collect_vec();
+        let sorted_b = b.iter().cloned().sorted().collect_vec();
 
-        assert_eq!(11, part1(&parse(&lists)));
+        Ok(sorted_a
+            .iter()
+            .zip(sorted_b.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum())
     }
 
-    #[test]
-    fn list_distances_p2() {
-        let lists = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
+    fn part2(lists: &Self::Input) -> AocResult<i32> {
+        let (a, b) = lists;
+        let b_frequency: FrequencyMap<i32> = b.iter().cloned().collect();
 
-        assert_eq!(31, part2(&parse(&lists)));
+        Ok(a.iter().map(|v| v * b_frequency.count(v) as i32).sum())
     }
 }
@@ -1,8 +1,10 @@
-extern crate aoc_runner;
+pub mod day1;
 
-#[macro_use]
-extern crate aoc_runner_derive;
+use util::aoc::solution::Day;
 
-pub mod day1;
+pub fn solutions() -> Vec<Box<dyn Day>> {
+    util::solution_days![day1::Day1]
+}
 
-aoc_lib! { year = 2024 }
+// A test per part of each example in `puzzle_examples/`, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
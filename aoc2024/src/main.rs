@@ -1,7 +0,0 @@
-extern crate aoc2024;
-extern crate aoc_runner;
-extern crate aoc_runner_derive;
-
-use aoc_runner_derive::aoc_main;
-
-aoc_main! { lib = aoc2024 }
@@ -0,0 +1,55 @@
+//! The 2018 day 9 marble game played on each ring structure, returning the high score. Shared by
+//! the `ring` benchmark and the ring tests, which each bring `DequeRing`, `SuccessorRing` and
+//! `UsefulLinkedList` into scope.
+
+use super::{DequeRing, SuccessorRing, UsefulLinkedList};
+
+pub fn linked_list(players: usize, last_marble: usize) -> usize {
+    let mut scores = vec![0; players];
+    let mut marbles = UsefulLinkedList::new();
+    let mut current = marbles.push_back(0);
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            current = marbles.step(current, -7);
+            let next = marbles.next_circular(current);
+            scores[marble % players] += marble + marbles.remove(current);
+            current = next;
+        } else {
+            current = marbles.insert_after(marbles.next_circular(current), marble);
+        }
+    }
+    scores.into_iter().max().unwrap()
+}
+
+pub fn successor_ring(players: usize, last_marble: usize) -> usize {
+    let mut scores = vec![0; players];
+    let mut marbles = SuccessorRing::new(vec![0], last_marble + 1);
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            marbles.rotate(-7);
+            let removed = marbles.current().unwrap();
+            marbles.remove(removed);
+            scores[marble % players] += marble + removed;
+        } else {
+            marbles.insert_after(marbles.next(marbles.current().unwrap()), marble);
+            marbles.set_current(marble);
+        }
+    }
+    scores.into_iter().max().unwrap()
+}
+
+pub fn deque_ring(players: usize, last_marble: usize) -> usize {
+    let mut scores = vec![0; players];
+    let mut marbles: DequeRing<usize> = vec![0].into_iter().collect();
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            marbles.rotate(-7);
+            scores[marble % players] += marble + marbles.remove_current().unwrap();
+        } else {
+            marbles.rotate(1);
+            marbles.insert_after(marble);
+            marbles.rotate(1);
+        }
+    }
+    scores.into_iter().max().unwrap()
+}
@@ -0,0 +1,28 @@
+//! Plays the 2018 day 9 marble game with each of the ring structures in util, to compare them.
+//! Run with `cargo bench --bench ring`.
+
+use util::aoc::linked_list::UsefulLinkedList;
+use util::aoc::ring::{DequeRing, SuccessorRing};
+use util::aoc::time;
+
+mod marbles;
+
+const PLAYERS: usize = 459;
+const LAST_MARBLE: usize = 7_210_300;
+
+fn main() {
+    let expected = time("UsefulLinkedList", || {
+        marbles::linked_list(PLAYERS, LAST_MARBLE)
+    });
+    assert_eq!(
+        expected,
+        time("SuccessorRing", || marbles::successor_ring(
+            PLAYERS,
+            LAST_MARBLE
+        ))
+    );
+    assert_eq!(
+        expected,
+        time("DequeRing", || marbles::deque_ring(PLAYERS, LAST_MARBLE))
+    );
+}
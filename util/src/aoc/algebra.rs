@@ -0,0 +1,510 @@
+use crate::aoc::math::gcd;
+use num_traits::{One, Zero};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert_ne!(0, denominator, "Zero denominator");
+
+        let sign = denominator.signum();
+        let divisor = gcd(numerator, denominator);
+        Rational {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    pub const fn integer(n: i128) -> Rational {
+        Rational {
+            numerator: n,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Rational::integer(n)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::integer(n as i128)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(n: i32) -> Self {
+        Rational::integer(n as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        Rational::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        Rational::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        Rational::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Rational::integer(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Rational::integer(1)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// A dense row-major matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    values: Vec<T>,
+}
+
+impl<T: Copy + Zero + One> Matrix<T> {
+    pub fn new(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "Ragged matrix");
+
+        Matrix {
+            rows: rows.len(),
+            cols,
+            values: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            rows,
+            cols,
+            values: vec![T::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut identity = Matrix::zeros(n, n);
+        for i in 0..n {
+            identity[(i, i)] = T::one();
+        }
+        identity
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::zeros(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                transposed[(c, r)] = self[(r, c)];
+            }
+        }
+        transposed
+    }
+
+    pub fn mul_vector(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(self.cols, vector.len(), "Dimension mismatch");
+
+        (0..self.rows)
+            .map(|r| {
+                self.row(r)
+                    .iter()
+                    .zip(vector)
+                    .fold(T::zero(), |sum, (&a, &b)| sum + a * b)
+            })
+            .collect()
+    }
+
+    /// Raises a square matrix to a power by repeated squaring, which steps a linear recurrence
+    /// `exp` times in `O(n^3 log exp)`.
+    pub fn pow(&self, exp: u64) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "Only square matrices have powers");
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.cols {
+            self.values.swap(a * self.cols + c, b * self.cols + c);
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.values[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.values[row * self.cols + col]
+    }
+}
+
+impl<T: Copy + Zero + One> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "Dimension mismatch");
+
+        let mut product = Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(r, k)];
+                for c in 0..rhs.cols {
+                    product[(r, c)] = product[(r, c)] + a * rhs[(k, c)];
+                }
+            }
+        }
+        product
+    }
+}
+
+impl Matrix<i128> {
+    /// The determinant by Bareiss' fraction-free elimination, where every intermediate value is
+    /// itself a determinant of a minor, so the arithmetic stays exact in integers.
+    pub fn determinant(&self) -> i128 {
+        assert_eq!(
+            self.rows, self.cols,
+            "Only square matrices have determinants"
+        );
+
+        let n = self.rows;
+        let mut m = self.clone();
+        let mut sign = 1;
+        let mut previous = 1;
+        for k in 0..n {
+            if m[(k, k)] == 0 {
+                match (k + 1..n).find(|&r| m[(r, k)] != 0) {
+                    Some(pivot) => {
+                        m.swap_rows(k, pivot);
+                        sign = -sign;
+                    }
+                    None => return 0,
+                }
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    m[(i, j)] = (m[(i, j)] * m[(k, k)] - m[(i, k)] * m[(k, j)]) / previous;
+                }
+            }
+            previous = m[(k, k)];
+        }
+        if n == 0 {
+            1
+        } else {
+            sign * m[(n - 1, n - 1)]
+        }
+    }
+}
+
+impl Matrix<Rational> {
+    /// Brings the matrix into reduced row echelon form by Gaussian elimination, returning it along
+    /// with its rank.
+    pub fn rref(&self) -> (Matrix<Rational>, usize) {
+        let mut m = self.clone();
+        let mut rank = 0;
+        for col in 0..m.cols {
+            let pivot = match (rank..m.rows).find(|&r| !m[(r, col)].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            m.swap_rows(rank, pivot);
+
+            let scale = m[(rank, col)];
+            for c in 0..m.cols {
+                m[(rank, c)] = m[(rank, c)] / scale;
+            }
+            for r in 0..m.rows {
+                let factor = m[(r, col)];
+                if r != rank && !factor.is_zero() {
+                    for c in 0..m.cols {
+                        m[(r, c)] = m[(r, c)] - factor * m[(rank, c)];
+                    }
+                }
+            }
+
+            rank += 1;
+            if rank == m.rows {
+                break;
+            }
+        }
+        (m, rank)
+    }
+
+    pub fn determinant(&self) -> Rational {
+        assert_eq!(
+            self.rows, self.cols,
+            "Only square matrices have determinants"
+        );
+
+        let mut m = self.clone();
+        let mut determinant = Rational::one();
+        for col in 0..m.cols {
+            let pivot = match (col..m.rows).find(|&r| !m[(r, col)].is_zero()) {
+                Some(pivot) => pivot,
+                None => return Rational::zero(),
+            };
+            if pivot != col {
+                m.swap_rows(col, pivot);
+                determinant = -determinant;
+            }
+
+            determinant = determinant * m[(col, col)];
+            for r in col + 1..m.rows {
+                let factor = m[(r, col)] / m[(col, col)];
+                for c in col..m.cols {
+                    m[(r, c)] = m[(r, c)] - factor * m[(col, c)];
+                }
+            }
+        }
+        determinant
+    }
+
+    /// The unique `x` with `self * x = b`, or `None` if there are no solutions or infinitely many.
+    pub fn solve(&self, b: &[Rational]) -> Option<Vec<Rational>> {
+        assert_eq!(self.rows, b.len(), "Dimension mismatch");
+
+        let augmented = Matrix::new(
+            (0..self.rows)
+                .map(|r| {
+                    let mut row = self.row(r).to_vec();
+                    row.push(b[r]);
+                    row
+                })
+                .collect(),
+        );
+        let (reduced, rank) = augmented.rref();
+
+        // A pivot in the last column means 0 = 1, and fewer pivots than unknowns leaves some free.
+        let pivots = (0..rank)
+            .map(|r| (0..reduced.cols).find(|&c| !reduced[(r, c)].is_zero()))
+            .collect::<Option<Vec<_>>>()?;
+        if rank != self.cols || pivots.contains(&self.cols) {
+            return None;
+        }
+
+        Some((0..self.cols).map(|r| reduced[(r, self.cols)]).collect())
+    }
+}
+
+/// Solves the square integer system `a * x = b`, returning `x` only if it's unique and entirely
+/// integral. Suits 2x2 and 3x3 puzzles where fractional answers mean "impossible".
+pub fn solve_integral<const N: usize>(a: [[i128; N]; N], b: [i128; N]) -> Option<[i128; N]> {
+    let matrix = Matrix::new(
+        a.iter()
+            .map(|row| row.iter().map(|&v| Rational::integer(v)).collect())
+            .collect(),
+    );
+    let b = b.map(Rational::integer);
+
+    let x = matrix.solve(&b)?;
+    let mut integral = [0; N];
+    for (i, value) in x.iter().enumerate() {
+        integral[i] = value.to_integer()?;
+    }
+    Some(integral)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        assert_eq!(r(1, 2), r(-2, -4));
+        assert_eq!(-1, r(1, -3).numerator());
+        assert_eq!(r(5, 6), r(1, 2) + r(1, 3));
+        assert_eq!(r(1, 6), r(1, 2) - r(1, 3));
+        assert_eq!(r(1, 6), r(1, 2) * r(1, 3));
+        assert_eq!(r(3, 2), r(1, 2) / r(1, 3));
+        assert_eq!(Some(4), r(8, 2).to_integer());
+        assert_eq!(None, r(7, 2).to_integer());
+        assert_eq!("-7/2", r(7, -2).to_string());
+    }
+
+    #[test]
+    fn lanternfish() {
+        // 2021 day 6: row i of the state holds the fish with timer i.
+        let mut rules = Matrix::<i128>::zeros(9, 9);
+        for timer in 1..9 {
+            rules[(timer - 1, timer)] = 1;
+        }
+        rules[(6, 0)] = 1;
+        rules[(8, 0)] = 1;
+
+        let fish = [0, 1, 1, 2, 1, 0, 0, 0, 0];
+        let count = |days| rules.pow(days).mul_vector(&fish).iter().sum::<i128>();
+
+        assert_eq!(26, count(18));
+        assert_eq!(5934, count(80));
+        assert_eq!(26_984_457_539, count(256));
+    }
+
+    #[test]
+    fn elimination() {
+        let m: Matrix<Rational> = Matrix::new(vec![
+            vec![2.into(), 1.into(), (-1).into()],
+            vec![(-3).into(), (-1).into(), 2.into()],
+            vec![(-2).into(), 1.into(), 2.into()],
+        ]);
+        let b = [8.into(), (-11).into(), (-3).into()];
+
+        assert_eq!(Some(vec![2.into(), 3.into(), (-1).into()]), m.solve(&b));
+        assert_eq!(r(-1, 1), m.determinant());
+        assert_eq!(
+            -1,
+            Matrix::new(vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]]).determinant()
+        );
+        assert_eq!(
+            -24,
+            Matrix::new(vec![vec![0, 2, 0], vec![3, 0, 0], vec![0, 0, 4]]).determinant()
+        );
+        assert_eq!(3, m.rref().1);
+        assert_eq!(Matrix::identity(3), m.rref().0);
+    }
+
+    #[test]
+    fn singular_systems() {
+        let m: Matrix<Rational> =
+            Matrix::new(vec![vec![1.into(), 2.into()], vec![2.into(), 4.into()]]);
+
+        assert_eq!(Rational::zero(), m.determinant());
+        assert_eq!(1, m.rref().1);
+        assert_eq!(None, m.solve(&[3.into(), 6.into()]));
+        assert_eq!(None, m.solve(&[3.into(), 7.into()]));
+    }
+
+    #[test]
+    fn integral_solutions() {
+        // Claw machines: presses of buttons A and B that land exactly on the prize.
+        assert_eq!(
+            Some([80, 40]),
+            solve_integral([[94, 22], [34, 67]], [8400, 5400])
+        );
+        assert_eq!(None, solve_integral([[26, 67], [66, 21]], [12748, 12176]));
+        assert_eq!(
+            Some([1, -2, 3]),
+            solve_integral([[1, 1, 1], [0, 2, 5], [2, 5, -1]], [2, 11, -11])
+        );
+    }
+}
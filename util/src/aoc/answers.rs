@@ -0,0 +1,122 @@
+use crate::aoc::{AocError, AocResult};
+use crate::format_err;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// The file in each year's crate holding its recorded answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The known answers for one year, stored as TOML with a table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = "1167"
+/// part2 = "1130"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u32, DayAnswers>,
+}
+
+impl Answers {
+    /// Reads answers from `path`, treating a missing file as no answers.
+    pub fn load(path: &Path) -> AocResult<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Answers::parse(&contents).map_err(|e| format_err!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(contents: &str) -> AocResult<Answers> {
+        let tables: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(AocError::parse)?;
+
+        let mut days = BTreeMap::new();
+        for (key, answers) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(|| format_err!("Expected a table named dayN, got [{}]", key))?;
+            days.insert(day, answers);
+        }
+
+        Ok(Answers { days })
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        std::fs::write(path, self.to_toml())?;
+        Ok(())
+    }
+
+    /// The answers as TOML, with the days in numeric order.
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        for (day, answers) in &self.days {
+            if !toml.is_empty() {
+                toml.push('\n');
+            }
+            writeln!(toml, "[day{}]", day).unwrap();
+            for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+                if let Some(answer) = answer {
+                    writeln!(toml, "part{} = {:?}", part, answer).unwrap();
+                }
+            }
+        }
+        toml
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: impl Into<String>) {
+        let answers = self.days.entry(day).or_default();
+        match part {
+            1 => answers.part1 = Some(answer.into()),
+            2 => answers.part2 = Some(answer.into()),
+            _ => panic!("There is no part {}", part),
+        }
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_round_trip() {
+        let toml = "[day2]\npart1 = \"abc\"\n\n[day10]\npart1 = \"1\"\npart2 = \"2\"\n";
+        let answers = Answers::parse(toml).unwrap();
+
+        assert_eq!(Some("abc"), answers.get(2, 1));
+        assert_eq!(None, answers.get(2, 2));
+        assert_eq!(Some("2"), answers.get(10, 2));
+        assert_eq!(toml, answers.to_toml());
+
+        let mut answers = answers;
+        answers.set(2, 2, "def");
+        assert_eq!(Some("def"), answers.get(2, 2));
+
+        assert!(Answers::parse("[tuesday]\npart1 = \"1\"").is_err());
+        assert!(Answers::parse("[day1]\npart3 = \"1\"").is_err());
+    }
+}
@@ -16,14 +16,14 @@ impl<T: Clone + Eq + Hash + Debug> AStarResult<T> {
     fn from_path(start: T, predecessor: &HashMap<T, T>, cost: &HashMap<T, Cost>) -> AStarResult<T> {
         let mut path = vec![start.clone()];
         let mut point = &start;
-        while let Some(next_point) = predecessor.get(&point) {
+        while let Some(next_point) = predecessor.get(point) {
             path.push(next_point.clone());
             point = next_point;
         }
 
         path.reverse();
 
-        let total_cost = *cost.get(&path.iter().last().unwrap()).unwrap_or(&0);
+        let total_cost = *cost.get(path.iter().last().unwrap()).unwrap_or(&0);
         AStarResult::Success(path, total_cost)
     }
 }
@@ -64,7 +64,7 @@ pub fn search<
     let mut frontier: BinaryHeap<ValueWithCost<T>> = BinaryHeap::new();
     frontier.push(ValueWithCost {
         value: start.clone(),
-        estimated_cost: heuristic_fn(&start),
+        estimated_cost: heuristic_fn(start),
     });
     let mut predecessor: HashMap<T, T> = HashMap::new();
     let mut path_cost: HashMap<T, Cost> = HashMap::new();
@@ -102,8 +102,5 @@ pub fn search<
 }
 
 fn get_path_cost<T: Clone + Hash + Eq>(value: &T, cost_map: &HashMap<T, Cost>) -> Option<u32> {
-    match cost_map.get(value) {
-        Some(cost) => Some(cost.clone()),
-        _ => None,
-    }
+    cost_map.get(value).copied()
 }
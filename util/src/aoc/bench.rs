@@ -0,0 +1,308 @@
+use crate::aoc::statistics::Statistics;
+use crate::aoc::{AocError, AocResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The file, at the root of the workspace, that benchmark runs are recorded in.
+pub const HISTORY_FILE: &str = "bench_history.json";
+
+/// How many times to run something before and while measuring it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            samples: 20,
+        }
+    }
+}
+
+/// The timings of one stage of a day, such as `Parse` or `Part 1`, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub stage: String,
+    pub samples: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    pub min: f64,
+}
+
+impl Measurement {
+    /// Summarizes sampled durations, or `None` if there are none.
+    pub fn from_samples(stage: &str, samples: &[Duration]) -> Option<Measurement> {
+        let nanos = || samples.iter().map(|sample| sample.as_nanos() as f64);
+        let summary = nanos().summary()?;
+
+        Some(Measurement {
+            stage: stage.to_string(),
+            samples: summary.count,
+            mean: summary.mean.raw(),
+            median: nanos().median()?.raw(),
+            stddev: summary.stddev().raw(),
+            min: summary.min.raw(),
+        })
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: mean {:?}, median {:?}, stddev {:?}, min {:?} ({} samples)",
+            self.stage,
+            nanos(self.mean),
+            nanos(self.median),
+            nanos(self.stddev),
+            nanos(self.min),
+            self.samples
+        )
+    }
+}
+
+fn nanos(value: f64) -> Duration {
+    Duration::from_nanos(value.round() as u64)
+}
+
+/// Runs `function` `config.warmup` times, then times it `config.samples` times.
+pub fn measure<F, T>(stage: &str, config: &BenchConfig, mut function: F) -> Measurement
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..config.warmup {
+        black_box(function());
+    }
+
+    let samples = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(function());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Measurement::from_samples(stage, &samples).expect("at least one sample")
+}
+
+/// Every stage measured for one day.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u32,
+    pub day: u32,
+    pub measurements: Vec<Measurement>,
+}
+
+/// The benchmarks taken at one commit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub commit: String,
+    /// Seconds since the Unix epoch when the run was last recorded.
+    pub timestamp: u64,
+    pub days: Vec<DayBench>,
+}
+
+/// Benchmark runs, oldest first, with at most one run per commit.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Reads a history file, treating a missing one as empty.
+    pub fn load(path: &Path) -> AocResult<History> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        serde_json::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|e| AocError::parse(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let json = serde_json::to_string_pretty(self).map_err(AocError::invalid_input)?;
+        std::fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// The most recent measurement of a stage, and the commit it was taken at.
+    pub fn latest(&self, year: u32, day: u32, stage: &str) -> Option<(&str, &Measurement)> {
+        self.runs.iter().rev().find_map(|run| {
+            run.days
+                .iter()
+                .filter(|bench| bench.year == year && bench.day == day)
+                .flat_map(|bench| &bench.measurements)
+                .find(|measurement| measurement.stage == stage)
+                .map(|measurement| (run.commit.as_str(), measurement))
+        })
+    }
+
+    /// Adds a day's measurements to the run for `commit`, replacing any earlier ones for that day,
+    /// and moves that run to the end.
+    pub fn record(&mut self, commit: &str, bench: DayBench) {
+        let mut run = match self.runs.iter().position(|run| run.commit == commit) {
+            Some(index) => self.runs.remove(index),
+            None => Run {
+                commit: commit.to_string(),
+                timestamp: 0,
+                days: Vec::new(),
+            },
+        };
+
+        run.timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        run.days
+            .retain(|existing| (existing.year, existing.day) != (bench.year, bench.day));
+        run.days.push(bench);
+        run.days.sort_by_key(|bench| (bench.year, bench.day));
+        self.runs.push(run);
+    }
+}
+
+/// How much slower (positive) or faster (negative) `current`'s median is than `previous`'s, as a
+/// percentage. There's none when `previous` took no measurable time.
+pub fn delta(previous: &Measurement, current: &Measurement) -> Option<f64> {
+    if previous.median == 0.0 {
+        return None;
+    }
+    Some((current.median - previous.median) / previous.median * 100.0)
+}
+
+/// Describes a measurement along with its change since the previous one, if there is one that
+/// a change can be worked out from.
+pub fn describe(current: &Measurement, previous: Option<(&str, &Measurement)>) -> String {
+    match previous.and_then(|(commit, previous)| Some((commit, delta(previous, current)?))) {
+        Some((commit, delta)) => format!("{} [{:+.1}% vs {}]", current, delta, commit),
+        None => current.to_string(),
+    }
+}
+
+/// The abbreviated hash of the commit checked out in `dir`, or `unknown` outside of a git
+/// checkout.
+pub fn current_commit(dir: &Path) -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(stage: &str, median: f64) -> Measurement {
+        Measurement {
+            stage: stage.to_string(),
+            samples: 1,
+            mean: median,
+            median,
+            stddev: 0.0,
+            min: median,
+        }
+    }
+
+    fn bench(year: u32, day: u32, median: f64) -> DayBench {
+        DayBench {
+            year,
+            day,
+            measurements: vec![measurement("Part 1", median)],
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let samples = [10, 30, 20, 40].map(Duration::from_nanos);
+        let measurement = Measurement::from_samples("Part 1", &samples).unwrap();
+
+        assert_eq!(measurement.samples, 4);
+        assert_eq!(measurement.mean, 25.0);
+        assert_eq!(measurement.median, 25.0);
+        assert_eq!(measurement.min, 10.0);
+        assert!((measurement.stddev - 125f64.sqrt()).abs() < 1e-9);
+        assert!(Measurement::from_samples("Part 1", &[]).is_none());
+    }
+
+    #[test]
+    fn measures_after_warming_up() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            samples: 5,
+        };
+        let measurement = measure("Parse", &config, || calls += 1);
+
+        assert_eq!(calls, 7);
+        assert_eq!(measurement.samples, 5);
+    }
+
+    #[test]
+    fn keeps_one_run_per_commit() {
+        let mut history = History::default();
+        history.record("aaa", bench(2024, 1, 100.0));
+        history.record("bbb", bench(2024, 1, 150.0));
+        history.record("aaa", bench(2024, 2, 10.0));
+        history.record("aaa", bench(2024, 1, 80.0));
+
+        assert_eq!(
+            history
+                .runs
+                .iter()
+                .map(|run| &run.commit)
+                .collect::<Vec<_>>(),
+            ["bbb", "aaa"]
+        );
+        assert_eq!(
+            history.runs[1].days,
+            [bench(2024, 1, 80.0), bench(2024, 2, 10.0)]
+        );
+
+        let (commit, latest) = history.latest(2024, 1, "Part 1").unwrap();
+        assert_eq!((commit, latest.median), ("aaa", 80.0));
+        assert!(history.latest(2024, 3, "Part 1").is_none());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut history = History::default();
+        history.record("aaa", bench(2024, 1, 100.0));
+
+        let json = serde_json::to_string(&history).unwrap();
+        assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+    }
+
+    #[test]
+    fn describes_deltas() {
+        let previous = measurement("Part 1", 200.0);
+        let current = measurement("Part 1", 150.0);
+
+        assert_eq!(delta(&previous, &current), Some(-25.0));
+        assert!(describe(&current, Some(("aaa", &previous))).ends_with("[-25.0% vs aaa]"));
+        assert!(!describe(&current, None).contains('['));
+    }
+
+    #[test]
+    fn omits_deltas_from_a_zero_baseline() {
+        let previous = measurement("Part 1", 0.0);
+        let current = measurement("Part 1", 150.0);
+
+        assert_eq!(delta(&previous, &current), None);
+        assert_eq!(
+            describe(&current, Some(("aaa", &previous))),
+            current.to_string()
+        );
+    }
+}
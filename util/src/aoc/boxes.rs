@@ -0,0 +1,298 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Range, RangeInclusive};
+
+/// An axis-aligned box in `N` dimensions, covering `min[d] <= v < max[d]` along each axis. Being
+/// half-open means adjacent boxes share no cells, and a box's volume is just the product of its
+/// side lengths. Any box with a side of zero or negative length is empty.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AxisBox<const N: usize> {
+    pub min: [i64; N],
+    pub max: [i64; N],
+}
+
+/// An axis-aligned rectangle.
+pub type Rect = AxisBox<2>;
+
+/// An axis-aligned cuboid.
+pub type Cuboid = AxisBox<3>;
+
+impl<const N: usize> AxisBox<N> {
+    pub const fn from_bounds(min: [i64; N], max: [i64; N]) -> Self {
+        AxisBox { min, max }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|d| self.min[d] >= self.max[d])
+    }
+
+    /// The length of the box along axis `d`.
+    pub fn side(&self, d: usize) -> i64 {
+        (self.max[d] - self.min[d]).max(0)
+    }
+
+    /// The number of unit cells within the box.
+    pub fn volume(&self) -> i64 {
+        (0..N).map(|d| self.side(d)).product()
+    }
+
+    pub fn contains_point(&self, point: &[i64; N]) -> bool {
+        (0..N).all(|d| self.min[d] <= point[d] && point[d] < self.max[d])
+    }
+
+    /// Whether every cell of `other` also lies within this box.
+    pub fn contains(&self, other: &AxisBox<N>) -> bool {
+        other.is_empty()
+            || (0..N).all(|d| self.min[d] <= other.min[d] && other.max[d] <= self.max[d])
+    }
+
+    pub fn intersects(&self, other: &AxisBox<N>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The box covering the cells shared by both boxes, if there are any.
+    pub fn intersection(&self, other: &AxisBox<N>) -> Option<AxisBox<N>> {
+        let intersection = AxisBox {
+            min: std::array::from_fn(|d| self.min[d].max(other.min[d])),
+            max: std::array::from_fn(|d| self.max[d].min(other.max[d])),
+        };
+
+        if intersection.is_empty() {
+            None
+        } else {
+            Some(intersection)
+        }
+    }
+
+    /// Removes `other` from this box, returning the remaining cells as at most `2 * N` disjoint
+    /// boxes. Each axis in turn has the slabs below and above `other` sliced off, and the middle
+    /// carries on to the next axis.
+    pub fn subtract(&self, other: &AxisBox<N>) -> Vec<AxisBox<N>> {
+        let overlap = match self.intersection(other) {
+            Some(overlap) => overlap,
+            None if self.is_empty() => return vec![],
+            None => return vec![*self],
+        };
+
+        let mut pieces = Vec::with_capacity(2 * N);
+        let mut remaining = *self;
+        for d in 0..N {
+            if remaining.min[d] < overlap.min[d] {
+                let mut below = remaining;
+                below.max[d] = overlap.min[d];
+                pieces.push(below);
+            }
+            if overlap.max[d] < remaining.max[d] {
+                let mut above = remaining;
+                above.min[d] = overlap.max[d];
+                pieces.push(above);
+            }
+
+            remaining.min[d] = overlap.min[d];
+            remaining.max[d] = overlap.max[d];
+        }
+
+        pieces
+    }
+
+    /// The number of cells covered by at least one of the boxes.
+    pub fn union_volume<'a>(boxes: impl IntoIterator<Item = &'a AxisBox<N>>) -> i64 {
+        Self::covered_volume(boxes, 1)
+    }
+
+    /// The number of cells covered by at least `min_count` of the boxes. Sweeps slabs between the
+    /// distinct coordinates along each axis in turn, so the cost depends on the number of boxes
+    /// rather than on their size.
+    pub fn covered_volume<'a>(
+        boxes: impl IntoIterator<Item = &'a AxisBox<N>>,
+        min_count: usize,
+    ) -> i64 {
+        let boxes = boxes
+            .into_iter()
+            .filter(|b| !b.is_empty())
+            .collect::<Vec<_>>();
+        Self::covered_volume_from(&boxes, 0, min_count.max(1))
+    }
+
+    fn covered_volume_from(boxes: &[&AxisBox<N>], d: usize, min_count: usize) -> i64 {
+        if boxes.len() < min_count {
+            return 0;
+        }
+        if d == N {
+            return 1;
+        }
+
+        let mut breakpoints = boxes
+            .iter()
+            .flat_map(|b| [b.min[d], b.max[d]])
+            .collect::<Vec<_>>();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        breakpoints
+            .windows(2)
+            .map(|slab| {
+                let active = boxes
+                    .iter()
+                    .filter(|b| b.min[d] <= slab[0] && slab[1] <= b.max[d])
+                    .copied()
+                    .collect::<Vec<_>>();
+                (slab[1] - slab[0]) * Self::covered_volume_from(&active, d + 1, min_count)
+            })
+            .sum()
+    }
+}
+
+impl Rect {
+    /// A rectangle spanning the half-open ranges `x` and `y`.
+    pub fn new(x: Range<i64>, y: Range<i64>) -> Rect {
+        AxisBox::from_bounds([x.start, y.start], [x.end, y.end])
+    }
+
+    /// A rectangle spanning the inclusive ranges `x` and `y`.
+    pub fn from_inclusive(x: RangeInclusive<i64>, y: RangeInclusive<i64>) -> Rect {
+        Rect::new(*x.start()..*x.end() + 1, *y.start()..*y.end() + 1)
+    }
+
+    /// A rectangle with its top-left cell at `(left, top)`.
+    pub fn from_size(left: i64, top: i64, width: i64, height: i64) -> Rect {
+        Rect::new(left..left + width, top..top + height)
+    }
+
+    pub fn area(&self) -> i64 {
+        self.volume()
+    }
+}
+
+impl Cuboid {
+    /// A cuboid spanning the half-open ranges `x`, `y` and `z`.
+    pub fn new(x: Range<i64>, y: Range<i64>, z: Range<i64>) -> Cuboid {
+        AxisBox::from_bounds([x.start, y.start, z.start], [x.end, y.end, z.end])
+    }
+
+    /// A cuboid spanning the inclusive ranges `x`, `y` and `z`.
+    pub fn from_inclusive(
+        x: RangeInclusive<i64>,
+        y: RangeInclusive<i64>,
+        z: RangeInclusive<i64>,
+    ) -> Cuboid {
+        Cuboid::new(
+            *x.start()..*x.end() + 1,
+            *y.start()..*y.end() + 1,
+            *z.start()..*z.end() + 1,
+        )
+    }
+}
+
+impl<const N: usize> Display for AxisBox<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for d in 0..N {
+            if d > 0 {
+                write!(f, " x ")?;
+            }
+            write!(f, "{}..{}", self.min[d], self.max[d])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_and_containment() {
+        let a = Rect::from_size(1, 3, 4, 4);
+        let b = Rect::from_size(3, 1, 4, 4);
+        let c = Rect::from_size(5, 5, 2, 2);
+
+        assert_eq!(Some(Rect::from_size(3, 3, 2, 2)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+        assert!(!b.intersects(&c));
+        assert!(a.contains(&Rect::from_size(2, 4, 2, 2)));
+        assert!(!a.contains(&b));
+        assert!(a.contains_point(&[4, 6]));
+        assert!(!a.contains_point(&[5, 6]));
+    }
+
+    #[test]
+    fn subtract_produces_disjoint_pieces() {
+        let outer = Cuboid::from_inclusive(0..=9, 0..=9, 0..=9);
+        let inner = Cuboid::from_inclusive(3..=5, 3..=5, 3..=5);
+
+        let pieces = outer.subtract(&inner);
+
+        assert_eq!(6, pieces.len());
+        assert_eq!(1000 - 27, pieces.iter().map(Cuboid::volume).sum::<i64>());
+        for (i, a) in pieces.iter().enumerate() {
+            assert!(!a.intersects(&inner));
+            for b in &pieces[i + 1..] {
+                assert!(!a.intersects(b));
+            }
+        }
+    }
+
+    #[test]
+    fn subtract_edge_cases() {
+        let a = Rect::from_size(0, 0, 4, 4);
+
+        assert_eq!(vec![a], a.subtract(&Rect::from_size(10, 10, 1, 1)));
+        assert!(a.subtract(&Rect::from_size(-1, -1, 6, 6)).is_empty());
+        assert_eq!(
+            vec![Rect::from_size(2, 0, 2, 4)],
+            a.subtract(&Rect::from_size(0, 0, 2, 4))
+        );
+    }
+
+    #[test]
+    fn union_volume() {
+        let rects = [
+            Rect::from_size(1, 3, 4, 4),
+            Rect::from_size(3, 1, 4, 4),
+            Rect::from_size(5, 5, 2, 2),
+        ];
+
+        assert_eq!(16 + 16 + 4 - 4, Rect::union_volume(&rects));
+    }
+
+    #[test]
+    fn covered_volume_sample_claims() {
+        // 2018 day 3: square inches within two or more claims.
+        let claims = [
+            Rect::from_size(1, 3, 4, 4),
+            Rect::from_size(3, 1, 4, 4),
+            Rect::from_size(5, 5, 2, 2),
+        ];
+
+        assert_eq!(4, Rect::covered_volume(&claims, 2));
+        assert_eq!(
+            Rect::union_volume(&claims),
+            Rect::covered_volume(&claims, 1)
+        );
+        assert_eq!(0, Rect::covered_volume(&claims, 3));
+    }
+
+    #[test]
+    fn union_volume_matches_disjoint_pieces() {
+        // Reboot steps from the 2021 day 22 sample, applied by subtraction.
+        let steps = [
+            (true, Cuboid::from_inclusive(10..=12, 10..=12, 10..=12)),
+            (true, Cuboid::from_inclusive(11..=13, 11..=13, 11..=13)),
+            (false, Cuboid::from_inclusive(9..=11, 9..=11, 9..=11)),
+            (true, Cuboid::from_inclusive(10..=10, 10..=10, 10..=10)),
+        ];
+
+        let mut lit: Vec<Cuboid> = Vec::new();
+        for (on, cuboid) in &steps {
+            lit = lit
+                .iter()
+                .flat_map(|piece| piece.subtract(cuboid))
+                .collect();
+            if *on {
+                lit.push(*cuboid);
+            }
+        }
+
+        assert_eq!(39, lit.iter().map(Cuboid::volume).sum::<i64>());
+        assert_eq!(39, Cuboid::union_volume(&lit));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::aoc::boxes::Rect;
+use crate::aoc::grid::Grid;
+use crate::aoc::Point;
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// One axis of a compressed coordinate space. Sorted, distinct breakpoints `b` split the axis into
+/// cells, with cell `i` covering the real coordinates `b[i]..b[i + 1]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressedAxis {
+    breakpoints: Vec<i64>,
+}
+
+impl CompressedAxis {
+    pub fn new(breakpoints: impl IntoIterator<Item = i64>) -> CompressedAxis {
+        let mut breakpoints = breakpoints.into_iter().collect::<Vec<_>>();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        CompressedAxis { breakpoints }
+    }
+
+    /// The number of cells along the axis.
+    pub fn len(&self) -> usize {
+        self.breakpoints.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn breakpoints(&self) -> &[i64] {
+        &self.breakpoints
+    }
+
+    /// The cell containing a real coordinate.
+    pub fn index_of(&self, value: i64) -> Option<usize> {
+        let index = self.breakpoints.partition_point(|&b| b <= value);
+        if index == 0 || index >= self.breakpoints.len() {
+            None
+        } else {
+            Some(index - 1)
+        }
+    }
+
+    /// The real coordinates covered by a cell.
+    pub fn range(&self, index: usize) -> Range<i64> {
+        self.breakpoints[index]..self.breakpoints[index + 1]
+    }
+
+    /// How many real coordinates a cell covers.
+    pub fn width(&self, index: usize) -> i64 {
+        self.breakpoints[index + 1] - self.breakpoints[index]
+    }
+
+    /// The half-open range of cells exactly covering the real coordinates `range`, if both of its
+    /// ends are breakpoints.
+    pub fn cells(&self, range: Range<i64>) -> Option<Range<usize>> {
+        let start = self.breakpoints.binary_search(&range.start).ok()?;
+        let end = self.breakpoints.binary_search(&range.end).ok()?;
+        Some(start..end)
+    }
+}
+
+/// A dense `Grid` over compressed coordinates. Each cell stands in for a whole rectangle of the
+/// real plane, so puzzles with huge coordinates but few distinct edges can still be solved with
+/// a small grid, and summing `weight`s over cells gives exact real-world areas.
+#[derive(Clone, Debug)]
+pub struct CompressedGrid<T> {
+    x_axis: CompressedAxis,
+    y_axis: CompressedAxis,
+    grid: Grid<T>,
+}
+
+impl<T: Debug + Clone> CompressedGrid<T> {
+    pub fn new(
+        x_breakpoints: impl IntoIterator<Item = i64>,
+        y_breakpoints: impl IntoIterator<Item = i64>,
+        initial: T,
+    ) -> CompressedGrid<T> {
+        let x_axis = CompressedAxis::new(x_breakpoints);
+        let y_axis = CompressedAxis::new(y_breakpoints);
+        let grid = Grid::new(vec![vec![initial; x_axis.len()]; y_axis.len()]);
+
+        CompressedGrid {
+            x_axis,
+            y_axis,
+            grid,
+        }
+    }
+
+    /// Builds a grid whose breakpoints are the edges of the given rectangles, so each rectangle
+    /// covers a whole number of cells.
+    pub fn from_rects<'a>(rects: impl IntoIterator<Item = &'a Rect>, initial: T) -> Self {
+        let (xs, ys): (Vec<_>, Vec<_>) = rects
+            .into_iter()
+            .flat_map(|rect| [(rect.min[0], rect.min[1]), (rect.max[0], rect.max[1])])
+            .unzip();
+
+        CompressedGrid::new(xs, ys, initial)
+    }
+
+    pub fn x_axis(&self) -> &CompressedAxis {
+        &self.x_axis
+    }
+
+    pub fn y_axis(&self) -> &CompressedAxis {
+        &self.y_axis
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// The cell containing a real-world point.
+    pub fn to_compressed(&self, point: &Point) -> Option<Point> {
+        Some(Point::new(
+            self.x_axis.index_of(point.x as i64)? as i32,
+            self.y_axis.index_of(point.y as i64)? as i32,
+        ))
+    }
+
+    /// The real-world top-left corner of a cell.
+    pub fn to_real(&self, cell: &Point) -> Point {
+        let rect = self.cell_rect(cell);
+        Point::new(rect.min[0] as i32, rect.min[1] as i32)
+    }
+
+    /// The real-world area covered by a cell.
+    pub fn cell_rect(&self, cell: &Point) -> Rect {
+        Rect::new(
+            self.x_axis.range(cell.x as usize),
+            self.y_axis.range(cell.y as usize),
+        )
+    }
+
+    /// The number of real-world points a cell stands in for.
+    pub fn weight(&self, cell: &Point) -> i64 {
+        self.x_axis.width(cell.x as usize) * self.y_axis.width(cell.y as usize)
+    }
+
+    /// Looks up the cell containing a real-world point.
+    pub fn lookup(&self, point: &Point) -> Option<&T> {
+        self.to_compressed(point)
+            .and_then(|cell| self.grid.lookup(&cell))
+    }
+
+    pub fn lookup_cell(&self, cell: &Point) -> Option<&T> {
+        self.grid.lookup(cell)
+    }
+
+    pub fn update_cell(&mut self, cell: &Point, state: T) {
+        self.grid.update(cell, state);
+    }
+
+    /// Applies `update` to every cell within a real-world rectangle. Returns false, leaving the
+    /// grid untouched, if the rectangle's edges aren't breakpoints of the grid.
+    pub fn update_rect<F: Fn(&T) -> T>(&mut self, rect: &Rect, update: F) -> bool {
+        let (xs, ys) = match (
+            self.x_axis.cells(rect.min[0]..rect.max[0]),
+            self.y_axis.cells(rect.min[1]..rect.max[1]),
+        ) {
+            (Some(xs), Some(ys)) => (xs, ys),
+            _ => return false,
+        };
+
+        for y in ys {
+            for x in xs.clone() {
+                let cell = Point::new(x as i32, y as i32);
+                let state = update(self.grid.lookup(&cell).unwrap());
+                self.grid.update(&cell, state);
+            }
+        }
+
+        true
+    }
+
+    /// Sums the real-world area of every cell matching a predicate.
+    pub fn weighted_count<F: Fn(&T) -> bool>(&self, predicate: F) -> i64 {
+        self.grid
+            .point_iterator()
+            .filter(|(_, state)| predicate(state))
+            .map(|(cell, _)| self.weight(&cell))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axis_lookup() {
+        let axis = CompressedAxis::new(vec![10, 0, 3, 3, 1_000_000]);
+
+        assert_eq!(3, axis.len());
+        assert_eq!(None, axis.index_of(-1));
+        assert_eq!(Some(0), axis.index_of(0));
+        assert_eq!(Some(0), axis.index_of(2));
+        assert_eq!(Some(1), axis.index_of(3));
+        assert_eq!(Some(2), axis.index_of(999_999));
+        assert_eq!(None, axis.index_of(1_000_000));
+        assert_eq!(10..1_000_000, axis.range(2));
+        assert_eq!(Some(1..3), axis.cells(3..1_000_000));
+        assert_eq!(None, axis.cells(4..10));
+    }
+
+    #[test]
+    fn point_mapping() {
+        let grid = CompressedGrid::new(vec![0, 5, 100], vec![-10, 0, 10], false);
+
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            grid.to_compressed(&Point::new(50, -3))
+        );
+        assert_eq!(None, grid.to_compressed(&Point::new(100, 0)));
+        assert_eq!(Point::new(5, -10), grid.to_real(&Point::new(1, 0)));
+        assert_eq!(950, grid.weight(&Point::new(1, 1)));
+    }
+
+    #[test]
+    fn sample_claims() {
+        // 2018 day 3, scaled up so a dense grid wouldn't be practical.
+        let claims = [
+            Rect::from_size(1_000_000, 3_000_000, 4_000_000, 4_000_000),
+            Rect::from_size(3_000_000, 1_000_000, 4_000_000, 4_000_000),
+            Rect::from_size(5_000_000, 5_000_000, 2_000_000, 2_000_000),
+        ];
+
+        let mut fabric = CompressedGrid::from_rects(&claims, 0);
+        for claim in &claims {
+            assert!(fabric.update_rect(claim, |count| count + 1));
+        }
+
+        assert_eq!(
+            4_000_000_000_000,
+            fabric.weighted_count(|&count| count >= 2)
+        );
+        assert_eq!(
+            Rect::union_volume(&claims),
+            fabric.weighted_count(|&count| count >= 1)
+        );
+        assert_eq!(Some(&2), fabric.lookup(&Point::new(4_000_000, 4_000_000)));
+        assert_eq!(Some(&0), fabric.lookup(&Point::new(1_000_000, 1_000_000)));
+    }
+}
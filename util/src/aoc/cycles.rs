@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the sequence `x0, f(x0), f(f(x0)), ...` starts repeating: the states at steps `prefix` and
+/// `prefix + length` are the first repeated pair.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Needs constant memory, but calls `step` roughly three times as often
+/// as the sequence is long.
+pub fn floyd<T, F>(initial: T, mut step: F) -> Cycle
+where
+    T: PartialEq,
+    F: FnMut(&T) -> T,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    let mut prefix = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm. Needs constant memory, and usually fewer calls to `step` than `floyd`.
+pub fn brent<T, F>(initial: T, mut step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Finds a cycle by remembering every state, comparing them by `key` so that parts of the state
+/// that don't affect the future (like a step counter) can be ignored. Returns the states up to the
+/// first repeat, so any later state can be looked up without re-running the simulation.
+pub fn find_cycle_by_key<T, K, F, G>(initial: T, mut step: F, mut key: G) -> (Cycle, Vec<T>)
+where
+    K: Eq + Hash,
+    F: FnMut(&T) -> T,
+    G: FnMut(&T) -> K,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let current = states.last().unwrap();
+        if let Some(prefix) = seen.insert(key(current), states.len() - 1) {
+            states.pop();
+            let length = states.len() - prefix;
+            return (Cycle { prefix, length }, states);
+        }
+        let next = step(current);
+        states.push(next);
+    }
+}
+
+/// Finds a cycle by remembering every state. See `find_cycle_by_key`.
+pub fn find_cycle<T, F>(initial: T, step: F) -> (Cycle, Vec<T>)
+where
+    T: Eq + Hash + Clone,
+    F: FnMut(&T) -> T,
+{
+    find_cycle_by_key(initial, step, T::clone)
+}
+
+/// The state after `n` steps, skipping whole cycles once one has been found.
+pub fn state_at<T, F>(initial: T, mut step: F, n: usize) -> T
+where
+    T: Eq + Hash + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    for i in 0..n {
+        if let Some(prefix) = seen.insert(states[i].clone(), i) {
+            let cycle = Cycle {
+                prefix,
+                length: i - prefix,
+            };
+            return states.swap_remove(cycle.equivalent_step(n));
+        }
+        let next = step(&states[i]);
+        states.push(next);
+    }
+    states.pop().unwrap()
+}
+
+/// A score that grows by a constant `delta` per step from `step` onwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LinearGrowth {
+    pub step: usize,
+    pub value: i64,
+    pub delta: i64,
+}
+
+impl LinearGrowth {
+    /// The extrapolated score at step `n`.
+    pub fn value_at(&self, n: usize) -> i64 {
+        self.value + (n as i64 - self.step as i64) * self.delta
+    }
+}
+
+/// Steps a simulation until its score has changed by the same amount `window` times in a row, for
+/// puzzles where the state drifts rather than repeats. Loops forever if that never happens.
+pub fn linear_growth<T, F, S>(initial: T, mut step: F, mut score: S, window: usize) -> LinearGrowth
+where
+    F: FnMut(&T) -> T,
+    S: FnMut(&T) -> i64,
+{
+    let mut state = initial;
+    let mut value = score(&state);
+    let mut growth = LinearGrowth {
+        step: 0,
+        value,
+        delta: 0,
+    };
+    let mut repeats = 0;
+
+    for i in 1.. {
+        state = step(&state);
+        let next = score(&state);
+        if next - value == growth.delta && i > 1 {
+            repeats += 1;
+            if repeats >= window {
+                break;
+            }
+        } else {
+            growth = LinearGrowth {
+                step: i - 1,
+                value,
+                delta: next - value,
+            };
+            repeats = 1;
+            if window <= 1 {
+                break;
+            }
+        }
+        value = next;
+    }
+
+    growth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A sequence with a prefix of 3 and a cycle of 5: 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    fn tail_and_loop(x: &u32) -> u32 {
+        if *x == 7 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let expected = Cycle {
+            prefix: 3,
+            length: 5,
+        };
+
+        assert_eq!(expected, floyd(0, tail_and_loop));
+        assert_eq!(expected, brent(0, tail_and_loop));
+        assert_eq!(expected, find_cycle(0, tail_and_loop).0);
+        assert_eq!(
+            Cycle {
+                prefix: 0,
+                length: 1
+            },
+            brent(9, |&x| x)
+        );
+    }
+
+    #[test]
+    fn states_at_large_steps() {
+        let cycle = brent(0, tail_and_loop);
+        assert_eq!(2, cycle.equivalent_step(2));
+        assert_eq!(5, cycle.equivalent_step(1_000_000_000));
+
+        assert_eq!(5, state_at(0, tail_and_loop, 1_000_000_000));
+        assert_eq!(6, state_at(0, tail_and_loop, 6));
+        assert_eq!(0, state_at(0, tail_and_loop, 0));
+
+        let (cycle, states) = find_cycle(0, tail_and_loop);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], states);
+        assert_eq!(3, states[cycle.equivalent_step(8)]);
+    }
+
+    #[test]
+    fn cycle_by_key() {
+        // Steps counted alongside the state still repeat once the counter is ignored.
+        let (cycle, states) =
+            find_cycle_by_key((0, 0), |&(x, n)| (tail_and_loop(&x), n + 1), |&(x, _)| x);
+
+        assert_eq!(3, cycle.prefix);
+        assert_eq!(5, cycle.length);
+        assert_eq!((7, 7), *states.last().unwrap());
+    }
+
+    #[test]
+    fn linear_growth_extrapolates() {
+        // Settles into growing by 3 per step after some noise.
+        let scores = [5, 1, 4, 4, 7, 10, 13, 16, 19];
+        let growth = linear_growth(0, |&i| i + 1, |&i| scores[i.min(scores.len() - 1)], 3);
+
+        assert_eq!(
+            LinearGrowth {
+                step: 3,
+                value: 4,
+                delta: 3
+            },
+            growth
+        );
+        assert_eq!(16, growth.value_at(7));
+        assert_eq!(
+            4 + 3 * (50_000_000_000 - 3),
+            growth.value_at(50_000_000_000)
+        );
+    }
+}
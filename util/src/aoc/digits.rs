@@ -1,30 +1,160 @@
-pub struct Digits {
-    n: u32,
-    divisor: u32,
+use num_traits::PrimInt;
+use std::marker::PhantomData;
+
+/// Iterates the digits of an integer, most significant first, or least significant first through
+/// `rev()`. Negative numbers yield the digits of their magnitude, and zero has the single digit 0.
+#[derive(Clone, Debug)]
+pub struct Digits<T> {
+    n: u128,
+    radix: u32,
+    low: u32,
+    len: u32,
+    marker: PhantomData<T>,
 }
 
-impl Digits {
-    pub fn new(n: u32) -> Self {
-        let mut divisor = 1;
-        while n >= divisor * 10 {
-            divisor *= 10;
+impl<T: PrimInt> Digits<T> {
+    pub fn new(n: T) -> Self {
+        Digits::with_radix(n, 10)
+    }
+
+    pub fn with_radix(n: T, radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "Unsupported radix {}", radix);
+
+        let n = magnitude(n);
+        Digits {
+            n,
+            radix,
+            low: 0,
+            len: digit_count_of(n, radix),
+            marker: PhantomData,
         }
+    }
 
-        Digits { n, divisor }
+    fn digit(&self, position: u32) -> T {
+        let digit = self.n / (self.radix as u128).pow(position) % self.radix as u128;
+        T::from(digit).unwrap()
     }
 }
 
-impl Iterator for Digits {
-    type Item = u32;
+impl<T: PrimInt> Iterator for Digits<T> {
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.divisor == 0 {
-            None
-        } else {
-            let v = Some(self.n / self.divisor);
-            self.n %= self.divisor;
-            self.divisor /= 10;
-            v
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.digit(self.low + self.len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len as usize, Some(self.len as usize))
+    }
+}
+
+impl<T: PrimInt> DoubleEndedIterator for Digits<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
         }
+        self.len -= 1;
+        self.low += 1;
+        Some(self.digit(self.low - 1))
+    }
+}
+
+impl<T: PrimInt> ExactSizeIterator for Digits<T> {}
+
+/// The number of digits in `n` written in the given radix.
+pub fn digit_count<T: PrimInt>(n: T, radix: u32) -> u32 {
+    Digits::with_radix(n, radix).len() as u32
+}
+
+/// The sum of the digits of `n` written in the given radix.
+pub fn digit_sum<T: PrimInt>(n: T, radix: u32) -> T {
+    Digits::with_radix(n, radix).fold(T::zero(), |sum, digit| sum + digit)
+}
+
+/// Builds a number from its digits, most significant first. The inverse of `Digits`.
+pub fn from_digits<T: PrimInt>(digits: impl IntoIterator<Item = T>, radix: u32) -> T {
+    let base = T::from(radix).unwrap();
+    digits.into_iter().fold(T::zero(), |n, digit| {
+        assert!(digit >= T::zero() && digit < base, "Digit out of range");
+        n * base + digit
+    })
+}
+
+fn magnitude<T: PrimInt>(n: T) -> u128 {
+    match n.to_u128() {
+        Some(n) => n,
+        None => n.to_i128().unwrap().unsigned_abs(),
+    }
+}
+
+fn digit_count_of(n: u128, radix: u32) -> u32 {
+    if n == 0 {
+        1
+    } else {
+        n.ilog(radix as u128) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal() {
+        assert_eq!(vec![1, 2, 3, 4], Digits::new(1234u32).collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 3, 2, 1],
+            Digits::new(1234u32).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0], Digits::new(0u8).collect::<Vec<_>>());
+        assert_eq!(vec![1, 0, 0, 2], Digits::new(-1002i64).collect::<Vec<_>>());
+        assert_eq!(
+            vec![1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5],
+            Digits::new(u64::MAX).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn radixes() {
+        assert_eq!(
+            vec![1, 0, 1, 1, 0],
+            Digits::with_radix(22, 2).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![2, 6], Digits::with_radix(22, 8).collect::<Vec<_>>());
+        assert_eq!(
+            vec![15, 15],
+            Digits::with_radix(255, 16).collect::<Vec<_>>()
+        );
+        assert_eq!(8, digit_count(i8::MIN, 2));
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut digits = Digits::new(12345);
+
+        assert_eq!(5, digits.len());
+        assert_eq!(Some(1), digits.next());
+        assert_eq!(Some(5), digits.next_back());
+        assert_eq!(Some(2), digits.next());
+        assert_eq!(Some(4), digits.next_back());
+        assert_eq!(1, digits.len());
+        assert_eq!(Some(3), digits.next_back());
+        assert_eq!(None, digits.next());
+    }
+
+    #[test]
+    fn count_sum_and_inverse() {
+        assert_eq!(6, digit_count(123456, 10));
+        assert_eq!(21, digit_sum(123456, 10));
+        assert_eq!(3, digit_sum(0b1011u16, 2));
+
+        // 2021 day 3: the gamma rate of the sample diagnostic report.
+        assert_eq!(22, from_digits(vec![1, 0, 1, 1, 0], 2));
+        assert_eq!(0xbeef, from_digits(Digits::with_radix(0xbeef, 16), 16));
+        assert_eq!(0, from_digits(Vec::<i32>::new(), 10));
     }
 }
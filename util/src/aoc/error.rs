@@ -0,0 +1,186 @@
+use crate::aoc::fetch::FetchError;
+use crate::aoc::input::InputError;
+use crate::aoc::parsing::{self, Diagnostic};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use thiserror::Error;
+
+/// Everything that can go wrong reading, parsing or solving a puzzle.
+#[derive(Debug, Error)]
+pub enum AocError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+
+    /// Part of the input couldn't be parsed. `line` counts from 1.
+    #[error("{}", describe_parse(.message, .line, .snippet))]
+    Parse {
+        message: String,
+        line: Option<usize>,
+        snippet: Option<String>,
+    },
+
+    /// Lines of the input that couldn't be parsed, as found by the `parsing` helpers.
+    #[error("{}", parsing::render(.0))]
+    Diagnostics(Vec<Diagnostic>),
+
+    /// The input parsed, but there's no answer for it.
+    #[error("No solution: {0}")]
+    NoSolution(String),
+
+    /// The input is malformed or breaks one of the puzzle's rules.
+    #[error("{0}")]
+    InvalidInput(String),
+}
+
+impl AocError {
+    pub fn parse(message: impl Display) -> AocError {
+        AocError::Parse {
+            message: message.to_string(),
+            line: None,
+            snippet: None,
+        }
+    }
+
+    pub fn no_solution(message: impl Display) -> AocError {
+        AocError::NoSolution(message.to_string())
+    }
+
+    pub fn invalid_input(message: impl Display) -> AocError {
+        AocError::InvalidInput(message.to_string())
+    }
+
+    /// Places the error on a line of the input, turning it into a parse error if it isn't one.
+    pub fn at_line(self, line: usize, snippet: &str) -> AocError {
+        let message = match self {
+            AocError::Parse { message, .. } => message,
+            other => other.to_string(),
+        };
+        AocError::Parse {
+            message,
+            line: Some(line),
+            snippet: Some(snippet.to_string()),
+        }
+    }
+}
+
+fn describe_parse(message: &str, line: &Option<usize>, snippet: &Option<String>) -> String {
+    match (line, snippet) {
+        (Some(line), Some(snippet)) => format!("Line {}: {}: {:?}", line, message, snippet),
+        (Some(line), None) => format!("Line {}: {}", line, message),
+        (None, Some(snippet)) => format!("{}: {:?}", message, snippet),
+        (None, None) => message.to_string(),
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        AocError::parse(e)
+    }
+}
+
+impl From<ParseFloatError> for AocError {
+    fn from(e: ParseFloatError) -> Self {
+        AocError::parse(e)
+    }
+}
+
+impl From<Infallible> for AocError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<regex::Error> for AocError {
+    fn from(e: regex::Error) -> Self {
+        AocError::parse(e)
+    }
+}
+
+impl From<nom::Err<nom::error::Error<&str>>> for AocError {
+    fn from(e: nom::Err<nom::error::Error<&str>>) -> Self {
+        match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => AocError::Parse {
+                message: format!("Expected {:?}", e.code),
+                line: None,
+                snippet: Some(e.input.lines().next().unwrap_or_default().to_string()),
+            },
+            nom::Err::Incomplete(_) => AocError::parse("Unexpected end of input"),
+        }
+    }
+}
+
+/// Builds an `AocError::InvalidInput` from a format string, as `format!` would.
+#[macro_export]
+macro_rules! format_err {
+    ($($arg:tt)*) => {
+        $crate::aoc::AocError::InvalidInput(format!($($arg)*))
+    };
+}
+
+/// Returns early with an `AocError::InvalidInput` built from a format string.
+#[macro_export]
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return Err($crate::format_err!($($arg)*).into())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::AocResult;
+
+    fn number(text: &str) -> AocResult<i32> {
+        Ok(text.parse()?)
+    }
+
+    fn checked(value: i32) -> AocResult<i32> {
+        if value < 0 {
+            crate::bail!("{} is negative", value);
+        }
+        Ok(value)
+    }
+
+    #[test]
+    fn converts_parse_errors() {
+        let error = number("12a").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: None, .. }));
+        assert_eq!(error.to_string(), "invalid digit found in string");
+
+        let error = error.at_line(3, "12a");
+        assert_eq!(
+            error.to_string(),
+            "Line 3: invalid digit found in string: \"12a\""
+        );
+    }
+
+    #[test]
+    fn converts_nom_errors() {
+        let result: nom::IResult<&str, &str> = nom::bytes::complete::tag("x")("abc\ndef");
+        let error = AocError::from(result.unwrap_err());
+        assert_eq!(error.to_string(), "Expected Tag: \"abc\"");
+    }
+
+    #[test]
+    fn bails_with_invalid_input() {
+        assert_eq!(checked(1).unwrap(), 1);
+        let error = checked(-1).unwrap_err();
+        assert!(matches!(error, AocError::InvalidInput(_)));
+        assert_eq!(error.to_string(), "-1 is negative");
+    }
+
+    #[test]
+    fn located_errors_become_parse_errors() {
+        let error = AocError::invalid_input("Unknown key: x").at_line(2, "x:1");
+        assert!(matches!(error, AocError::Parse { line: Some(2), .. }));
+        assert_eq!(error.to_string(), "Line 2: Unknown key: x: \"x:1\"");
+    }
+}
@@ -0,0 +1,307 @@
+//! Generates a test per example input and part from files rather than string literals.
+//!
+//! Each example is a pair of files in `puzzle_examples/dayN/` of a year's crate: the input as
+//! `NAME.txt` and the answers it should produce as `NAME.toml`, with a key per part it applies to:
+//!
+//! ```toml
+//! part1 = "24000"
+//! part2 = "45000"
+//! ```
+//!
+//! The crate's `build.rs` calls `build_script`, which generates a `#[cfg(test)]` module of tests
+//! calling the crate's `solutions()`, and the crate's `lib.rs` includes it:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+//! ```
+
+use crate::aoc::answers::DayAnswers;
+use crate::aoc::solution::Day;
+use crate::aoc::AocResult;
+use crate::{bail, format_err};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// The directory in each year's crate holding its examples. Not `examples/`, which Cargo
+/// would try to build as example binaries.
+pub const EXAMPLES_DIR: &str = "puzzle_examples";
+
+/// The file written to `OUT_DIR` by `build_script`.
+pub const GENERATED_FILE: &str = "examples.rs";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub day: u32,
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: DayAnswers,
+}
+
+impl Example {
+    /// The examples in `dir`, ordered by day then name.
+    pub fn find_all(dir: &Path) -> AocResult<Vec<Example>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut examples = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let day_dir = entry?.path();
+            let day = day_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(|| {
+                    format_err!("Expected a directory named dayN, got {}", day_dir.display())
+                })?;
+
+            for entry in std::fs::read_dir(&day_dir)? {
+                let input = entry?.path();
+                if input
+                    .extension()
+                    .is_some_and(|extension| extension == "txt")
+                {
+                    examples.push(Example::load(day, input)?);
+                }
+            }
+        }
+
+        examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+        Ok(examples)
+    }
+
+    fn load(day: u32, input: PathBuf) -> AocResult<Example> {
+        let name = input
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| format_err!("Bad example name: {}", input.display()))?
+            .to_string();
+
+        let answers_path = input.with_extension("toml");
+        if !answers_path.exists() {
+            bail!(
+                "{} has no answers; add them to {}",
+                input.display(),
+                answers_path.display()
+            );
+        }
+        let answers = toml::from_str(&std::fs::read_to_string(&answers_path)?)
+            .map_err(|e| format_err!("{}: {}", answers_path.display(), e))?;
+
+        Ok(Example {
+            day,
+            name,
+            input,
+            answers,
+        })
+    }
+
+    /// The parts this example has answers for, with their answers.
+    pub fn parts(&self) -> impl Iterator<Item = (u32, &str)> {
+        [(1, &self.answers.part1), (2, &self.answers.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| answer.as_deref().map(|answer| (part, answer)))
+    }
+}
+
+/// A `puzzle_examples` test module with a test function for each part of each example, calling
+/// `check` with the crate's `solutions()`.
+pub fn generate_tests(examples: &[Example]) -> String {
+    let mut tests = String::from("#[cfg(test)]\nmod puzzle_examples {\n");
+    for example in examples {
+        for (part, answer) in example.parts() {
+            writeln!(
+                tests,
+                "    #[test]\n    \
+                 fn day{}_{}_part{}() {{\n        \
+                     util::aoc::examples::check(\n            \
+                         &crate::solutions(),\n            \
+                         {},\n            \
+                         {},\n            \
+                         include_str!({:?}),\n            \
+                         {:?},\n        \
+                     );\n    \
+                 }}\n",
+                example.day,
+                test_name(&example.name),
+                part,
+                example.day,
+                part,
+                example.input.display().to_string(),
+                answer
+            )
+            .unwrap();
+        }
+    }
+    tests.push_str("}\n");
+    tests
+}
+
+/// Turns an example's file name into part of an identifier.
+fn test_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' => c,
+            'A'..='Z' => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Two examples of the same day whose names make the same test name, like `Example 1` and
+/// `example_1`, which would generate clashing test functions.
+pub fn find_clash(examples: &[Example]) -> Option<(&Example, &Example)> {
+    let mut seen = HashMap::new();
+    examples.iter().find_map(|example| {
+        seen.insert((example.day, test_name(&example.name)), example)
+            .map(|other| (other, example))
+    })
+}
+
+/// Generates the crate's example tests into `OUT_DIR`. Meant to be called from `build.rs`.
+pub fn build_script() {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let examples_dir = crate_dir.join(EXAMPLES_DIR);
+
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+    let examples = Example::find_all(&examples_dir).unwrap_or_else(|e| panic!("{}", e));
+    if let Some((a, b)) = find_clash(&examples) {
+        panic!(
+            "{} and {} would generate the same tests; rename one of them",
+            a.input.display(),
+            b.input.display()
+        );
+    }
+
+    // Only touch the file when it changes so the tests aren't rebuilt needlessly.
+    let out = out_dir.join(GENERATED_FILE);
+    let tests = generate_tests(&examples);
+    if std::fs::read_to_string(&out).ok().as_deref() != Some(tests.as_str()) {
+        std::fs::write(&out, tests).unwrap();
+    }
+}
+
+/// Solves one part of an example and checks its answer, panicking if it differs.
+pub fn check(solutions: &[Box<dyn Day>], day: u32, part: u32, input: &str, expected: &str) {
+    let solution = solutions
+        .iter()
+        .find(|solution| solution.day() == day)
+        .unwrap_or_else(|| panic!("There's no solution for day {}", day));
+
+    match solution.solve(input, part) {
+        Ok(answer) => assert_eq!(
+            answer.trim(),
+            expected.trim(),
+            "Wrong answer for day {} part {}",
+            day,
+            part
+        ),
+        Err(e) => panic!("Day {} part {} failed: {}", day, part, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory under the system's temp directory, removed again when dropped.
+    struct ScratchDir(PathBuf);
+
+    impl std::ops::Deref for ScratchDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn scratch_dir(name: &str) -> ScratchDir {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-examples-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ScratchDir(dir)
+    }
+
+    fn write(path: PathBuf, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn finds_examples_with_answers() {
+        let dir = scratch_dir("find");
+        write(dir.join("day10/larger.txt"), "2\n3");
+        write(dir.join("day10/larger.toml"), "part2 = \"6\"");
+        write(dir.join("day2/Example 1.txt"), "1\n2");
+        write(
+            dir.join("day2/Example 1.toml"),
+            "part1 = \"3\"\npart2 = \"2\"",
+        );
+
+        let examples = Example::find_all(&dir).unwrap();
+        assert_eq!(
+            examples
+                .iter()
+                .map(|example| (example.day, example.name.as_str()))
+                .collect::<Vec<_>>(),
+            [(2, "Example 1"), (10, "larger")]
+        );
+        assert_eq!(
+            examples[0].parts().collect::<Vec<_>>(),
+            [(1, "3"), (2, "2")]
+        );
+        assert_eq!(examples[1].parts().collect::<Vec<_>>(), [(2, "6")]);
+
+        let tests = generate_tests(&examples);
+        assert!(tests.contains("fn day2_example_1_part1()"));
+        assert!(tests.contains("fn day10_larger_part2()"));
+        assert!(!tests.contains("fn day10_larger_part1()"));
+        assert!(tests.starts_with("#[cfg(test)]\nmod puzzle_examples {"));
+        assert!(tests.contains("&crate::solutions(),\n            10,\n            2,\n"));
+    }
+
+    #[test]
+    fn finds_names_making_the_same_test() {
+        let dir = scratch_dir("clash");
+        for name in ["day3/Example 1", "day3/example_1", "day4/example_1"] {
+            write(dir.join(format!("{}.txt", name)), "1");
+            write(dir.join(format!("{}.toml", name)), "part1 = \"1\"");
+        }
+
+        let examples = Example::find_all(&dir).unwrap();
+        let (a, b) = find_clash(&examples).unwrap();
+        assert_eq!(
+            [a.input.clone(), b.input.clone()],
+            [
+                dir.join("day3/Example 1.txt"),
+                dir.join("day3/example_1.txt")
+            ]
+        );
+        assert_eq!(find_clash(&examples[1..]), None);
+    }
+
+    #[test]
+    fn examples_need_answers() {
+        let dir = scratch_dir("unanswered");
+        write(dir.join("day1/example.txt"), "1");
+
+        let error = Example::find_all(&dir).unwrap_err().to_string();
+        assert!(error.contains("has no answers"), "{}", error);
+    }
+
+    #[test]
+    fn missing_examples_directory_is_empty() {
+        assert!(Example::find_all(&scratch_dir("missing"))
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -6,7 +6,7 @@ pub trait Grid<T: Debug> {
     fn lookup(&self, point: &Point) -> Option<&T>;
     fn update(&mut self, point: &Point, state: T);
     fn update_fn(&mut self, point: &Point, state_fn: fn(&T) -> T) -> &T;
-    fn point_iterator(&self) -> PointIterator<T>;
+    fn point_iterator(&self) -> PointIterator<'_, T>;
     fn print_grid(&self);
     fn x_max(&self) -> usize;
     fn y_max(&self) -> usize;
@@ -45,7 +45,7 @@ impl<T: Debug, G: Grid<T> + Debug> Grid<T> for TranslationGrid<T, G> {
         self.grid.update_fn(point, state_fn)
     }
 
-    fn point_iterator(&self) -> PointIterator<T> {
+    fn point_iterator(&self) -> PointIterator<'_, T> {
         // self.grid
         //     .point_iterator()
         //     .map(|(point, v)| (point - self.translate, v))
@@ -74,6 +74,12 @@ pub struct ExpandingArrayGrid<T> {
     states: Vec<Vec<T>>,
 }
 
+impl<T: Debug + Clone + Default> Default for ExpandingArrayGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Debug + Clone + Default> ExpandingArrayGrid<T> {
     pub fn new() -> Self {
         ExpandingArrayGrid { states: vec![] }
@@ -112,7 +118,7 @@ impl<T: Debug + Clone + Default> Grid<T> for ExpandingArrayGrid<T> {
         &self.states[point.y as usize][point.x as usize]
     }
 
-    fn point_iterator(&self) -> PointIterator<T> {
+    fn point_iterator(&self) -> PointIterator<'_, T> {
         PointIterator {
             grid: self,
             x: 0,
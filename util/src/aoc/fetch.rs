@@ -0,0 +1,505 @@
+use crate::aoc::input::{InputKind, InputLoader};
+use crate::aoc::AocResult;
+use std::env;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// The environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable naming a file holding the session cookie.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// The environment variable overriding the user agent sent with each request.
+pub const USER_AGENT_VAR: &str = "AOC_USER_AGENT";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error(
+        "No session token: set {} or {}, or write it to ~/.config/aoc/session",
+        SESSION_VAR,
+        SESSION_FILE_VAR
+    )]
+    MissingSession,
+
+    #[error("GET {url} returned {status}")]
+    Http { url: String, status: u16 },
+
+    #[error("GET {url} failed: {message}")]
+    Network { url: String, message: String },
+
+    #[error("Couldn't cache {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Performs a GET request, returning the response body.
+pub trait HttpBackend {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, FetchError>;
+}
+
+/// A bare-bones HTTP/1.1 client over a `TcpStream`. It only speaks plain `http://`, which is
+/// enough for a local stand-in server.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PlainHttp;
+
+impl HttpBackend for PlainHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, FetchError> {
+        let network = |message: String| FetchError::Network {
+            url: url.to_string(),
+            message,
+        };
+
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| network("only http:// is supported".to_string()))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+
+        let mut request = format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+            path, host
+        );
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        request.push_str("\r\n");
+
+        let mut stream = TcpStream::connect(host).map_err(|e| network(e.to_string()))?;
+        stream
+            .write_all(request.as_bytes())
+            .map_err(|e| network(e.to_string()))?;
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .map_err(|e| network(e.to_string()))?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| network("malformed response".to_string()))?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or_else(|| network("malformed status line".to_string()))?;
+
+        if status == 200 {
+            Ok(body.to_string())
+        } else {
+            Err(FetchError::Http {
+                url: url.to_string(),
+                status,
+            })
+        }
+    }
+}
+
+/// Shells out to `curl`, which handles TLS without pulling it into every year's build. Headers
+/// are passed as a config file on stdin rather than as arguments, so the session cookie doesn't
+/// show up in the process list.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Curl;
+
+impl HttpBackend for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, FetchError> {
+        let network = |e: io::Error| FetchError::Network {
+            url: url.to_string(),
+            message: e.to_string(),
+        };
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location"])
+            .args(["--config", "-"])
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(network)?;
+
+        let config = curl_config(headers);
+        // Dropping stdin once it's written closes it, letting curl read to the end.
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(config.as_bytes())
+            .map_err(network)?;
+        let output = child.wait_with_output().map_err(network)?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            Err(FetchError::Network {
+                url: url.to_string(),
+                message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            })
+        }
+    }
+}
+
+/// A curl config file setting each header, quoted as curl expects.
+fn curl_config(headers: &[(&str, &str)]) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let header = format!("{}: {}", name, value)
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            format!("header = \"{}\"\n", header)
+        })
+        .collect()
+}
+
+/// Uses `PlainHttp` for `http://` URLs and `Curl` for everything else.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemHttp;
+
+impl HttpBackend for SystemHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, FetchError> {
+        if url.starts_with("http://") {
+            PlainHttp.get(url, headers)
+        } else {
+            Curl.get(url, headers)
+        }
+    }
+}
+
+/// Looks for a session token in `AOC_SESSION`, then in the file named by `AOC_SESSION_FILE`, then
+/// in `~/.config/aoc/session`.
+pub fn session_from_env() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Some(session.trim().to_string());
+    }
+
+    let file = env::var_os(SESSION_FILE_VAR)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config/aoc/session")))?;
+    std::fs::read_to_string(file)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+/// Downloads puzzle inputs, caching each under `{cache_root}/input/{year}/day{N}.txt` so that
+/// `InputLoader` finds it afterwards and no input is downloaded twice. Requests are spaced at
+/// least `min_interval` apart, across every fetcher and process sharing a cache, by recording
+/// when the last one was made in `{cache_root}/input/.last_request` while holding a lock on it.
+pub struct InputFetcher {
+    base_url: String,
+    session: Option<String>,
+    user_agent: String,
+    cache_root: PathBuf,
+    min_interval: Duration,
+    backend: Box<dyn HttpBackend + Send + Sync>,
+}
+
+impl InputFetcher {
+    /// A fetcher for adventofcode.com, configured from the environment.
+    pub fn new(cache_root: impl Into<PathBuf>) -> InputFetcher {
+        InputFetcher {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session_from_env(),
+            user_agent: env::var(USER_AGENT_VAR)
+                .unwrap_or_else(|_| concat!("aoc-util/", env!("CARGO_PKG_VERSION")).to_string()),
+            cache_root: cache_root.into(),
+            min_interval: Duration::from_secs(5),
+            backend: Box::new(SystemHttp),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn with_backend(mut self, backend: impl HttpBackend + Send + Sync + 'static) -> Self {
+        self.backend = Box::new(backend);
+        self
+    }
+
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_root
+            .join(format!("input/{}/day{}.txt", year, day))
+    }
+
+    fn last_request_path(&self) -> PathBuf {
+        self.cache_root.join("input/.last_request")
+    }
+
+    /// The cached input, downloading it first if it isn't cached yet.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        match std::fs::read_to_string(self.cache_path(year, day)) {
+            Ok(cached) => Ok(cached),
+            Err(_) => self.download(year, day),
+        }
+    }
+
+    /// Downloads the input again, falling back to the cached copy if the download fails.
+    pub fn refresh(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        self.download(year, day)
+            .or_else(|error| std::fs::read_to_string(self.cache_path(year, day)).map_err(|_| error))
+    }
+
+    fn download(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let cookie = format!("session={}", session);
+
+        self.wait_for_rate_limit()?;
+        let input = self.backend.get(
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", &self.user_agent)],
+        )?;
+
+        let path = self.cache_path(year, day);
+        std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| std::fs::write(&path, &input))
+            .map_err(|source| FetchError::Io { path, source })?;
+
+        Ok(input)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request recorded under the cache
+    /// root, then records this one.
+    fn wait_for_rate_limit(&self) -> Result<(), FetchError> {
+        let path = self.last_request_path();
+        let io_error = |source| FetchError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        std::fs::create_dir_all(path.parent().unwrap()).map_err(io_error)?;
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(io_error)?;
+        // Held until the file is closed, so fetchers in this process and others take turns.
+        file.lock().map_err(io_error)?;
+
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).map_err(io_error)?;
+        let last = contents
+            .trim()
+            .parse::<u64>()
+            .ok()
+            // The time was truncated to the millisecond when written; round it up so the wait is
+            // never short.
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis + 1));
+        if let Some(elapsed) = last.map(|last| SystemTime::now().duration_since(last)) {
+            // A last request in the future means the clock moved back; wait out the interval.
+            let elapsed = elapsed.unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        file.set_len(0)
+            .and_then(|_| file.seek(SeekFrom::Start(0)))
+            .and_then(|_| file.write_all(now.to_string().as_bytes()))
+            .map_err(io_error)
+    }
+}
+
+/// Reads a day's input from disk, downloading it into the top of the repository if none of the
+/// usual places have it. Without a session token to download with, the error lists where the
+/// input was looked for.
+pub fn load_or_fetch(year: u32, day: u32) -> AocResult<String> {
+    let loader = InputLoader::new();
+    match loader.read(year, day, InputKind::Puzzle) {
+        Ok(input) => Ok(input),
+        Err(not_found) => {
+            let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+            match InputFetcher::new(workspace).fetch(year, day) {
+                Ok(input) => Ok(input),
+                Err(FetchError::MissingSession) => Err(not_found.into()),
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    /// Serves `responses` in order, one per connection, sending each request back over the
+    /// channel.
+    fn stand_in_server(responses: Vec<&'static str>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 4096];
+                let n = stream.read(&mut request).unwrap();
+                sender
+                    .send(String::from_utf8_lossy(&request[..n]).into_owned())
+                    .unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (address, receiver)
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_caches() {
+        let (address, requests) = stand_in_server(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n1\n2\n3\n",
+        ]);
+        let cache = scratch_dir("cache");
+        let fetcher = InputFetcher::new(&cache)
+            .with_base_url(address)
+            .with_session("abc123")
+            .with_user_agent("tests");
+
+        assert_eq!("1\n2\n3\n", fetcher.fetch(2021, 1).unwrap());
+        assert_eq!("1\n2\n3\n", fetcher.fetch(2021, 1).unwrap());
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains("User-Agent: tests\r\n"));
+        assert!(requests.try_recv().is_err());
+
+        let loader = InputLoader::with_roots([&cache]);
+        assert_eq!(
+            "1\n2\n3\n",
+            loader.read(2021, 1, InputKind::Puzzle).unwrap()
+        );
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_cache_when_offline() {
+        let cache = scratch_dir("offline");
+        // Nothing listens on the port once the listener is dropped.
+        let address = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let fetcher = InputFetcher::new(&cache)
+            .with_base_url(address)
+            .with_session("abc123")
+            .with_min_interval(Duration::ZERO);
+
+        assert!(matches!(
+            fetcher.refresh(2020, 5),
+            Err(FetchError::Network { .. })
+        ));
+
+        std::fs::create_dir_all(cache.join("input/2020")).unwrap();
+        std::fs::write(fetcher.cache_path(2020, 5), "cached").unwrap();
+        assert_eq!("cached", fetcher.refresh(2020, 5).unwrap());
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn errors_and_rate_limit() {
+        let (address, _requests) = stand_in_server(vec![
+            "HTTP/1.1 404 Not Found\r\n\r\n",
+            "HTTP/1.1 200 OK\r\n\r\nok",
+        ]);
+        let cache = scratch_dir("errors");
+        let mut fetcher = InputFetcher::new(&cache)
+            .with_base_url(address)
+            .with_min_interval(Duration::from_millis(200));
+        fetcher.session = None;
+
+        assert!(matches!(
+            fetcher.fetch(2019, 2),
+            Err(FetchError::MissingSession)
+        ));
+        let fetcher = fetcher.with_session("abc123");
+
+        let start = Instant::now();
+        assert!(matches!(
+            fetcher.fetch(2019, 2),
+            Err(FetchError::Http { status: 404, .. })
+        ));
+        assert_eq!("ok", fetcher.fetch(2019, 2).unwrap());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn rate_limit_is_shared_between_fetchers() {
+        let (address, _requests) = stand_in_server(vec![
+            "HTTP/1.1 200 OK\r\n\r\none",
+            "HTTP/1.1 200 OK\r\n\r\ntwo",
+        ]);
+        let cache = scratch_dir("shared");
+        let fetcher = || {
+            InputFetcher::new(&cache)
+                .with_base_url(&address)
+                .with_session("abc123")
+                .with_min_interval(Duration::from_millis(200))
+        };
+
+        let start = Instant::now();
+        assert_eq!("one", fetcher().fetch(2018, 1).unwrap());
+        assert_eq!("two", fetcher().fetch(2018, 2).unwrap());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn quotes_curl_headers() {
+        assert_eq!(
+            curl_config(&[("Cookie", "session=abc"), ("User-Agent", r#"a "b" \c"#)]),
+            "header = \"Cookie: session=abc\"\n\
+             header = \"User-Agent: a \\\"b\\\" \\\\c\"\n"
+        );
+    }
+}
@@ -1,13 +1,21 @@
+use crate::aoc::top_k::TopK;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::iter::FromIterator;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FrequencyMap<T: Eq + Hash> {
     counts: HashMap<T, u64>,
 }
 
+impl<T: Eq + Hash> Default for FrequencyMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Eq + Hash> FrequencyMap<T> {
     pub fn new() -> Self {
         FrequencyMap {
@@ -30,6 +38,36 @@ impl<T: Eq + Hash> FrequencyMap<T> {
         }
     }
 
+    /// Lowers a key's count by `value`, removing the key once its count reaches zero.
+    pub fn multi_remove(&mut self, key: &T, value: u64) {
+        if let Some(count) = self.counts.get_mut(key) {
+            if *count > value {
+                *count -= value;
+            } else {
+                self.counts.remove(key);
+            }
+        }
+    }
+
+    /// Removes a key entirely, returning its count.
+    pub fn remove(&mut self, key: &T) -> u64 {
+        self.counts.remove(key).unwrap_or(0)
+    }
+
+    /// The number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The sum of all counts.
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
     pub fn count(&self, value: &T) -> u64 {
         self.counts.get(value).cloned().unwrap_or(0)
     }
@@ -55,6 +93,83 @@ impl<T: Eq + Hash> FrequencyMap<T> {
             .max_by_key(|entry| entry.1)
             .map(|(k, _v)| k)
     }
+
+    pub fn min_count(&self) -> Option<u64> {
+        self.counts.values().min().copied()
+    }
+
+    pub fn max_count(&self) -> Option<u64> {
+        self.counts.values().max().copied()
+    }
+
+    /// Every key tied for the lowest count, in no particular order.
+    pub fn min_keys(&self) -> Vec<&T> {
+        self.keys_with_count(self.min_count())
+    }
+
+    /// Every key tied for the highest count, in no particular order.
+    pub fn max_keys(&self) -> Vec<&T> {
+        self.keys_with_count(self.max_count())
+    }
+
+    fn keys_with_count(&self, count: Option<u64>) -> Vec<&T> {
+        self.counts
+            .iter()
+            .filter(|(_, &c)| Some(c) == count)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// Adds every count from `other` to this map.
+    pub fn merge(&mut self, other: &FrequencyMap<T>)
+    where
+        T: Clone,
+    {
+        for (key, &count) in &other.counts {
+            self.multi_add(key.clone(), count);
+        }
+    }
+
+    /// Lowers counts by those in `other`, removing keys that reach zero.
+    pub fn subtract(&mut self, other: &FrequencyMap<T>) {
+        for (key, &count) in &other.counts {
+            self.multi_remove(key, count);
+        }
+    }
+
+    /// Multiplies every count by `factor`.
+    pub fn scale(&mut self, factor: u64) {
+        if factor == 0 {
+            self.counts.clear();
+        } else {
+            self.counts.values_mut().for_each(|count| *count *= factor);
+        }
+    }
+}
+
+impl<T: Eq + Hash + Ord> FrequencyMap<T> {
+    /// The `k` highest counts, highest first, with ties broken by ascending key.
+    pub fn most_common(&self, k: usize) -> Vec<(&T, u64)> {
+        self.counts
+            .iter()
+            .map(|(key, &count)| (count, Reverse(key)))
+            .topk(k)
+            .into_iter()
+            .map(|(count, Reverse(key))| (key, count))
+            .collect()
+    }
+
+    /// Every entry, ordered by ascending key.
+    pub fn sorted_by_key(&self) -> Vec<(&T, u64)> {
+        let mut entries = self.counts.iter().map(|(k, &c)| (k, c)).collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+
+    /// Every entry, ordered by descending count and then ascending key.
+    pub fn sorted_by_count(&self) -> Vec<(&T, u64)> {
+        self.most_common(self.counts.len())
+    }
 }
 
 impl<T: Eq + Hash> FromIterator<T> for FrequencyMap<T> {
@@ -72,3 +187,73 @@ impl<T: Eq + Hash> Extend<T> for FrequencyMap<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting() {
+        let mut frequency: FrequencyMap<char> = "mississippi".chars().collect();
+
+        assert_eq!(4, frequency.len());
+        assert_eq!(11, frequency.total());
+        assert_eq!(4, frequency.count(&'s'));
+
+        frequency.multi_remove(&'s', 3);
+        assert_eq!(1, frequency.count(&'s'));
+        frequency.multi_remove(&'s', 3);
+        assert_eq!(0, frequency.count(&'s'));
+        assert_eq!(3, frequency.len());
+
+        assert_eq!(2, frequency.remove(&'p'));
+        assert_eq!(0, frequency.remove(&'p'));
+        assert_eq!(vec![(&'i', 4), (&'m', 1)], frequency.sorted_by_key());
+    }
+
+    #[test]
+    fn ties() {
+        let frequency: FrequencyMap<char> = "mississippi".chars().collect();
+
+        let mut max_keys = frequency.max_keys();
+        max_keys.sort();
+        assert_eq!(vec![&'i', &'s'], max_keys);
+        assert_eq!(vec![&'m'], frequency.min_keys());
+        assert_eq!(Some(4), frequency.max_count());
+        assert_eq!(Some(1), frequency.min_count());
+        assert!(FrequencyMap::<char>::new().max_keys().is_empty());
+    }
+
+    #[test]
+    fn most_common() {
+        let frequency: FrequencyMap<char> = "mississippi".chars().collect();
+
+        assert_eq!(vec![(&'i', 4), (&'s', 4)], frequency.most_common(2));
+        assert_eq!(
+            vec![(&'i', 4), (&'s', 4), (&'p', 2), (&'m', 1)],
+            frequency.sorted_by_count()
+        );
+        assert!(frequency.most_common(0).is_empty());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut a: FrequencyMap<&str> = vec!["NN", "NC", "CB"].into_iter().collect();
+        let b: FrequencyMap<&str> = vec!["NC", "NC", "BB"].into_iter().collect();
+
+        a.merge(&b);
+        assert_eq!(3, a.count(&"NC"));
+        assert_eq!(6, a.total());
+
+        a.scale(10);
+        assert_eq!(30, a.count(&"NC"));
+        assert_eq!(60, a.total());
+
+        a.subtract(&b);
+        assert_eq!(28, a.count(&"NC"));
+        assert_eq!(9, a.count(&"BB"));
+
+        a.scale(0);
+        assert!(a.is_empty());
+    }
+}
@@ -33,7 +33,7 @@ impl<T: Debug> Grid<T> {
         &self.states[point.y as usize][point.x as usize]
     }
 
-    pub fn point_iterator(&self) -> PointIterator<T> {
+    pub fn point_iterator(&self) -> PointIterator<'_, T> {
         PointIterator {
             grid: self,
             x: 0,
@@ -41,7 +41,7 @@ impl<T: Debug> Grid<T> {
         }
     }
 
-    pub fn perimeter_iterator(&self) -> PerimeterIterator<T> {
+    pub fn perimeter_iterator(&self) -> PerimeterIterator<'_, T> {
         PerimeterIterator {
             grid: self,
             x: 0,
@@ -163,6 +163,12 @@ pub struct HashGrid<T> {
     pub max_y: Option<i32>,
 }
 
+impl<T: Debug + Display> Default for HashGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Debug + Display> HashGrid<T> {
     pub fn new() -> Self {
         HashGrid {
@@ -239,9 +245,8 @@ impl<T: Debug + Display> HashGrid<T> {
             for y in min_y..=max_y {
                 for x in min_x..=max_x {
                     let point = Point::new(x, y);
-                    let value = self.grid.get(&point);
-                    if value.is_some() {
-                        print!("{}", value.unwrap())
+                    if let Some(value) = self.grid.get(&point) {
+                        print!("{}", value)
                     } else {
                         print!(".")
                     }
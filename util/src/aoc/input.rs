@@ -1,8 +1,10 @@
+use std::env;
 use std::fs::File;
 use std::io;
 use std::io::BufReader;
 use std::io::{BufRead, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 type IoResult<T> = Result<T, io::Error>;
 
@@ -26,3 +28,253 @@ pub fn read_all(day: u32) -> IoResult<String> {
 
     Ok(output)
 }
+
+/// Reads the puzzle input for a day, searching the default roots of `InputLoader`.
+pub fn load(year: u32, day: u32) -> Result<String, InputError> {
+    InputLoader::new().read(year, day, InputKind::Puzzle)
+}
+
+/// Reads the puzzle input for a day as lines.
+pub fn load_lines(year: u32, day: u32) -> Result<Vec<String>, InputError> {
+    load(year, day).map(|input| input.lines().map(String::from).collect())
+}
+
+/// Reads the `k`th example input for a day, stored as `day{N}.example{K}.txt` beside the input.
+pub fn load_example(year: u32, day: u32, k: u32) -> Result<String, InputError> {
+    InputLoader::new().read(year, day, InputKind::Example(k))
+}
+
+/// The environment variable holding extra input roots, separated like `PATH`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Puzzle,
+    Example(u32),
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("{}", describe_missing(*.year, *.day, *.kind, .tried))]
+    NotFound {
+        year: u32,
+        day: u32,
+        kind: InputKind,
+        tried: Vec<PathBuf>,
+    },
+    #[error("Couldn't read {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+fn describe_missing(year: u32, day: u32, kind: InputKind, tried: &[PathBuf]) -> String {
+    let mut message = match kind {
+        InputKind::Puzzle => format!("No input for {} day {}", year, day),
+        InputKind::Example(k) => format!("No example {} for {} day {}", k, year, day),
+    };
+    message.push_str(", tried:");
+    for path in tried {
+        message.push_str(&format!("\n  {}", path.display()));
+    }
+    message
+}
+
+/// Finds puzzle inputs across the layouts the years use:
+///
+/// * `input/{year}/day{N}.txt`, for 2022 and 2024,
+/// * `input/{N}/input.txt`, for 2016,
+/// * `input/{NN}.txt`, for the other years.
+///
+/// `input/{year}/day{N}.txt` names the year, so it's tried under each root, and under each root's
+/// `aoc{year}`, `{year}` and `{year}/aoc-core` directories; one root at the top of the repository
+/// finds every year. The other two could belong to any year, so they're only tried in that year's
+/// crate, `{year}/aoc-core` or `aoc{year}`: under each root, or in a root that is that crate itself.
+#[derive(Clone, Debug)]
+pub struct InputLoader {
+    roots: Vec<PathBuf>,
+}
+
+impl Default for InputLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputLoader {
+    /// A loader searching, in order: the roots in `AOC_INPUT_DIR`, the working directory, the
+    /// directory of the crate being run by cargo, and the top of this repository.
+    pub fn new() -> InputLoader {
+        let mut roots = Vec::new();
+        if let Some(dirs) = env::var_os(INPUT_DIR_VAR) {
+            roots.extend(env::split_paths(&dirs));
+        }
+        roots.push(PathBuf::from("."));
+        if let Some(manifest_dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            roots.push(PathBuf::from(manifest_dir));
+        }
+        if let Some(workspace) = Path::new(env!("CARGO_MANIFEST_DIR")).parent() {
+            roots.push(workspace.to_path_buf());
+        }
+
+        InputLoader { roots }
+    }
+
+    /// A loader searching only the given roots.
+    pub fn with_roots(roots: impl IntoIterator<Item = impl Into<PathBuf>>) -> InputLoader {
+        InputLoader {
+            roots: roots.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Searches `root` after the existing roots.
+    pub fn add_root(mut self, root: impl Into<PathBuf>) -> InputLoader {
+        self.roots.push(root.into());
+        self
+    }
+
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Every path that could hold the input, in the order they're searched.
+    pub fn candidates(&self, year: u32, day: u32, kind: InputKind) -> Vec<PathBuf> {
+        let file = |name: &str| match kind {
+            InputKind::Puzzle => name.to_string(),
+            InputKind::Example(k) => format!("day{}.example{}.txt", day, k),
+        };
+        let year_layout =
+            PathBuf::from(format!("input/{}", year)).join(file(&format!("day{}.txt", day)));
+        let year_dirs = [
+            PathBuf::new(),
+            PathBuf::from(format!("aoc{}", year)),
+            PathBuf::from(format!("{}", year)),
+            PathBuf::from(format!("{}/aoc-core", year)),
+        ];
+
+        // The layouts that don't name the year, with the crate directory each is used in.
+        let crate_layouts = [
+            (
+                PathBuf::from(format!("aoc{}", year)),
+                PathBuf::from("input").join(file(&format!("{:02}.txt", day))),
+            ),
+            (
+                PathBuf::from(format!("{}/aoc-core", year)),
+                PathBuf::from(format!("input/{}", day)).join(file("input.txt")),
+            ),
+        ];
+        let is_crate = |root: &Path, dir: &Path| {
+            let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+            root.ends_with(dir)
+        };
+
+        let mut candidates = Vec::new();
+        let mut push = |candidate: PathBuf| {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        };
+        for root in &self.roots {
+            for dir in &year_dirs {
+                push(root.join(dir).join(&year_layout));
+            }
+        }
+        for root in &self.roots {
+            for (dir, layout) in &crate_layouts {
+                if is_crate(root, dir) {
+                    push(root.join(layout));
+                }
+                push(root.join(dir).join(layout));
+            }
+        }
+        candidates
+    }
+
+    /// The first candidate path that exists.
+    pub fn find(&self, year: u32, day: u32, kind: InputKind) -> Result<PathBuf, InputError> {
+        let tried = self.candidates(year, day, kind);
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                year,
+                day,
+                kind,
+                tried,
+            }),
+        }
+    }
+
+    pub fn read(&self, year: u32, day: u32, kind: InputKind) -> Result<String, InputError> {
+        let path = self.find(year, day, kind)?;
+        std::fs::read_to_string(&path).map_err(|source| InputError::Io { path, source })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn write(path: PathBuf, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn finds_every_layout() {
+        let root = scratch_dir("layouts");
+        write(root.join("aoc2021/input/03.txt"), "2021");
+        write(root.join("aoc2024/input/2024/day3.txt"), "2024");
+        write(root.join("2016/aoc-core/input/3/input.txt"), "2016");
+        write(root.join("aoc2024/input/2024/day3.example2.txt"), "example");
+
+        let loader = InputLoader::with_roots([&root]);
+        assert_eq!("2021", loader.read(2021, 3, InputKind::Puzzle).unwrap());
+        assert_eq!("2024", loader.read(2024, 3, InputKind::Puzzle).unwrap());
+        assert_eq!("2016", loader.read(2016, 3, InputKind::Puzzle).unwrap());
+        assert_eq!(
+            "example",
+            loader.read(2024, 3, InputKind::Example(2)).unwrap()
+        );
+
+        // From inside a year's crate, the unqualified layouts are found directly.
+        let loader = InputLoader::with_roots([root.join("aoc2021")]);
+        assert_eq!("2021", loader.read(2021, 3, InputKind::Puzzle).unwrap());
+        let loader = InputLoader::with_roots([root.join("2016/aoc-core")]);
+        assert_eq!("2016", loader.read(2016, 3, InputKind::Puzzle).unwrap());
+
+        // Outside of it, `input/{NN}.txt` could be any year's.
+        write(root.join("input/04.txt"), "stray");
+        write(root.join("aoc2020/input/04.txt"), "2020");
+        let loader = InputLoader::with_roots([&root, &root.join("aoc2020")]);
+        write(root.join("input/4/input.txt"), "stray");
+        assert!(loader.read(2021, 4, InputKind::Puzzle).is_err());
+        assert_eq!("2020", loader.read(2020, 4, InputKind::Puzzle).unwrap());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn missing_input_lists_paths() {
+        let root = scratch_dir("missing");
+        let loader = InputLoader::with_roots([&root]);
+
+        let error = loader.read(2021, 25, InputKind::Puzzle).unwrap_err();
+        let message = error.to_string();
+
+        assert!(message.starts_with("No input for 2021 day 25, tried:"));
+        assert!(message.contains(&root.join("aoc2021/input/25.txt").display().to_string()));
+        assert!(message.contains(&root.join("input/2021/day25.txt").display().to_string()));
+        match error {
+            InputError::NotFound { tried, .. } => assert_eq!(6, tried.len()),
+            _ => panic!("Expected NotFound"),
+        }
+    }
+}
@@ -0,0 +1,499 @@
+use num_traits::PrimInt;
+use std::fmt::Debug;
+use std::iter::FromIterator;
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive ranges. Inserting `1..=3`
+/// and `4..=6` leaves a single `1..=6`, so two sets covering the same values always compare equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeSet<T: PrimInt> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: PrimInt> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The normalized ranges making up the set, in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    /// Every value in the set, in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|range| {
+            let end = *range.end();
+            std::iter::successors(Some(*range.start()), move |&v| {
+                if v < end {
+                    Some(v + T::one())
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// The smallest range covering the whole set.
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => Some(*first.start()..=*last.end()),
+            _ => None,
+        }
+    }
+
+    /// The number of integers in the set. Panics if that's more than `u128::MAX`, which only a
+    /// set of every 128-bit integer is.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .try_fold(0u128, |len, range| len.checked_add(Self::range_len(range)?))
+            .expect("RangeSet::len overflows u128")
+    }
+
+    fn range_len(range: &RangeInclusive<T>) -> Option<u128> {
+        let (start, end) = (*range.start(), *range.end());
+        let zero = T::zero();
+        // Work out `end - start` without overflowing `T`, as values that fit in a `u128`.
+        let distance = if start >= zero {
+            end.to_u128()? - start.to_u128()?
+        } else if end < zero {
+            (end - start).to_u128()?
+        } else {
+            end.to_u128()? + (zero - (start + T::one())).to_u128()? + 1
+        };
+        distance.checked_add(1)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_ok()
+    }
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || match self.find(range.start()) {
+                Ok(i) => self.ranges[i].end() >= range.end(),
+                Err(_) => false,
+            }
+    }
+
+    /// Whether any value of `range` is in the set.
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+
+        let (from, to) = self.overlapping(range);
+        from < to
+    }
+
+    /// Whether every value of `other` is also in this set.
+    pub fn is_superset(&self, other: &RangeSet<T>) -> bool {
+        other.ranges.iter().all(|range| self.contains_range(range))
+    }
+
+    /// Adds a range, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let mut start = *range.start();
+        let mut end = *range.end();
+
+        // Widen by one in each direction so adjacent ranges are merged too.
+        let widened = start.checked_sub(&T::one()).unwrap_or(start)
+            ..=end.checked_add(&T::one()).unwrap_or(end);
+        let (from, to) = self.overlapping(&widened);
+        if from < to {
+            start = start.min(*self.ranges[from].start());
+            end = end.max(*self.ranges[to - 1].end());
+        }
+
+        self.ranges.splice(from..to, std::iter::once(start..=end));
+    }
+
+    /// Removes every value of `range` from the set, splitting any range it lands inside of.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (from, to) = self.overlapping(&range);
+        if from == to {
+            return;
+        }
+
+        let mut remainder = Vec::with_capacity(2);
+        let first = &self.ranges[from];
+        if first.start() < range.start() {
+            remainder.push(*first.start()..=*range.start() - T::one());
+        }
+        let last = &self.ranges[to - 1];
+        if last.end() > range.end() {
+            remainder.push(*range.end() + T::one()..=*last.end());
+        }
+
+        self.ranges.splice(from..to, remainder);
+    }
+
+    /// Adds every range of `other` to this set.
+    pub fn merge(&mut self, other: &RangeSet<T>) {
+        for range in &other.ranges {
+            self.insert(range.clone());
+        }
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let a = &self.ranges[i];
+            let b = &other.ranges[j];
+
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // Pieces of disjoint, non-adjacent ranges are themselves disjoint and non-adjacent.
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The ranges within `within` that aren't covered by the set.
+    pub fn gaps(&self, within: RangeInclusive<T>) -> RangeSet<T> {
+        let mut gaps = RangeSet::new();
+        gaps.insert(within);
+        gaps.difference(self)
+    }
+
+    /// Binary searches for the range containing `value`, returning its index, or the index at
+    /// which a range starting at `value` would be inserted.
+    fn find(&self, value: &T) -> Result<usize, usize> {
+        let index = self.ranges.partition_point(|range| range.end() < value);
+        match self.ranges.get(index) {
+            Some(range) if range.start() <= value => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    /// The half-open index range of stored ranges that overlap `range`.
+    fn overlapping(&self, range: &RangeInclusive<T>) -> (usize, usize) {
+        let from = self.ranges.partition_point(|r| r.end() < range.start());
+        let to = self.ranges.partition_point(|r| r.start() <= range.end());
+        (from, to.max(from))
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(it: I) -> RangeSet<T> {
+        let mut set = RangeSet::new();
+        set.extend(it);
+        set
+    }
+}
+
+impl<T: PrimInt> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, it: I) {
+        for range in it {
+            self.insert(range);
+        }
+    }
+}
+
+/// A piecewise translation table: values inside a source range are shifted to the matching
+/// position in its destination range, and everything else maps to itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap<T: PrimInt> {
+    // Sorted by source start, non-overlapping.
+    entries: Vec<(RangeInclusive<T>, T)>,
+}
+
+impl<T: PrimInt + Debug> RangeMap<T> {
+    pub fn new() -> Self {
+        RangeMap {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Maps `source` onto the range of the same length starting at `destination_start`. Panics if
+    /// `source` overlaps a range that's already mapped.
+    pub fn insert(&mut self, source: RangeInclusive<T>, destination_start: T) {
+        let index = self
+            .entries
+            .partition_point(|(range, _)| range.start() < source.start());
+
+        let overlaps_prev = index > 0 && self.entries[index - 1].0.end() >= source.start();
+        let overlaps_next = self
+            .entries
+            .get(index)
+            .is_some_and(|(range, _)| range.start() <= source.end());
+        if overlaps_prev || overlaps_next {
+            panic!("RangeMap source {:?} overlaps an existing entry", source);
+        }
+
+        self.entries.insert(index, (source, destination_start));
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let index = self
+            .entries
+            .partition_point(|(range, _)| *range.end() < value);
+        match self.entries.get(index) {
+            Some((range, destination)) if *range.start() <= value => {
+                *destination + (value - *range.start())
+            }
+            _ => value,
+        }
+    }
+
+    /// Splits `range` at the table's boundaries, returning each source piece alongside the range
+    /// it maps to, in ascending source order.
+    pub fn split(&self, range: RangeInclusive<T>) -> Vec<(RangeInclusive<T>, RangeInclusive<T>)> {
+        let mut pieces = Vec::new();
+        if range.is_empty() {
+            return pieces;
+        }
+
+        let (mut start, end) = range.into_inner();
+        let from = self
+            .entries
+            .partition_point(|(source, _)| *source.end() < start);
+
+        for (source, destination) in &self.entries[from..] {
+            if *source.start() > end {
+                break;
+            }
+
+            // An unmapped stretch before this entry maps to itself.
+            if start < *source.start() {
+                let gap_end = *source.start() - T::one();
+                pieces.push((start..=gap_end, start..=gap_end));
+                start = *source.start();
+            }
+
+            let piece_end = end.min(*source.end());
+            let offset = start - *source.start();
+            let length = piece_end - start;
+            pieces.push((
+                start..=piece_end,
+                *destination + offset..=*destination + offset + length,
+            ));
+
+            match piece_end.checked_add(&T::one()) {
+                Some(next) if piece_end < end => start = next,
+                _ => return pieces,
+            }
+        }
+
+        pieces.push((start..=end, start..=end));
+        pieces
+    }
+
+    /// Maps every value of a set through the table.
+    pub fn map(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        set.ranges()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(_, destination)| destination)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i32, i32)]) -> RangeSet<i32> {
+        ranges.iter().map(|&(start, end)| start..=end).collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let mut ranges = RangeSet::new();
+        ranges.insert(10..=12);
+        ranges.insert(1..=3);
+        ranges.insert(4..=5);
+        ranges.insert(20..=30);
+        ranges.insert(11..=21);
+
+        assert_eq!(
+            vec![&(1..=5), &(10..=30)],
+            ranges.ranges().collect::<Vec<_>>()
+        );
+        assert_eq!(26, ranges.len());
+    }
+
+    #[test]
+    fn insert_at_type_bounds() {
+        let mut ranges = RangeSet::new();
+        ranges.insert(250u8..=255);
+        ranges.insert(0..=3);
+        ranges.insert(4..=249);
+
+        assert_eq!(vec![&(0..=255)], ranges.ranges().collect::<Vec<_>>());
+        assert_eq!(256, ranges.len());
+    }
+
+    #[test]
+    fn full_width_lengths() {
+        assert_eq!(1 << 64, RangeSet::from_iter([i64::MIN..=i64::MAX]).len());
+        assert_eq!(1 << 64, RangeSet::from_iter([u64::MIN..=u64::MAX]).len());
+        assert_eq!(u128::MAX, RangeSet::from_iter([1..=u128::MAX]).len());
+        assert_eq!(
+            u128::MAX,
+            RangeSet::from_iter([i128::MIN + 1..=i128::MAX]).len()
+        );
+        assert_eq!(
+            3,
+            set(&[(i32::MIN, i32::MIN + 1), (i32::MAX, i32::MAX)]).len()
+        );
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut ranges = set(&[(1, 10), (20, 30)]);
+        ranges.remove(5..=22);
+
+        assert_eq!(set(&[(1, 4), (23, 30)]), ranges);
+
+        ranges.remove(2..=2);
+        assert_eq!(set(&[(1, 1), (3, 4), (23, 30)]), ranges);
+
+        ranges.remove(0..=100);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn containment() {
+        let ranges = set(&[(2, 4), (6, 8)]);
+
+        assert!(ranges.contains(&2));
+        assert!(!ranges.contains(&5));
+        assert!(ranges.contains_range(&(6..=8)));
+        assert!(!ranges.contains_range(&(4..=6)));
+        assert!(ranges.overlaps(&(4..=6)));
+        assert!(!ranges.overlaps(&(9..=12)));
+        assert!(ranges.is_superset(&set(&[(3, 4), (7, 7)])));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 21)]);
+
+        assert_eq!(set(&[(1, 15), (20, 21)]), a.union(&b));
+        assert_eq!(set(&[(4, 5), (10, 11)]), a.intersection(&b));
+        assert_eq!(set(&[(1, 3), (12, 15)]), a.difference(&b));
+        assert_eq!(set(&[(16, 19)]), a.union(&b).gaps(1..=21));
+        assert_eq!(set(&[(0, 0), (6, 9), (16, 20)]), a.gaps(0..=20));
+    }
+
+    #[test]
+    fn sample_section_assignments() {
+        // 2022 day 4: pairs where one assignment fully contains the other.
+        let pairs = [
+            ((2, 4), (6, 8)),
+            ((2, 3), (4, 5)),
+            ((5, 7), (7, 9)),
+            ((2, 8), (3, 7)),
+            ((6, 6), (4, 6)),
+            ((2, 6), (4, 8)),
+        ];
+
+        let contained = pairs
+            .iter()
+            .filter(|(l, r)| {
+                let (l, r) = (set(&[*l]), set(&[*r]));
+                l.is_superset(&r) || r.is_superset(&l)
+            })
+            .count();
+        let overlapping = pairs
+            .iter()
+            .filter(|(l, r)| set(&[*l]).overlaps(&(r.0..=r.1)))
+            .count();
+
+        assert_eq!(2, contained);
+        assert_eq!(4, overlapping);
+    }
+
+    #[test]
+    fn range_map_split() {
+        let mut map = RangeMap::new();
+        map.insert(98..=99, 50);
+        map.insert(50..=97, 52);
+
+        assert_eq!(81, map.get(79));
+        assert_eq!(14, map.get(14));
+        assert_eq!(51, map.get(99));
+        assert_eq!(
+            vec![
+                (45..=49, 45..=49),
+                (50..=97, 52..=99),
+                (98..=99, 50..=51),
+                (100..=110, 100..=110)
+            ],
+            map.split(45..=110)
+        );
+        assert_eq!(vec![(60..=70, 62..=72)], map.split(60..=70));
+    }
+
+    #[test]
+    fn range_map_maps_sets() {
+        let mut map = RangeMap::new();
+        map.insert(98..=99, 50);
+        map.insert(50..=97, 52);
+
+        let seeds = set(&[(79, 92), (55, 67)]);
+
+        assert_eq!(set(&[(57, 69), (81, 94)]), map.map(&seeds));
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_map_rejects_overlaps() {
+        let mut map = RangeMap::new();
+        map.insert(10..=20, 0);
+        map.insert(15..=25, 100);
+    }
+}
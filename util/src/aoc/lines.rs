@@ -1,4 +1,7 @@
-use crate::aoc::Point;
+use crate::aoc::math::gcd;
+use crate::aoc::{AocResult, Point};
+use crate::bail;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LineSegment {
@@ -15,18 +18,42 @@ impl LineSegment {
         self.start.distance(&self.end)
     }
 
+    /// Returns the intersection of two non-parallel segments, if they meet at a lattice point.
     pub fn intersection(&self, other_segment: &LineSegment) -> Option<Point> {
         match self.relate(other_segment) {
-            LineRelation::DivergentIntersecting(point) => Some(point),
+            LineRelation::DivergentIntersecting(point) => point.to_point(),
             _ => None,
         }
     }
 
+    /// Iterates over every lattice point on the segment, from start to end inclusive.
     pub fn point_iterator(&self) -> LineSegmentPointIterator {
         LineSegmentPointIterator::new(self.clone())
     }
 
-    /// Borrowed from line_intersection crate, and adapted for this integer-only AoC world.
+    /// Iterates over the points a Bresenham rasterization of the segment would draw. Unlike
+    /// `point_iterator`, this yields one point per step along the major axis, even when the line
+    /// doesn't pass through a lattice point there.
+    pub fn raster_iterator(&self) -> BresenhamIterator {
+        BresenhamIterator::new(self.clone())
+    }
+
+    /// Whether a point lies exactly on this segment.
+    pub fn contains(&self, point: &Point) -> bool {
+        let r = self.end - self.start;
+        let q = *point - self.start;
+
+        Self::cross(&r, &q) == 0
+            && (self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)).contains(&point.x)
+            && (self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)).contains(&point.y)
+    }
+
+    /// Borrowed from line_intersection crate, and adapted for this integer-only AoC world. All
+    /// arithmetic is exact, so non-lattice intersections are reported as rational points rather
+    /// than being truncated.
+    ///
+    /// A single-point segment has no direction, so it either meets the other segment at that
+    /// point or misses it entirely.
     pub fn relate(&self, other: &LineSegment) -> LineRelation {
         // see https://stackoverflow.com/a/565282
         let p = self.start;
@@ -34,32 +61,54 @@ impl LineSegment {
         let r = self.end - self.start;
         let s = other.end - other.start;
 
+        let single_point = if r == Point::zero() {
+            Some((p, other))
+        } else if s == Point::zero() {
+            Some((q, self))
+        } else {
+            None
+        };
+        if let Some((point, segment)) = single_point {
+            return if segment.contains(&point) {
+                LineRelation::DivergentIntersecting(RationalPoint::from(point))
+            } else {
+                LineRelation::DivergentDisjoint
+            };
+        }
+
         let r_cross_s = Self::cross(&r, &s);
         let q_minus_p = q - p;
         let q_minus_p_cross_r = Self::cross(&q_minus_p, &r);
 
         // are the lines are parallel?
-        if r_cross_s == 0f32 {
+        if r_cross_s == 0 {
             // are the lines collinear?
-            if q_minus_p_cross_r == 0f32 {
+            if q_minus_p_cross_r == 0 {
                 // the lines are collinear
-                LineRelation::Collinear
+                LineRelation::Collinear(self.collinear_overlap(other))
             } else {
                 // the lines are parallel but not collinear
                 LineRelation::Parallel
             }
         } else {
-            // the lines are not parallel
-            let t = Self::cross_div(&q_minus_p, &s, r_cross_s);
-            let u = Self::cross_div(&q_minus_p, &r, r_cross_s);
+            // the lines are not parallel; t = t_num / denominator, u = u_num / denominator
+            let (t_num, u_num, denominator) = if r_cross_s < 0 {
+                (-Self::cross(&q_minus_p, &s), -q_minus_p_cross_r, -r_cross_s)
+            } else {
+                (Self::cross(&q_minus_p, &s), q_minus_p_cross_r, r_cross_s)
+            };
 
             // are the intersection coordinates both in range?
-            let t_in_range = (0f32..=1f32).contains(&t);
-            let u_in_range = (0f32..=1f32).contains(&u);
+            let t_in_range = (0..=denominator).contains(&t_num);
+            let u_in_range = (0..=denominator).contains(&u_num);
 
             if t_in_range && u_in_range {
-                // there is an intersection
-                LineRelation::DivergentIntersecting(Self::t_coord_to_point(&p, &r, t))
+                // there is an intersection at p + t * r
+                LineRelation::DivergentIntersecting(RationalPoint::new(
+                    p.x as i128 * denominator + t_num * r.x as i128,
+                    p.y as i128 * denominator + t_num * r.y as i128,
+                    denominator,
+                ))
             } else {
                 // there is no intersection
                 LineRelation::DivergentDisjoint
@@ -67,21 +116,109 @@ impl LineSegment {
         }
     }
 
-    fn cross(a: &Point, b: &Point) -> f32 {
-        (a.x * b.y - a.y * b.x) as f32
+    /// Finds the shared sub-segment of two segments of non-zero length already known to be
+    /// collinear, oriented in the same direction as `self`.
+    fn collinear_overlap(&self, other: &LineSegment) -> Option<LineSegment> {
+        // Project everything onto an axis the shared line isn't perpendicular to.
+        let direction = self.end - self.start;
+        let project = |point: &Point| {
+            if direction.x != 0 {
+                point.x
+            } else {
+                point.y
+            }
+        };
+
+        let (self_min, self_max) = Self::ordered_by(self.start, self.end, project);
+        let (other_min, other_max) = Self::ordered_by(other.start, other.end, project);
+
+        let start = if project(&self_min) >= project(&other_min) {
+            self_min
+        } else {
+            other_min
+        };
+        let end = if project(&self_max) <= project(&other_max) {
+            self_max
+        } else {
+            other_max
+        };
+
+        if project(&start) > project(&end) {
+            None
+        } else if project(&self.start) <= project(&self.end) {
+            Some(LineSegment::new(start, end))
+        } else {
+            Some(LineSegment::new(end, start))
+        }
     }
 
-    fn cross_div(a: &Point, b: &Point, r_cross_s: f32) -> f32 {
-        let x = b.x as f32 / r_cross_s;
-        let y = b.y as f32 / r_cross_s;
+    fn ordered_by<F: Fn(&Point) -> i32>(a: Point, b: Point, key: F) -> (Point, Point) {
+        if key(&a) <= key(&b) {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
 
-        a.x as f32 * y - a.y as f32 * x
+    fn cross(a: &Point, b: &Point) -> i128 {
+        a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128
     }
+}
+
+/// A point with exact rational coordinates `(x / denominator, y / denominator)`, always kept in
+/// lowest terms with a positive denominator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RationalPoint {
+    pub x: i128,
+    pub y: i128,
+    pub denominator: i128,
+}
+
+impl RationalPoint {
+    pub fn new(x: i128, y: i128, denominator: i128) -> RationalPoint {
+        if denominator == 0 {
+            panic!("RationalPoint denominator must be non-zero");
+        }
+
+        let sign = denominator.signum();
+        let divisor = gcd(gcd(x, y), denominator);
 
-    fn t_coord_to_point(p: &Point, r: &Point, t: f32) -> Point {
-        let t_x = t * r.x as f32;
-        let t_y = t * r.y as f32;
-        Point::new(p.x + t_x as i32, p.y + t_y as i32)
+        RationalPoint {
+            x: sign * x / divisor,
+            y: sign * y / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    /// Whether both coordinates are whole numbers.
+    pub fn is_integral(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Converts to a lattice `Point`, if both coordinates are whole numbers.
+    pub fn to_point(&self) -> Option<Point> {
+        if self.is_integral() {
+            Some(Point::new(
+                i32::try_from(self.x).ok()?,
+                i32::try_from(self.y).ok()?,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Lossy conversion to floating point coordinates.
+    pub fn to_f64(&self) -> (f64, f64) {
+        (
+            self.x as f64 / self.denominator as f64,
+            self.y as f64 / self.denominator as f64,
+        )
+    }
+}
+
+impl From<Point> for RationalPoint {
+    fn from(point: Point) -> Self {
+        RationalPoint::new(point.x as i128, point.y as i128, 1)
     }
 }
 
@@ -89,47 +226,41 @@ impl LineSegment {
 #[derive(Debug, PartialEq)]
 pub enum LineRelation {
     /// The line intervals are not parallel (or anti-parallel), and "meet" each other at exactly
-    /// one point.
-    DivergentIntersecting(Point),
+    /// one point. That point need not lie on the integer lattice.
+    DivergentIntersecting(RationalPoint),
     /// The line intervals are not parallel (or anti-parallel), and do not intersect; they "miss"
     /// each other.
     DivergentDisjoint,
-    /// The line intervals lie on the same line. They may or may not overlap, and this intersection
-    /// is possibly infinite.
-    Collinear,
+    /// The line intervals lie on the same line. If they overlap, the shared sub-segment (which may
+    /// be a single point) is included, oriented in the same direction as the first segment.
+    Collinear(Option<LineSegment>),
     /// The line intervals are parallel or anti-parallel.
     Parallel,
 }
 
+/// Walks every lattice point on a segment of any slope, stepping by the segment's direction
+/// vector divided by the gcd of its components.
 pub struct LineSegmentPointIterator {
-    line: LineSegment,
     current_point: Point,
     delta: Point,
+    remaining: u32,
 }
 
 impl LineSegmentPointIterator {
     pub fn new(line: LineSegment) -> LineSegmentPointIterator {
-        // Determine delta.
-        let start = line.start;
-        let end = line.end;
-
-        let delta = match (end.x - start.x, end.y - start.y) {
-            (0, 0) => panic!("Not a line"),
-            (x, 0) if x > 0 => Point::new(1, 0),
-            (x, 0) if x < 0 => Point::new(-1, 0),
-            (0, y) if y > 0 => Point::new(0, 1),
-            (0, y) if y < 0 => Point::new(0, -1),
-            (x, y) if x > 0 && y > 0 => Point::new(1, 1),
-            (x, y) if x > 0 && y < 0 => Point::new(1, -1),
-            (x, y) if x < 0 && y > 0 => Point::new(-1, 1),
-            (x, y) if x < 0 && y < 0 => Point::new(-1, -1),
-            x => panic!("Unmatched: {:?}", x),
+        let direction = line.end - line.start;
+        let steps = gcd(direction.x as i128, direction.y as i128) as i32;
+
+        let delta = if steps == 0 {
+            Point::zero()
+        } else {
+            Point::new(direction.x / steps, direction.y / steps)
         };
 
         LineSegmentPointIterator {
-            current_point: line.start - delta,
-            line,
+            current_point: line.start,
             delta,
+            remaining: steps as u32 + 1,
         }
     }
 }
@@ -138,18 +269,390 @@ impl Iterator for LineSegmentPointIterator {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_point == self.line.end {
+        if self.remaining == 0 {
             None
         } else {
+            let point = self.current_point;
+            self.remaining -= 1;
             self.current_point += self.delta;
-            Some(self.current_point)
+            Some(point)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+/// Rasterizes a segment of any slope using Bresenham's algorithm.
+pub struct BresenhamIterator {
+    current_point: Point,
+    end: Point,
+    dx: i32,
+    dy: i32,
+    step: Point,
+    error: i32,
+    done: bool,
+}
+
+impl BresenhamIterator {
+    pub fn new(line: LineSegment) -> BresenhamIterator {
+        let dx = (line.end.x - line.start.x).abs();
+        let dy = -(line.end.y - line.start.y).abs();
+
+        BresenhamIterator {
+            current_point: line.start,
+            end: line.end,
+            dx,
+            dy,
+            step: Point::new(
+                (line.end.x - line.start.x).signum(),
+                (line.end.y - line.start.y).signum(),
+            ),
+            error: dx + dy,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for BresenhamIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let point = self.current_point;
+        if point == self.end {
+            self.done = true;
+        } else {
+            let doubled_error = 2 * self.error;
+            if doubled_error >= self.dy {
+                self.error += self.dy;
+                self.current_point.x += self.step.x;
+            }
+            if doubled_error <= self.dx {
+                self.error += self.dx;
+                self.current_point.y += self.step.y;
+            }
+        }
+
+        Some(point)
+    }
+}
+
+/// The four slopes a `SegmentSweep` understands. Each describes a family of parallel lines
+/// `a*x + b*y = key`, with points along a given line identified by `param`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Orientation {
+    Horizontal,
+    Vertical,
+    Diagonal,
+    AntiDiagonal,
+}
+
+impl Orientation {
+    const ALL: [Orientation; 4] = [
+        Orientation::Horizontal,
+        Orientation::Vertical,
+        Orientation::Diagonal,
+        Orientation::AntiDiagonal,
+    ];
+
+    fn of(segment: &LineSegment) -> Option<Orientation> {
+        let delta = segment.end - segment.start;
+        match (delta.x, delta.y) {
+            (_, 0) => Some(Orientation::Horizontal),
+            (0, _) => Some(Orientation::Vertical),
+            (x, y) if x == y => Some(Orientation::Diagonal),
+            (x, y) if x == -y => Some(Orientation::AntiDiagonal),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Coefficients `(a, b)` of the line equation `a*x + b*y = key`.
+    fn coefficients(self) -> (i64, i64) {
+        match self {
+            Orientation::Horizontal => (0, 1),
+            Orientation::Vertical => (1, 0),
+            Orientation::Diagonal => (1, -1),
+            Orientation::AntiDiagonal => (1, 1),
+        }
+    }
+
+    fn key(self, point: &Point) -> i64 {
+        let (a, b) = self.coefficients();
+        a * point.x as i64 + b * point.y as i64
+    }
+
+    fn param(self, point: &Point) -> i64 {
+        match self {
+            Orientation::Vertical => point.y as i64,
+            _ => point.x as i64,
+        }
+    }
+
+    fn point(self, key: i64, param: i64) -> Point {
+        let (x, y) = match self {
+            Orientation::Horizontal => (param, key),
+            Orientation::Vertical => (key, param),
+            Orientation::Diagonal => (param, param - key),
+            Orientation::AntiDiagonal => (param, key - param),
+        };
+        Point::new(x as i32, y as i32)
+    }
+
+    /// Where the line `self = key` meets `other = other_key`, if that's a lattice point.
+    fn meet(self, key: i64, other: Orientation, other_key: i64) -> Option<Point> {
+        let (a1, b1) = self.coefficients();
+        let (a2, b2) = other.coefficients();
+        let det = a1 * b2 - a2 * b1;
+        let x = key * b2 - other_key * b1;
+        let y = a1 * other_key - a2 * key;
+
+        if det == 0 || x % det != 0 || y % det != 0 {
+            None
+        } else {
+            Some(Point::new((x / det) as i32, (y / det) as i32))
+        }
+    }
+}
+
+/// A maximal stretch of a line covered by the same number of input segments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageRun {
+    pub segment: LineSegment,
+    pub count: u32,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Run {
+    key: i64,
+    lo: i64,
+    hi: i64,
+    count: u32,
+}
+
+impl Run {
+    fn len(&self) -> u64 {
+        (self.hi - self.lo + 1) as u64
+    }
+}
+
+/// Finds every lattice point covered by more than one segment, for segments that are horizontal,
+/// vertical or at 45°, without walking the segments point by point. Collinear overlaps are
+/// merged into `CoverageRun`s with a 1D sweep per line; crossings between non-parallel families
+/// are found with an orthogonal sweep, so the cost depends on the number of segments and
+/// intersections rather than on their length.
+#[derive(Debug)]
+pub struct SegmentSweep {
+    runs: [Vec<Run>; 4],
+    crossings: HashMap<Point, [u32; 4]>,
+}
+
+impl SegmentSweep {
+    pub fn new<'a>(segments: impl IntoIterator<Item = &'a LineSegment>) -> AocResult<Self> {
+        let mut lines: [HashMap<i64, Vec<(i64, i64)>>; 4] = Default::default();
+        for segment in segments {
+            let orientation = match Orientation::of(segment) {
+                Some(orientation) => orientation,
+                None => bail!(
+                    "Segment is not horizontal, vertical or diagonal: {:?}",
+                    segment
+                ),
+            };
+
+            let key = orientation.key(&segment.start);
+            let a = orientation.param(&segment.start);
+            let b = orientation.param(&segment.end);
+            lines[orientation.index()]
+                .entry(key)
+                .or_default()
+                .push((a.min(b), a.max(b)));
+        }
+
+        let runs = lines.map(|lines| {
+            lines
+                .into_iter()
+                .flat_map(|(key, intervals)| Self::merge_line(key, intervals))
+                .collect::<Vec<_>>()
+        });
+
+        let mut sweep = SegmentSweep {
+            runs,
+            crossings: HashMap::new(),
+        };
+
+        for (i, &first) in Orientation::ALL.iter().enumerate() {
+            for &second in &Orientation::ALL[i + 1..] {
+                sweep.find_crossings(first, second);
+            }
+        }
+
+        Ok(sweep)
+    }
+
+    /// Splits the intervals on a single line into runs of constant, non-zero coverage.
+    fn merge_line(key: i64, intervals: Vec<(i64, i64)>) -> Vec<Run> {
+        let mut events: Vec<(i64, i32)> = intervals
+            .into_iter()
+            .flat_map(|(lo, hi)| [(lo, 1), (hi + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut runs = Vec::new();
+        let mut count = 0;
+        let mut i = 0;
+        while i < events.len() {
+            let position = events[i].0;
+            while i < events.len() && events[i].0 == position {
+                count += events[i].1;
+                i += 1;
+            }
+
+            if count > 0 {
+                let hi = events[i].0 - 1;
+                runs.push(Run {
+                    key,
+                    lo: position,
+                    hi,
+                    count: count as u32,
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// Sweeps over `first`'s key. Each run of `second` is a fixed `second` key spanning a range of
+    /// `first` keys, and each run of `first` is a query for `second` keys in its span.
+    fn find_crossings(&mut self, first: Orientation, second: Orientation) {
+        #[derive(PartialEq, Eq, PartialOrd, Ord)]
+        enum Event {
+            Insert(i64, u32),
+            Query(usize),
+            Remove(i64),
+        }
+
+        let span = |run: &Run, along: Orientation, of: Orientation| {
+            let a = of.key(&along.point(run.key, run.lo));
+            let b = of.key(&along.point(run.key, run.hi));
+            (a.min(b), a.max(b))
+        };
+
+        let mut events = Vec::new();
+        for run in &self.runs[second.index()] {
+            let (lo, hi) = span(run, second, first);
+            events.push((lo, Event::Insert(run.key, run.count)));
+            events.push((hi, Event::Remove(run.key)));
+        }
+        for (i, run) in self.runs[first.index()].iter().enumerate() {
+            events.push((run.key, Event::Query(i)));
+        }
+        events.sort_unstable();
+
+        let mut active: BTreeMap<i64, u32> = BTreeMap::new();
+        for (first_key, event) in events {
+            match event {
+                Event::Insert(key, count) => {
+                    active.insert(key, count);
+                }
+                Event::Remove(key) => {
+                    active.remove(&key);
+                }
+                Event::Query(i) => {
+                    let run = self.runs[first.index()][i];
+                    let (lo, hi) = span(&run, first, second);
+                    for (&second_key, &second_count) in active.range(lo..=hi) {
+                        if let Some(point) = first.meet(first_key, second, second_key) {
+                            let coverage = self.crossings.entry(point).or_default();
+                            coverage[first.index()] = run.count;
+                            coverage[second.index()] = second_count;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stretches of line covered by at least two collinear segments.
+    pub fn overlaps(&self) -> impl Iterator<Item = CoverageRun> + '_ {
+        Orientation::ALL.iter().flat_map(move |&orientation| {
+            self.runs[orientation.index()]
+                .iter()
+                .filter(|run| run.count > 1)
+                .map(move |run| CoverageRun {
+                    segment: LineSegment::new(
+                        orientation.point(run.key, run.lo),
+                        orientation.point(run.key, run.hi),
+                    ),
+                    count: run.count,
+                })
+        })
+    }
+
+    /// Points where non-parallel segments meet, along with the total number of segments covering
+    /// each.
+    pub fn crossings(&self) -> impl Iterator<Item = (Point, u32)> + '_ {
+        self.crossings
+            .iter()
+            .map(|(point, coverage)| (*point, coverage.iter().sum()))
+    }
+
+    /// How many segments cover the given point.
+    pub fn coverage(&self, point: &Point) -> u32 {
+        if let Some(coverage) = self.crossings.get(point) {
+            return coverage.iter().sum();
         }
+
+        Orientation::ALL
+            .iter()
+            .map(|&orientation| {
+                let key = orientation.key(point);
+                let param = orientation.param(point);
+                self.runs[orientation.index()]
+                    .iter()
+                    .find(|run| run.key == key && (run.lo..=run.hi).contains(&param))
+                    .map_or(0, |run| run.count)
+            })
+            .sum()
+    }
+
+    /// Counts the lattice points covered by at least `min_coverage` segments.
+    pub fn count_covered(&self, min_coverage: u32) -> u64 {
+        let along_runs: u64 = self
+            .runs
+            .iter()
+            .flatten()
+            .filter(|run| run.count >= min_coverage)
+            .map(Run::len)
+            .sum();
+
+        // Crossing points were either counted once per family whose run already met the minimum,
+        // or not at all; replace that with a single count based on their total coverage.
+        let crossing_adjustment: i64 = self
+            .crossings
+            .values()
+            .map(|coverage| {
+                let already_counted = coverage.iter().filter(|&&c| c >= min_coverage).count();
+                let total: u32 = coverage.iter().sum();
+                (total >= min_coverage) as i64 - already_counted as i64
+            })
+            .sum();
+
+        (along_runs as i64 + crossing_adjustment) as u64
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use itertools::Itertools;
 
     #[test]
     fn point_iterator_two_horizontal() {
@@ -263,4 +766,300 @@ mod tests {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn point_iterator_single_point() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(
+            vec![point],
+            LineSegment::new(point, point)
+                .point_iterator()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn point_iterator_diagonal() {
+        let start = Point::new(3, 0);
+        let end = Point::new(0, 3);
+
+        assert_eq!(
+            vec![start, Point::new(2, 1), Point::new(1, 2), end],
+            LineSegment::new(start, end)
+                .point_iterator()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn point_iterator_arbitrary_slope() {
+        let start = Point::new(0, 0);
+        let end = Point::new(6, -4);
+
+        assert_eq!(
+            vec![start, Point::new(3, -2), end],
+            LineSegment::new(start, end)
+                .point_iterator()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn raster_iterator_shallow_slope() {
+        let start = Point::new(0, 0);
+        let end = Point::new(5, 2);
+
+        assert_eq!(
+            vec![
+                start,
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(3, 1),
+                Point::new(4, 2),
+                end
+            ],
+            LineSegment::new(start, end)
+                .raster_iterator()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn raster_iterator_matches_point_iterator_on_diagonals() {
+        let line = LineSegment::new(Point::new(5, 5), Point::new(8, 2));
+
+        assert_eq!(
+            line.point_iterator().collect::<Vec<_>>(),
+            line.raster_iterator().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn relate_lattice_intersection() {
+        let a = LineSegment::new(Point::new(0, 0), Point::new(4, 4));
+        let b = LineSegment::new(Point::new(0, 4), Point::new(4, 0));
+
+        assert_eq!(
+            LineRelation::DivergentIntersecting(RationalPoint::from(Point::new(2, 2))),
+            a.relate(&b)
+        );
+        assert_eq!(Some(Point::new(2, 2)), a.intersection(&b));
+    }
+
+    #[test]
+    fn relate_non_lattice_intersection() {
+        let a = LineSegment::new(Point::new(0, 0), Point::new(1, 1));
+        let b = LineSegment::new(Point::new(0, 1), Point::new(1, 0));
+
+        match a.relate(&b) {
+            LineRelation::DivergentIntersecting(point) => {
+                assert!(!point.is_integral());
+                assert_eq!(RationalPoint::new(1, 1, 2), point);
+                assert_eq!((0.5, 0.5), point.to_f64());
+            }
+            other => panic!("Expected an intersection, got {:?}", other),
+        }
+        assert_eq!(None, a.intersection(&b));
+    }
+
+    #[test]
+    fn relate_disjoint_and_parallel() {
+        let a = LineSegment::new(Point::new(0, 0), Point::new(2, 0));
+
+        assert_eq!(
+            LineRelation::DivergentDisjoint,
+            a.relate(&LineSegment::new(Point::new(3, -1), Point::new(3, 1)))
+        );
+        assert_eq!(
+            LineRelation::Parallel,
+            a.relate(&LineSegment::new(Point::new(0, 1), Point::new(2, 1)))
+        );
+    }
+
+    #[test]
+    fn relate_collinear_overlap() {
+        let a = LineSegment::new(Point::new(0, 0), Point::new(6, 3));
+        let b = LineSegment::new(Point::new(8, 4), Point::new(2, 1));
+
+        assert_eq!(
+            LineRelation::Collinear(Some(LineSegment::new(Point::new(2, 1), Point::new(6, 3)))),
+            a.relate(&b)
+        );
+        assert_eq!(
+            LineRelation::Collinear(Some(LineSegment::new(Point::new(6, 3), Point::new(2, 1)))),
+            b.relate(&a)
+        );
+    }
+
+    #[test]
+    fn relate_collinear_touching_and_disjoint() {
+        let a = LineSegment::new(Point::new(0, 0), Point::new(0, 5));
+
+        assert_eq!(
+            LineRelation::Collinear(Some(LineSegment::new(Point::new(0, 5), Point::new(0, 5)))),
+            a.relate(&LineSegment::new(Point::new(0, 5), Point::new(0, 9)))
+        );
+        assert_eq!(
+            LineRelation::Collinear(None),
+            a.relate(&LineSegment::new(Point::new(0, 6), Point::new(0, 9)))
+        );
+    }
+
+    #[test]
+    fn relate_single_points() {
+        let line = LineSegment::new(Point::new(0, 0), Point::new(6, 4));
+        let on = LineSegment::new(Point::new(3, 2), Point::new(3, 2));
+        let beyond = LineSegment::new(Point::new(9, 6), Point::new(9, 6));
+        let off = LineSegment::new(Point::new(2, 1), Point::new(2, 1));
+
+        assert_eq!(Some(Point::new(3, 2)), line.intersection(&on));
+        assert_eq!(Some(Point::new(3, 2)), on.intersection(&line));
+        assert_eq!(LineRelation::DivergentDisjoint, line.relate(&beyond));
+        assert_eq!(LineRelation::DivergentDisjoint, beyond.relate(&line));
+        assert_eq!(LineRelation::DivergentDisjoint, off.relate(&line));
+        assert_eq!(Some(Point::new(3, 2)), on.intersection(&on));
+        assert_eq!(LineRelation::DivergentDisjoint, on.relate(&off));
+    }
+
+    #[test]
+    fn contains() {
+        let line = LineSegment::new(Point::new(0, 0), Point::new(6, 4));
+
+        assert!(line.contains(&Point::new(3, 2)));
+        assert!(line.contains(&Point::new(6, 4)));
+        assert!(!line.contains(&Point::new(9, 6)));
+        assert!(!line.contains(&Point::new(2, 1)));
+    }
+
+    fn brute_force_coverage(segments: &[LineSegment]) -> HashMap<Point, u32> {
+        let mut coverage = HashMap::new();
+        for point in segments.iter().flat_map(|s| s.point_iterator()) {
+            *coverage.entry(point).or_default() += 1;
+        }
+        coverage
+    }
+
+    #[test]
+    fn sweep_sample() {
+        let segments = vec![
+            ((0, 9), (5, 9)),
+            ((8, 0), (0, 8)),
+            ((9, 4), (3, 4)),
+            ((2, 2), (2, 1)),
+            ((7, 0), (7, 4)),
+            ((6, 4), (2, 0)),
+            ((0, 9), (2, 9)),
+            ((3, 4), (1, 4)),
+            ((0, 0), (8, 8)),
+            ((5, 5), (8, 2)),
+        ]
+        .into_iter()
+        .map(|((x1, y1), (x2, y2))| LineSegment::new(Point::new(x1, y1), Point::new(x2, y2)))
+        .collect::<Vec<_>>();
+
+        let sweep = SegmentSweep::new(&segments).unwrap();
+
+        assert_eq!(12, sweep.count_covered(2));
+        assert_eq!(2, sweep.coverage(&Point::new(7, 4)));
+        assert_eq!(3, sweep.coverage(&Point::new(4, 4)));
+        assert_eq!(
+            vec![
+                CoverageRun {
+                    segment: LineSegment::new(Point::new(0, 9), Point::new(2, 9)),
+                    count: 2
+                },
+                CoverageRun {
+                    segment: LineSegment::new(Point::new(3, 4), Point::new(3, 4)),
+                    count: 2
+                }
+            ],
+            sweep
+                .overlaps()
+                .sorted_by_key(|run| run.segment.start.x)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn sweep_matches_rasterization() {
+        // A small LCG keeps this deterministic without pulling in a rand dependency.
+        let mut seed: u64 = 12345;
+        let mut next = |bound: i32| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % bound as u64) as i32
+        };
+
+        let segments = (0..200)
+            .map(|_| {
+                let start = Point::new(next(40), next(40));
+                let length = next(15);
+                let delta = match next(4) {
+                    0 => Point::new(length, 0),
+                    1 => Point::new(0, -length),
+                    2 => Point::new(-length, -length),
+                    _ => Point::new(length, -length),
+                };
+                LineSegment::new(start, start + delta)
+            })
+            .collect::<Vec<_>>();
+
+        let expected = brute_force_coverage(&segments);
+        let sweep = SegmentSweep::new(&segments).unwrap();
+
+        for min_coverage in 1..=4 {
+            assert_eq!(
+                expected.values().filter(|&&c| c >= min_coverage).count() as u64,
+                sweep.count_covered(min_coverage)
+            );
+        }
+        for (point, count) in &expected {
+            assert_eq!(*count, sweep.coverage(point), "at {}", point);
+        }
+        for (point, count) in sweep.crossings() {
+            assert_eq!(expected[&point], count);
+        }
+    }
+
+    #[test]
+    fn sweep_long_segments() {
+        let segments = vec![
+            LineSegment::new(Point::new(0, 0), Point::new(10_000_000, 0)),
+            LineSegment::new(Point::new(5_000_000, -1), Point::new(15_000_000, -1)),
+            LineSegment::new(Point::new(5_000_000, 0), Point::new(15_000_000, 0)),
+            LineSegment::new(Point::new(7, -3_000_000), Point::new(7, 3_000_000)),
+            LineSegment::new(Point::new(-1, -1), Point::new(2, 2)),
+        ];
+
+        let sweep = SegmentSweep::new(&segments).unwrap();
+
+        // 5,000,001 collinear points, plus the vertical and diagonal crossings at (7, 0) and
+        // (0, 0).
+        assert_eq!(5_000_001 + 2, sweep.count_covered(2));
+        assert_eq!(2, sweep.coverage(&Point::new(0, 0)));
+        assert_eq!(2, sweep.coverage(&Point::new(7, 0)));
+        assert_eq!(1, sweep.coverage(&Point::new(7, -1)));
+    }
+
+    #[test]
+    fn sweep_rejects_arbitrary_slopes() {
+        let segments = vec![LineSegment::new(Point::new(0, 0), Point::new(2, 1))];
+
+        assert!(SegmentSweep::new(&segments).is_err());
+    }
+
+    #[test]
+    fn diagonals_only_cross_on_lattice_points() {
+        let segments = vec![
+            LineSegment::new(Point::new(0, 0), Point::new(1, 1)),
+            LineSegment::new(Point::new(0, 1), Point::new(1, 0)),
+        ];
+
+        let sweep = SegmentSweep::new(&segments).unwrap();
+
+        assert_eq!(0, sweep.count_covered(2));
+        assert_eq!(0, sweep.crossings().count());
+    }
 }
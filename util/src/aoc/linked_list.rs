@@ -1,7 +1,12 @@
+use std::iter::FromIterator;
 use std::mem;
 use std::ops::{Index, IndexMut};
 
 /// A doubly-linked list that lets you actually do O(1) insertion.
+///
+/// Nodes are addressed by the `Idx` returned when they're inserted, which stays valid until that
+/// node is removed. The list has a head and a tail, but the `*_circular` and `step` methods wrap
+/// around from one to the other, so it can also be treated as a ring.
 pub struct UsefulLinkedList<T> {
     // There's probably a nice slab allocator out there.
     nodes: Vec<Option<Node<T>>>,
@@ -9,21 +14,34 @@ pub struct UsefulLinkedList<T> {
     free: Vec<Idx>,
     head: Option<Idx>,
     tail: Option<Idx>,
+    len: usize,
 }
 
-impl<T> UsefulLinkedList<T>
-where
-    T: std::fmt::Debug,
-{
+impl<T> Default for UsefulLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> UsefulLinkedList<T> {
     pub fn new() -> UsefulLinkedList<T> {
         UsefulLinkedList {
             nodes: Vec::new(),
             free: Vec::new(),
             head: None,
             tail: None,
+            len: 0,
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub fn head(&self) -> Option<Idx> {
         self.head
     }
@@ -32,6 +50,20 @@ where
         self.tail
     }
 
+    pub fn get(&self, node_index: Idx) -> Option<&T> {
+        self.nodes
+            .get(node_index.0)
+            .and_then(|node| node.as_ref())
+            .map(|node| &node.value)
+    }
+
+    pub fn get_mut(&mut self, node_index: Idx) -> Option<&mut T> {
+        self.nodes
+            .get_mut(node_index.0)
+            .and_then(|node| node.as_mut())
+            .map(|node| &mut node.value)
+    }
+
     pub fn next(&self, node_index: Idx) -> Option<Idx> {
         self[node_index].next
     }
@@ -40,73 +72,215 @@ where
         self[node_index].prev
     }
 
+    /// The node after this one, wrapping around from the tail to the head.
+    pub fn next_circular(&self, node_index: Idx) -> Idx {
+        match self[node_index].next {
+            Some(next) => next,
+            None => self.head.unwrap(),
+        }
+    }
+
+    /// The node before this one, wrapping around from the head to the tail.
+    pub fn prev_circular(&self, node_index: Idx) -> Idx {
+        match self[node_index].prev {
+            Some(prev) => prev,
+            None => self.tail.unwrap(),
+        }
+    }
+
+    /// Moves `steps` nodes forwards (or backwards, if negative) around the ring. Full laps are
+    /// skipped, and the shorter direction is taken, so this walks at most `len / 2` nodes.
+    pub fn step(&self, node_index: Idx, steps: isize) -> Idx {
+        let len = self.len as isize;
+        let forwards = steps.rem_euclid(len);
+
+        let mut idx = node_index;
+        if forwards <= len / 2 {
+            for _ in 0..forwards {
+                idx = self.next_circular(idx);
+            }
+        } else {
+            for _ in 0..len - forwards {
+                idx = self.prev_circular(idx);
+            }
+        }
+
+        idx
+    }
+
     pub fn push_back(&mut self, item: T) -> Idx {
         match self.tail {
             Some(t) => self.insert_after(t, item),
-            None => {
-                let node = Node {
-                    value: item,
-                    next: None,
-                    prev: None,
-                };
-                let idx = self.next_node_idx();
-                self.nodes[idx.0] = Some(node);
-                self.tail = Some(idx);
-                if self.head.is_none() {
-                    self.head = Some(idx);
-                }
-
-                idx
-            }
+            None => self.push_first(item),
         }
     }
 
+    pub fn push_front(&mut self, item: T) -> Idx {
+        match self.head {
+            Some(h) => self.insert_before(h, item),
+            None => self.push_first(item),
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| self.remove(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| self.remove(tail))
+    }
+
+    fn push_first(&mut self, item: T) -> Idx {
+        let idx = self.allocate(item);
+        self.head = Some(idx);
+        self.tail = Some(idx);
+
+        idx
+    }
+
     pub fn insert_after(&mut self, node_index: Idx, item: T) -> Idx {
-        let new_node_idx = self.next_node_idx();
+        let new_node_idx = self.allocate(item);
+        self.link_after(node_index, new_node_idx);
 
-        let new_node = Node {
-            value: item,
-            next: self[node_index].next,
-            prev: Some(node_index),
-        };
-        self.nodes[new_node_idx.0] = Some(new_node);
+        new_node_idx
+    }
 
-        if let Some(next) = self[node_index].next {
-            self[next].prev = Some(new_node_idx);
+    pub fn insert_before(&mut self, node_index: Idx, item: T) -> Idx {
+        let new_node_idx = self.allocate(item);
+        self.link_before(node_index, new_node_idx);
+
+        new_node_idx
+    }
+
+    /// Splices a node out of its current position and back in just after `target`, without
+    /// reallocating it; its `Idx` stays the same.
+    pub fn move_after(&mut self, node_index: Idx, target: Idx) {
+        if node_index == target {
+            panic!("Can't move a node after itself");
         }
 
-        self[node_index].next = Some(new_node_idx);
+        self.unlink(node_index);
+        self.link_after(target, node_index);
+    }
 
-        if self.tail == Some(node_index) {
-            self.tail = Some(new_node_idx)
+    /// Splices a node out of its current position and back in just before `target`, without
+    /// reallocating it; its `Idx` stays the same.
+    pub fn move_before(&mut self, node_index: Idx, target: Idx) {
+        if node_index == target {
+            panic!("Can't move a node before itself");
         }
 
-        new_node_idx
+        self.unlink(node_index);
+        self.link_before(target, node_index);
     }
 
     pub fn remove(&mut self, node_index: Idx) -> T {
+        self.unlink(node_index);
+
         let mut removed_node: Option<Node<T>> = None;
         mem::swap(&mut removed_node, &mut self.nodes[node_index.0]);
         self.free.push(node_index);
+        self.len -= 1;
+
+        removed_node.unwrap().value
+    }
+
+    /// Iterates over the values from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    /// Iterates over the node indices from head to tail.
+    pub fn indices(&self) -> impl Iterator<Item = Idx> + '_ {
+        std::iter::successors(self.head, move |&idx| self[idx].next)
+    }
 
-        let removed_node = removed_node.unwrap();
+    /// Iterates once around the ring, starting at `node_index` and wrapping from the tail to the
+    /// head.
+    pub fn iter_circular_from(&self, node_index: Idx) -> impl Iterator<Item = (Idx, &T)> + '_ {
+        let mut idx = node_index;
+        (0..self.len).map(move |_| {
+            let current = idx;
+            idx = self.next_circular(idx);
+            (current, &self[current].value)
+        })
+    }
+
+    fn allocate(&mut self, item: T) -> Idx {
+        let idx = self.next_node_idx();
+        self.nodes[idx.0] = Some(Node {
+            value: item,
+            next: None,
+            prev: None,
+        });
+        self.len += 1;
+
+        idx
+    }
+
+    /// Detaches a node from its neighbours, leaving it allocated but unreachable.
+    fn unlink(&mut self, node_index: Idx) {
+        let (prev, next) = {
+            let node = &self[node_index];
+            (node.prev, node.next)
+        };
 
         if Some(node_index) == self.head {
-            self.head = removed_node.next
+            self.head = next
         }
 
         if Some(node_index) == self.tail {
-            self.tail = removed_node.prev
+            self.tail = prev
+        }
+
+        if let Some(prev) = prev {
+            self[prev].next = next;
+        }
+        if let Some(next) = next {
+            self[next].prev = prev;
         }
 
-        if let Some(prev) = removed_node.prev {
-            self[prev].next = removed_node.next;
+        self[node_index].prev = None;
+        self[node_index].next = None;
+    }
+
+    /// Attaches a detached node just after `node_index`.
+    fn link_after(&mut self, node_index: Idx, new_node_idx: Idx) {
+        let next = self[node_index].next;
+        self[new_node_idx].prev = Some(node_index);
+        self[new_node_idx].next = next;
+
+        if let Some(next) = next {
+            self[next].prev = Some(new_node_idx);
+        }
+
+        self[node_index].next = Some(new_node_idx);
+
+        if self.tail == Some(node_index) {
+            self.tail = Some(new_node_idx)
         }
-        if let Some(next) = removed_node.next {
-            self[next].prev = removed_node.prev;
+    }
+
+    /// Attaches a detached node just before `node_index`.
+    fn link_before(&mut self, node_index: Idx, new_node_idx: Idx) {
+        let prev = self[node_index].prev;
+        self[new_node_idx].next = Some(node_index);
+        self[new_node_idx].prev = prev;
+
+        if let Some(prev) = prev {
+            self[prev].next = Some(new_node_idx);
         }
 
-        removed_node.value
+        self[node_index].prev = Some(new_node_idx);
+
+        if self.head == Some(node_index) {
+            self.head = Some(new_node_idx)
+        }
     }
 
     fn next_node_idx(&mut self) -> Idx {
@@ -160,40 +334,107 @@ impl<T> IndexMut<Idx> for UsefulLinkedList<T> {
         }
     }
 }
-//
-//impl<T> IntoIterator for UsefulLinkedList<T> {
-//    type Item = T;
-//    type IntoIter = UsefulLinkedListIterator<T>;
-//
-//    fn into_iter(self) -> Self::IntoIter {
-//        UsefulLinkedListIterator {
-//            index: self.head.clone(),
-//            linked_list: self,
-//        }
-//    }
-//}
-//
-//struct UsefulLinkedListIterator<T> {
-//    linked_list: UsefulLinkedList<T>,
-//    index: Option<Idx>
-//}
-//
-//impl<T> Iterator for UsefulLinkedListIterator<T> {
-//    type Item = T;
-//
-//    fn next(&mut self) -> Option<Self::Item> {
-//        match self.index {
-//            Some(idx) => {
-//                let result = &self.linked_list[idx];
-//                self.index = result.next;
-//                Some(result.value)
-//            },
-//            None => None,
-//        }
-//    }
-//}
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+
+impl<T> FromIterator<T> for UsefulLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(it: I) -> UsefulLinkedList<T> {
+        let mut list = UsefulLinkedList::new();
+        list.extend(it);
+        list
+    }
+}
+
+impl<T> Extend<T> for UsefulLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, it: I) {
+        for item in it {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for UsefulLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { linked_list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UsefulLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a UsefulLinkedList<T>,
+    front: Option<Idx>,
+    back: Option<Idx>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = &self.list[self.front?];
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = &self.list[self.back?];
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IntoIter<T> {
+    linked_list: UsefulLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.linked_list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.linked_list.len, Some(self.linked_list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.linked_list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Idx(usize);
 
 #[derive(Debug)]
@@ -202,21 +443,109 @@ pub struct Node<T> {
     pub next: Option<Idx>,
     pub prev: Option<Idx>,
 }
-//
-//mod test {
-//    use super::*;
-//
-//    #[test]
-//    fn insertion() {
-//        let mut ll = UsefulLinkedList::new();
-//
-//        ll.push_back(1);
-//        let two = ll.push_back(2);
-//        ll.push_back(4);
-//        ll.insert_after(two, 3);
-//
-//        let result: Vec<i32> = ll.into_iter().collect();
-//
-//        assert_eq!(vec![1,2,3,4], result);
-//    }
-//}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insertion() {
+        let mut ll = UsefulLinkedList::new();
+
+        ll.push_back(1);
+        let two = ll.push_back(2);
+        ll.push_back(4);
+        ll.insert_after(two, 3);
+
+        let result: Vec<i32> = ll.into_iter().collect();
+
+        assert_eq!(vec![1, 2, 3, 4], result);
+    }
+
+    #[test]
+    fn insert_before_and_push_front() {
+        let mut ll = UsefulLinkedList::new();
+
+        let three = ll.push_back(3);
+        ll.insert_before(three, 2);
+        ll.push_front(1);
+        ll.push_front(0);
+
+        assert_eq!(4, ll.len());
+        assert_eq!(vec![&0, &1, &2, &3], ll.iter().collect::<Vec<_>>());
+        assert_eq!(vec![&3, &2, &1, &0], ll.iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn removal_reuses_nodes() {
+        let mut ll: UsefulLinkedList<i32> = (1..=5).collect();
+
+        let head = ll.head().unwrap();
+        let tail = ll.tail().unwrap();
+        assert_eq!(1, ll.remove(head));
+        assert_eq!(5, ll.remove(tail));
+        let six = ll.push_back(6);
+
+        assert!(six == head || six == tail);
+        assert_eq!(Some(&6), ll.get(six));
+        assert_eq!(4, ll.len());
+        assert_eq!(vec![2, 3, 4, 6], ll.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn empty() {
+        let mut ll: UsefulLinkedList<i32> = UsefulLinkedList::new();
+
+        assert!(ll.is_empty());
+        assert_eq!(None, ll.iter().next());
+        assert_eq!(None, ll.pop_front());
+
+        let only = ll.push_back(1);
+        assert_eq!(Some(1), ll.pop_back());
+        assert!(ll.is_empty());
+        assert_eq!(None, ll.get(only));
+        assert_eq!(None, ll.head());
+        assert_eq!(None, ll.tail());
+    }
+
+    #[test]
+    fn circular_movement() {
+        let mut ll = UsefulLinkedList::new();
+        let indices = (0..7).map(|i| ll.push_back(i)).collect::<Vec<_>>();
+
+        assert_eq!(indices[0], ll.next_circular(indices[6]));
+        assert_eq!(indices[6], ll.prev_circular(indices[0]));
+        assert_eq!(indices[3], ll.step(indices[1], 2));
+        assert_eq!(indices[5], ll.step(indices[1], -3));
+        assert_eq!(indices[2], ll.step(indices[1], 15));
+        assert_eq!(indices[1], ll.step(indices[1], -14));
+        assert_eq!(
+            vec![5, 6, 0, 1, 2, 3, 4],
+            ll.iter_circular_from(indices[5])
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn splicing() {
+        let mut ll = UsefulLinkedList::new();
+        let indices = (0..5).map(|i| ll.push_back(i)).collect::<Vec<_>>();
+
+        ll.move_after(indices[0], indices[4]);
+        assert_eq!(vec![&1, &2, &3, &4, &0], ll.iter().collect::<Vec<_>>());
+        assert_eq!(Some(indices[0]), ll.tail());
+
+        ll.move_before(indices[4], indices[1]);
+        assert_eq!(vec![&4, &1, &2, &3, &0], ll.iter().collect::<Vec<_>>());
+        assert_eq!(Some(indices[4]), ll.head());
+
+        ll.move_after(indices[2], indices[3]);
+        assert_eq!(vec![&4, &1, &3, &2, &0], ll.iter().collect::<Vec<_>>());
+        assert_eq!(
+            vec![&0, &2, &3, &1, &4],
+            ll.iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(5, ll.len());
+    }
+}
@@ -0,0 +1,324 @@
+use num_traits::{PrimInt, Signed};
+
+/// The greatest common divisor, which is never negative.
+pub fn gcd<T: PrimInt + Signed>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::zero() {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// The least common multiple, which is never negative.
+pub fn lcm<T: PrimInt + Signed>(a: T, b: T) -> T {
+    if a == T::zero() || b == T::zero() {
+        T::zero()
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// The least common multiple of every value, or 1 if there are none.
+pub fn lcm_all<T: PrimInt + Signed>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), lcm)
+}
+
+/// The extended Euclidean algorithm. Returns `(g, x, y)` where `g = gcd(a, b)` and
+/// `a * x + b * y = g`.
+pub fn egcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while r != T::zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a` reduced into `0..m`, for positive `m`.
+pub fn modulo<T: PrimInt + Signed>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::zero() {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// The `x` in `0..m` with `a * x = 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = egcd(modulo(a, m), m);
+    if g == T::one() {
+        Some(modulo(x, m))
+    } else {
+        None
+    }
+}
+
+/// `a * b mod m`, without overflowing even when the product wouldn't fit in `T`.
+pub fn mulmod<T: PrimInt + Signed>(a: T, b: T, m: T) -> T {
+    let (a, b) = (modulo(a, m), modulo(b, m));
+
+    // Anything up to 64 bits can be multiplied exactly in an i128.
+    if let (Some(a), Some(b), Some(m)) = (a.to_i64(), b.to_i64(), m.to_i64()) {
+        return T::from((a as i128 * b as i128) % m as i128).unwrap();
+    }
+
+    // Otherwise double-and-add, which only needs room for `2 * m`.
+    let (mut a, mut b) = (a, b);
+    let mut product = T::zero();
+    while b > T::zero() {
+        if b & T::one() == T::one() {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b >> 1;
+    }
+    product
+}
+
+fn add_mod<T: PrimInt + Signed>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base ^ exp mod m`, by repeated squaring.
+pub fn modpow<T: PrimInt + Signed>(base: T, exp: T, m: T) -> T {
+    assert!(exp >= T::zero(), "Negative exponent");
+
+    let (mut base, mut exp) = (modulo(base, m), exp);
+    let mut result = modulo(T::one(), m);
+    while exp > T::zero() {
+        if exp & T::one() == T::one() {
+            result = mulmod(result, base, m);
+        }
+        base = mulmod(base, base, m);
+        exp = exp >> 1;
+    }
+    result
+}
+
+/// Solves a system of congruences `x = residue (mod modulus)`, which needn't have pairwise coprime
+/// moduli. Returns `(x, lcm)` with `x` in `0..lcm` and `lcm` the lcm of the moduli, or `None` if
+/// the congruences contradict each other.
+pub fn crt<T: PrimInt + Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(x, m), &(residue, modulus)| {
+            let residue = modulo(residue, modulus);
+            let (g, _, _) = egcd(m, modulus);
+            let difference = residue - x;
+            if difference % g != T::zero() {
+                return None;
+            }
+
+            // Solve x + m * k = residue (mod modulus) for k, which is unique modulo modulus / g.
+            let reduced = modulus / g;
+            let k = if reduced == T::one() {
+                T::zero()
+            } else {
+                mulmod(difference / g, mod_inverse(m / g, reduced)?, reduced)
+            };
+
+            let lcm = m * reduced;
+            Some((add_mod(x, mulmod(m, k, lcm), lcm), lcm))
+        })
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt<T: PrimInt>(n: T) -> T {
+    assert!(n >= T::zero(), "Square root of a negative number");
+
+    // Start from the floating point estimate, then correct its rounding.
+    let mut r = T::from(n.to_f64().unwrap().sqrt()).unwrap_or_else(T::max_value);
+    while r.checked_mul(&r).is_none_or(|square| square > n) {
+        r = r - T::one();
+    }
+    while (r + T::one())
+        .checked_mul(&(r + T::one()))
+        .is_some_and(|square| square <= n)
+    {
+        r = r + T::one();
+    }
+    r
+}
+
+/// Deterministic Miller-Rabin, exact for every `n` below 318665857834031151167461 (about
+/// 3.19 * 10^23), which covers every `i64` but not every `i128`.
+pub fn is_prime<T: PrimInt + Signed>(n: T) -> bool {
+    const WITNESSES: [i64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < T::from(2).unwrap() {
+        return false;
+    }
+    for &p in &WITNESSES {
+        let p = match T::from(p) {
+            Some(p) => p,
+            None => return true,
+        };
+        if n == p {
+            return true;
+        }
+        if n % p == T::zero() {
+            return false;
+        }
+    }
+
+    let one = T::one();
+    let n_minus_one = n - one;
+    let trailing = n_minus_one.trailing_zeros();
+    let d = n_minus_one >> trailing as usize;
+
+    WITNESSES.iter().all(|&a| {
+        let mut x = modpow(T::from(a).unwrap(), d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..trailing {
+            x = mulmod(x, x, n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// The prime factors of a positive `n` with their multiplicities, smallest first, by trial
+/// division.
+pub fn factorize<T: PrimInt + Signed>(n: T) -> Vec<(T, u32)> {
+    assert!(n > T::zero(), "Can only factorize positive numbers");
+
+    let mut n = n;
+    let mut factors = Vec::new();
+    let mut divide_out = |n: &mut T, p: T| {
+        let mut count = 0;
+        while *n % p == T::zero() {
+            *n = *n / p;
+            count += 1;
+        }
+        if count > 0 {
+            factors.push((p, count));
+        }
+    };
+
+    let two = T::from(2).unwrap();
+    let six = T::from(6).unwrap();
+    divide_out(&mut n, two);
+    divide_out(&mut n, two + T::one());
+
+    // Every other prime is one either side of a multiple of six.
+    let mut p = six - T::one();
+    while p <= n / p {
+        divide_out(&mut n, p);
+        divide_out(&mut n, p + two);
+        p = p + six;
+    }
+    if n > T::one() {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(36, lcm(-12, 18));
+        assert_eq!(0, lcm(0, 18));
+
+        // 2019 day 12: the sample's axes repeat after 18, 28 and 44 steps.
+        assert_eq!(2772, lcm_all(vec![18i64, 28, 44]));
+        assert_eq!(1, lcm_all(Vec::<i64>::new()));
+    }
+
+    #[test]
+    fn extended_gcd_and_inverse() {
+        let (g, x, y) = egcd(240i64, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+
+        assert_eq!(Some(4), mod_inverse(3i64, 11));
+        assert_eq!(Some(7), mod_inverse(-3i64, 11));
+        assert_eq!(None, mod_inverse(4i64, 8));
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(3, modulo(-7, 5));
+        assert_eq!(445, modpow(4i64, 13, 497));
+        assert_eq!(0, modpow(5i64, 0, 1));
+
+        let m = i64::MAX - 24;
+        assert_eq!(
+            ((i64::MAX as i128 - 1) * (i64::MAX as i128 - 2) % m as i128) as i64,
+            mulmod(i64::MAX - 1, i64::MAX - 2, m)
+        );
+
+        let big = i128::MAX - 158;
+        assert_eq!(
+            mulmod(modpow(3, 100, big), modpow(3, 100, big), big),
+            modpow(3, 200, big)
+        );
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        // 2020 day 13: buses 17,x,13,19 first depart on consecutive minutes at 3417.
+        let buses = [(0, 17), (-2, 13), (-3, 19)];
+        assert_eq!(Some((3417, 17 * 13 * 19)), crt(&buses));
+
+        // Moduli sharing factors still combine when consistent.
+        assert_eq!(Some((10i64, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1i64, 6), (2, 4)]));
+        assert_eq!(Some((0i64, 1)), crt(&[]));
+    }
+
+    #[test]
+    fn square_roots() {
+        assert_eq!(0, isqrt(0));
+        assert_eq!(3, isqrt(15));
+        assert_eq!(4, isqrt(16));
+        assert_eq!(3_037_000_499, isqrt(i64::MAX));
+        assert_eq!(u64::MAX >> 32, isqrt(u64::MAX));
+        assert_eq!(10i128.pow(18), isqrt(10i128.pow(36) + 1));
+    }
+
+    #[test]
+    fn primes() {
+        let small = (0i64..50).filter(|&n| is_prime(n)).collect::<Vec<_>>();
+        assert_eq!(
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47],
+            small
+        );
+        assert!(is_prime(1_000_000_007i64));
+        assert!(!is_prime(3_215_031_751i64));
+        assert!(is_prime(i64::MAX as i128 * 2 + 1 - 58));
+    }
+
+    #[test]
+    fn factors() {
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], factorize(360));
+        assert_eq!(vec![(1_000_000_007i64, 1)], factorize(1_000_000_007));
+        assert!(factorize(1i64).is_empty());
+    }
+}
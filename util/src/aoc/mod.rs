@@ -1,21 +1,41 @@
-use failure::bail;
+use crate::bail;
 use itertools::Itertools;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, AddAssign, Mul, Sub};
 use std::str::FromStr;
 use std::time::Instant;
 
+pub mod algebra;
+pub mod answers;
 pub mod astar;
+pub mod bench;
+pub mod boxes;
+pub mod compression;
+pub mod cycles;
 pub mod digits;
+pub mod error;
+pub mod examples;
 pub mod expanding_grid;
+pub mod fetch;
 pub mod frequency;
 pub mod grid;
 pub mod input;
+pub mod intervals;
 pub mod lines;
 pub mod linked_list;
+pub mod math;
+pub mod parsers;
+pub mod parsing;
+pub mod ring;
+pub mod search;
+pub mod solution;
+pub mod statistics;
 pub mod top_k;
 
-pub type AocResult<T> = std::result::Result<T, failure::Error>;
+pub use error::AocError;
+pub use solution::Solution;
+
+pub type AocResult<T> = std::result::Result<T, AocError>;
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Point {
@@ -106,7 +126,7 @@ impl Display for Point {
 }
 
 impl FromStr for Point {
-    type Err = failure::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let vec = s.split(",").filter(|v| !v.is_empty()).collect_vec();
@@ -173,7 +193,7 @@ impl Iterator for PointIterator {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let value = self.start.clone();
+        let value = self.start;
         self.start += self.delta;
         Some(value)
     }
@@ -183,7 +203,6 @@ pub fn time<S, F, T>(label: S, function: F) -> T
 where
     F: Fn() -> T,
     S: ToString + Display,
-    T: Debug,
 {
     let start_time = Instant::now();
     let result: T = function();
@@ -204,14 +223,25 @@ where
 }
 
 pub trait ParseAs {
-    fn parse<F: FromStr>(&self) -> Result<Vec<F>, F::Err>;
+    /// Parses each element as one line, reporting every element that fails as
+    /// `parsing::parse_lines` does.
+    fn parse<F>(&self) -> AocResult<Vec<F>>
+    where
+        F: FromStr,
+        F::Err: Display;
 }
 
 impl<T: AsRef<str>> ParseAs for Vec<T> {
-    fn parse<F: FromStr>(&self) -> Result<Vec<F>, <F as FromStr>::Err> {
-        self.iter()
-            .map(|val| FromStr::from_str(val.as_ref()))
-            .collect()
+    fn parse<F>(&self) -> AocResult<Vec<F>>
+    where
+        F: FromStr,
+        F::Err: Display,
+    {
+        parsing::collect(self.iter().enumerate().map(|(index, line)| {
+            let line = line.as_ref();
+            line.parse()
+                .map_err(|e| parsing::Diagnostic::for_line(index + 1, line, e))
+        }))
     }
 }
 
@@ -262,3 +292,22 @@ where
         self.next().map(|first| self.fold(first, f))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_element_as_a_line() {
+        assert_eq!(vec!["1", "-2"].parse::<i32>().unwrap(), [1, -2]);
+
+        match vec!["1", ""].parse::<i32>().unwrap_err() {
+            AocError::Diagnostics(diagnostics) => {
+                assert_eq!(diagnostics.len(), 1);
+                assert_eq!(diagnostics[0].line, 2);
+            }
+            other => panic!("Expected diagnostics, got {:?}", other),
+        }
+        assert!(vec!["1\n2"].parse::<i32>().is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! `nom` parsers for the shapes puzzle inputs usually come in. Each is an ordinary `nom`
+//! combinator, and `parse_all` runs one over a whole input:
+//!
+//! ```ignore
+//! let items: Vec<u64> = parse_all(labeled("Starting items", comma_separated(unsigned)), input)?;
+//! ```
+
+use crate::aoc::parsing::Diagnostic;
+use crate::aoc::{AocError, AocResult};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, line_ending, multispace0, one_of, space0};
+use nom::combinator::{cut, map, map_res, opt, recognize};
+use nom::error::Error;
+use nom::multi::{many1, separated_list0, separated_list1};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::{IResult, Parser};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Digits read as any integer type, e.g. `unsigned::<u8>` or `unsigned::<usize>`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Digits with an optional leading `+` or `-`, read as any integer type.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more items separated by `separator`, which may have spaces around it.
+pub fn separated<'a, T, S, O, F>(
+    separator: S,
+    item: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    S: Parser<&'a str, O, Error<&'a str>>,
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    separated_list1(delimited(space0, separator, space0), item)
+}
+
+/// One or more items separated by commas, as in `79, 98` or `1,2,3`.
+pub fn comma_separated<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    separated(char(','), item)
+}
+
+/// A value after `label:`, as in `Starting items: 79, 98`. Any whitespace before the label,
+/// including line breaks, is skipped so fields on consecutive lines can follow one another.
+pub fn labeled<'a, T, F>(label: &'a str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, T>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    preceded(tuple((multispace0, tag(label), char(':'), space0)), value)
+}
+
+/// One item per line.
+pub fn lines<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// Items separated by one or more blank lines, where each item may span several lines.
+pub fn blocks<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    separated_list1(
+        pair(line_ending, many1(preceded(space0, line_ending))),
+        item,
+    )
+}
+
+/// A value or a bracketed list of nested values, as in `[1,[2,[3]],[]]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Nested<T> {
+    Item(T),
+    List(Vec<Nested<T>>),
+}
+
+/// An item or bracketed, comma-separated list of nested values, with lists nesting to any depth.
+pub fn nested<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Nested<T>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, T> + Copy,
+{
+    move |input| nested_value(input, item)
+}
+
+/// A bracketed, comma-separated list of nested values.
+pub fn bracketed<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Nested<T>>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, T> + Copy,
+{
+    move |input| bracketed_list(input, item)
+}
+
+fn nested_value<'a, T, F>(input: &'a str, item: F) -> IResult<&'a str, Nested<T>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, T> + Copy,
+{
+    alt((
+        map(move |i| bracketed_list(i, item), Nested::List),
+        map(item, Nested::Item),
+    ))(input)
+}
+
+fn bracketed_list<'a, T, F>(input: &'a str, item: F) -> IResult<&'a str, Vec<Nested<T>>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, T> + Copy,
+{
+    preceded(
+        char('['),
+        cut(terminated(
+            separated_list0(
+                delimited(space0, char(','), space0),
+                preceded(space0, move |i| nested_value(i, item)),
+            ),
+            preceded(space0, char(']')),
+        )),
+    )(input)
+}
+
+/// Runs `parser` over the whole input, failing if anything but whitespace is left over. Failures
+/// point at where parsing stopped.
+pub fn parse_all<'a, T, P>(mut parser: P, input: &'a str) -> AocResult<T>
+where
+    P: Parser<&'a str, T, Error<&'a str>>,
+{
+    match parser.parse(input) {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(stopped_at(input, rest, "Unexpected trailing input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(stopped_at(
+            input,
+            e.input,
+            format!("Expected {}", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse("Unexpected end of input")),
+    }
+}
+
+/// A diagnostic underlining the rest of the line where `rest`, a suffix of `input`, begins.
+fn stopped_at(input: &str, rest: &str, message: impl Display) -> AocError {
+    let offset = input.len() - rest.len();
+    let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let source = input[line_start..].lines().next().unwrap_or_default();
+    let span = &input[offset..line_start + source.len()];
+    let line = input[..offset].matches('\n').count() + 1;
+
+    AocError::Diagnostics(vec![Diagnostic::new(line, source, span, message)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_of_any_width() {
+        assert_eq!(unsigned::<u8>("255 left"), Ok((" left", 255)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(unsigned::<u32>("-1").is_err());
+        assert_eq!(signed::<i64>("-12,"), Ok((",", -12)));
+        assert_eq!(signed::<i8>("+7"), Ok(("", 7)));
+        assert!(signed::<i32>("-").is_err());
+    }
+
+    #[test]
+    fn parses_separated_lists() {
+        assert_eq!(
+            comma_separated(signed::<i32>)("1, -2 ,3;4"),
+            Ok((";4", vec![1, -2, 3]))
+        );
+        assert_eq!(
+            separated(tag("->"), unsigned::<u32>)("1 -> 2->3"),
+            Ok(("", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            lines(unsigned::<u32>)("1\n2\r\n3\n"),
+            Ok(("\n", vec![1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn parses_labeled_fields() {
+        let mut monkey = tuple((
+            labeled("Starting items", comma_separated(unsigned::<u64>)),
+            labeled("Test", preceded(tag("divisible by "), unsigned::<u64>)),
+        ));
+
+        assert_eq!(
+            monkey("  Starting items: 79, 98\n  Test: divisible by 23"),
+            Ok(("", (vec![79, 98], 23)))
+        );
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let input = "1\n2\n\n3\n \n\n4,5";
+        assert_eq!(
+            blocks(lines(comma_separated(unsigned::<u32>)))(input),
+            Ok((
+                "",
+                vec![vec![vec![1], vec![2]], vec![vec![3]], vec![vec![4, 5]]]
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_nested_lists() {
+        use Nested::{Item, List};
+
+        assert_eq!(
+            nested(unsigned::<u32>)("[1,[2, [3]],[]]"),
+            Ok((
+                "",
+                List(vec![
+                    Item(1),
+                    List(vec![Item(2), List(vec![Item(3)])]),
+                    List(vec![])
+                ])
+            ))
+        );
+        assert_eq!(nested(unsigned::<u32>)("4"), Ok(("", Item(4))));
+        assert_eq!(bracketed(unsigned::<u32>)("[]"), Ok(("", vec![])));
+        assert!(nested(unsigned::<u32>)("[1,[2]").is_err());
+    }
+
+    #[test]
+    fn parse_all_rejects_trailing_input() {
+        assert_eq!(parse_all(lines(unsigned::<u32>), "1\n2\n").unwrap(), [1, 2]);
+
+        let error = parse_all(lines(unsigned::<u32>), "1\n2\n3x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 2: Unexpected trailing input\n \
+             3 | 3x\n   \
+               |  ^"
+        );
+    }
+
+    #[test]
+    fn parse_all_points_at_failures() {
+        let error = parse_all(nested(unsigned::<u32>), "[1,[2,x]]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 1, column 6: Expected Char\n \
+             1 | [1,[2,x]]\n   \
+               |      ^^^^"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! Parses input line by line, reporting every line that fails rather than only the first, each
+//! with its 1-based line number and a caret under the offending text:
+//!
+//! ```text
+//! Line 3, column 4: invalid digit found in string
+//!  3 | 12,4x,7
+//!    |    ^^
+//! ```
+
+use crate::aoc::{AocError, AocResult};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// At most this many diagnostics are shown; the rest are only counted.
+const MAX_SHOWN: usize = 10;
+
+/// Where and why the input failed to parse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The line number, counting from 1.
+    pub line: usize,
+    /// The column in characters, counting from 1.
+    pub column: usize,
+    /// How many characters to underline.
+    pub width: usize,
+    /// The text of the whole line.
+    pub source: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// A diagnostic underlining `span`, which must be a slice of `source`.
+    pub fn new(line: usize, source: &str, span: &str, message: impl Display) -> Diagnostic {
+        let start = (span.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&start| start + span.len() <= source.len())
+            .unwrap_or(0);
+
+        Diagnostic {
+            line,
+            column: source[..start].chars().count() + 1,
+            width: span.chars().count().max(1),
+            source: source.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// A diagnostic underlining the whole line, less surrounding whitespace.
+    pub fn for_line(line: usize, source: &str, message: impl Display) -> Diagnostic {
+        Diagnostic::new(line, source, source.trim(), message)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "Line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, " {} | {}", self.line, self.source)?;
+        write!(
+            f,
+            " {:gutter$} | {:indent$}{}",
+            "",
+            "",
+            "^".repeat(self.width),
+            gutter = gutter,
+            indent = self.column - 1
+        )
+    }
+}
+
+/// Renders diagnostics one after another, eliding all but the first few.
+pub(crate) fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut shown = diagnostics
+        .iter()
+        .take(MAX_SHOWN)
+        .map(Diagnostic::to_string)
+        .collect::<Vec<_>>();
+    if diagnostics.len() > MAX_SHOWN {
+        shown.push(format!(
+            "... and {} more errors",
+            diagnostics.len() - MAX_SHOWN
+        ));
+    }
+    shown.join("\n")
+}
+
+/// The results, or every failure if there were any.
+pub(crate) fn collect<T>(
+    results: impl Iterator<Item = Result<T, Diagnostic>>,
+) -> AocResult<Vec<T>> {
+    let mut values = Vec::new();
+    let mut diagnostics = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(values)
+    } else {
+        Err(AocError::Diagnostics(diagnostics))
+    }
+}
+
+/// Parses each line with `FromStr`.
+pub fn parse_lines<T>(input: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_lines_with(input, str::parse)
+}
+
+/// Parses each line with `parse`.
+pub fn parse_lines_with<T, E, F>(input: &str, mut parse: F) -> AocResult<Vec<T>>
+where
+    E: Display,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    collect(
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse(line).map_err(|e| Diagnostic::for_line(index + 1, line, e))),
+    )
+}
+
+/// Parses each group of lines separated by blank lines with `FromStr`, given the group's lines
+/// joined by `\n`.
+pub fn parse_blocks<T>(input: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+{
+    parse_blocks_with(input, str::parse)
+}
+
+/// Parses each group of lines separated by blank lines with `parse`, given the group's lines
+/// joined by `\n`. A failure that names lines of the group, like one from `parse_lines` or
+/// `AocError::at_line`, points at those lines of the input; any other failure points at the
+/// group's first line.
+pub fn parse_blocks_with<T, E, F>(input: &str, mut parse: F) -> AocResult<Vec<T>>
+where
+    E: Into<AocError>,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    let mut values = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (first, lines) in blocks(input) {
+        match parse(&lines.join("\n")).map_err(Into::into) {
+            Ok(value) => values.push(value),
+            Err(AocError::Diagnostics(within)) => {
+                diagnostics.extend(within.into_iter().map(|diagnostic| Diagnostic {
+                    line: first + diagnostic.line - 1,
+                    ..diagnostic
+                }));
+            }
+            Err(AocError::Parse {
+                message,
+                line: Some(line),
+                ..
+            }) if (1..=lines.len()).contains(&line) => {
+                diagnostics.push(Diagnostic::for_line(
+                    first + line - 1,
+                    lines[line - 1],
+                    message,
+                ));
+            }
+            Err(e) => diagnostics.push(Diagnostic::for_line(first, lines[0], e)),
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(values)
+    } else {
+        Err(AocError::Diagnostics(diagnostics))
+    }
+}
+
+/// The groups of lines separated by blank lines, each with the number of its first line.
+fn blocks(input: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut blocks: Vec<(usize, Vec<&str>)> = Vec::new();
+    let mut in_block = false;
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            in_block = false;
+        } else if in_block {
+            blocks.last_mut().unwrap().1.push(line);
+        } else {
+            blocks.push((index + 1, vec![line]));
+            in_block = true;
+        }
+    }
+    blocks
+}
+
+/// Parses each line as comma-separated values, pointing at each field that fails.
+pub fn parse_csv<T>(input: &str) -> AocResult<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut diagnostics = Vec::new();
+    let mut rows = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let mut row = Vec::new();
+        for field in line.split(',') {
+            match field.trim().parse() {
+                Ok(value) => row.push(value),
+                Err(e) => diagnostics.push(Diagnostic::new(index + 1, line, field.trim(), e)),
+            }
+        }
+        rows.push(row);
+    }
+
+    if diagnostics.is_empty() {
+        Ok(rows)
+    } else {
+        Err(AocError::Diagnostics(diagnostics))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(error: AocError) -> Vec<Diagnostic> {
+        match error {
+            AocError::Diagnostics(diagnostics) => diagnostics,
+            other => panic!("Expected diagnostics, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(parse_lines::<i32>("1\n-2\n3\n").unwrap(), [1, -2, 3]);
+    }
+
+    #[test]
+    fn reports_every_bad_line() {
+        let error = parse_lines::<i32>("1\nx\n3\n  4y\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: invalid digit found in string\n \
+             2 | x\n   \
+               | ^\n\
+             Line 4, column 3: invalid digit found in string\n \
+             4 |   4y\n   \
+               |   ^^"
+        );
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let input = "1\n2\n\n\n3\n\n4\nx";
+        let sums = parse_blocks_with(input, |block| {
+            parse_lines::<i32>(block).map(|values| values.iter().sum::<i32>())
+        });
+
+        let diagnostics = diagnostics(sums.unwrap_err());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!((diagnostics[0].line, diagnostics[0].width), (8, 1));
+        assert_eq!(diagnostics[0].source, "x");
+
+        let blocks = parse_blocks_with(input.trim_end_matches("\nx"), |block| {
+            Ok::<_, AocError>(block.lines().count())
+        });
+        assert_eq!(blocks.unwrap(), [2, 1, 1]);
+    }
+
+    #[test]
+    fn points_at_the_start_of_blocks_for_other_errors() {
+        let error = parse_blocks_with("1\n2\n\n3\nx", |block| match block.contains('x') {
+            true => Err(AocError::invalid_input("no x allowed")),
+            false => Ok(()),
+        });
+
+        let diagnostics = diagnostics(error.unwrap_err());
+        assert_eq!(
+            (diagnostics[0].line, diagnostics[0].source.as_str()),
+            (4, "3")
+        );
+    }
+
+    #[test]
+    fn points_at_bad_csv_fields() {
+        assert_eq!(
+            parse_csv::<u8>("1,2\n3, 4").unwrap(),
+            [vec![1, 2], vec![3, 4]]
+        );
+
+        let diagnostics = diagnostics(parse_csv::<u8>("1,2\n3, 4x,300").unwrap_err());
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.line, d.column, d.width))
+                .collect::<Vec<_>>(),
+            [(2, 4, 2), (2, 7, 3)]
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "Line 2, column 7: number too large to fit in target type\n \
+             2 | 3, 4x,300\n   \
+               |       ^^^"
+        );
+    }
+
+    #[test]
+    fn elides_long_lists() {
+        let input = "x\n".repeat(12);
+        let error = parse_lines::<i32>(&input).unwrap_err().to_string();
+        assert!(error.ends_with("... and 2 more errors"), "{}", error);
+        assert_eq!(error.matches("Line ").count(), 10);
+    }
+}
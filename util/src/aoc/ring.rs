@@ -0,0 +1,395 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A ring of distinct `usize` values, stored as successor and predecessor arrays indexed by value.
+/// Since every value is its own node, finding a value is O(1), as are insertion and removal next
+/// to it. This suits games where the pieces are numbered `0..n`, like cups or marbles. Rotating
+/// by `n` walks `min(n, len - n)` links.
+#[derive(Clone, Debug)]
+pub struct SuccessorRing {
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    current: Option<usize>,
+    len: usize,
+}
+
+const ABSENT: usize = usize::MAX;
+
+impl SuccessorRing {
+    /// An empty ring that can hold the values `0..capacity`.
+    pub fn with_capacity(capacity: usize) -> SuccessorRing {
+        SuccessorRing {
+            next: vec![ABSENT; capacity],
+            prev: vec![ABSENT; capacity],
+            current: None,
+            len: 0,
+        }
+    }
+
+    /// A ring holding the given values in order, with the first as the current value. Values must
+    /// be distinct, and the ring can hold values up to the largest given (or `capacity - 1`).
+    pub fn new(values: impl IntoIterator<Item = usize>, capacity: usize) -> SuccessorRing {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let capacity = capacity.max(values.iter().max().map_or(0, |max| max + 1));
+
+        let mut ring = SuccessorRing::with_capacity(capacity);
+        let mut last = None;
+        for value in values {
+            match last {
+                None => ring.push_first(value),
+                Some(last) => ring.insert_after(last, value),
+            }
+            last = Some(value);
+        }
+
+        ring
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Makes `value`, which must already be in the ring, the current value.
+    pub fn set_current(&mut self, value: usize) {
+        assert!(self.contains(value), "{} isn't in the ring", value);
+        self.current = Some(value);
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.next.get(value).is_some_and(|&next| next != ABSENT)
+    }
+
+    pub fn next(&self, value: usize) -> usize {
+        self.next[value]
+    }
+
+    pub fn prev(&self, value: usize) -> usize {
+        self.prev[value]
+    }
+
+    /// Moves the current value `n` places clockwise, or anticlockwise if `n` is negative.
+    pub fn rotate(&mut self, n: isize) {
+        let mut current = match self.current {
+            Some(current) => current,
+            None => return,
+        };
+
+        let len = self.len as isize;
+        let forwards = n.rem_euclid(len);
+        if forwards <= len / 2 {
+            for _ in 0..forwards {
+                current = self.next[current];
+            }
+        } else {
+            for _ in 0..len - forwards {
+                current = self.prev[current];
+            }
+        }
+
+        self.current = Some(current);
+    }
+
+    /// Inserts a value into an empty ring, making it current.
+    pub fn push_first(&mut self, value: usize) {
+        assert!(self.is_empty(), "Ring already has values");
+        self.ensure_capacity(value);
+
+        self.next[value] = value;
+        self.prev[value] = value;
+        self.current = Some(value);
+        self.len = 1;
+    }
+
+    /// Inserts `value` clockwise of `after`.
+    pub fn insert_after(&mut self, after: usize, value: usize) {
+        assert!(self.contains(after), "{} isn't in the ring", after);
+        assert!(!self.contains(value), "{} is already in the ring", value);
+        self.ensure_capacity(value);
+
+        let next = self.next[after];
+        self.next[after] = value;
+        self.prev[value] = after;
+        self.next[value] = next;
+        self.prev[next] = value;
+        self.len += 1;
+    }
+
+    /// Removes and returns the value clockwise of `after`.
+    pub fn remove_after(&mut self, after: usize) -> usize {
+        let value = self.next[after];
+        self.remove(value);
+        value
+    }
+
+    /// Removes a value from the ring. If it was current, the value clockwise of it becomes current.
+    pub fn remove(&mut self, value: usize) {
+        assert!(self.contains(value), "{} isn't in the ring", value);
+
+        let next = self.next[value];
+        let prev = self.prev[value];
+        self.next[prev] = next;
+        self.prev[next] = prev;
+        self.next[value] = ABSENT;
+        self.prev[value] = ABSENT;
+        self.len -= 1;
+
+        if self.current == Some(value) {
+            self.current = if self.len == 0 { None } else { Some(next) };
+        }
+    }
+
+    /// Iterates once around the ring, clockwise from `start`.
+    pub fn iter_from(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut value = start;
+        (0..self.len).map(move |_| {
+            let current = value;
+            value = self.next[value];
+            current
+        })
+    }
+
+    fn ensure_capacity(&mut self, value: usize) {
+        if value >= self.next.len() {
+            self.next.resize(value + 1, ABSENT);
+            self.prev.resize(value + 1, ABSENT);
+        }
+    }
+}
+
+/// A ring of distinct values backed by a `VecDeque`, with the current value kept at the front.
+/// Rotating moves elements between the ends of the deque, and insertion or removal beside the
+/// current value only touches the front, so both are O(1), and rotating by `n` moves
+/// `min(n, len - n)` elements. Unlike `SuccessorRing` it can hold any hashable type.
+///
+/// Each value's position is kept in an index, counted from a point that only moves when the front
+/// of the deque does, so finding a value is O(1) too. Keeping it costs a hash update for every
+/// element moved, though, so for values numbered `0..n` `SuccessorRing` is much faster.
+#[derive(Clone, Debug, Default)]
+pub struct DequeRing<T> {
+    values: VecDeque<T>,
+    positions: HashMap<T, isize>,
+    /// The position of the value at the front of `values`.
+    front: isize,
+}
+
+impl<T: Clone + Eq + Hash> DequeRing<T> {
+    pub fn new() -> DequeRing<T> {
+        DequeRing {
+            values: VecDeque::new(),
+            positions: HashMap::new(),
+            front: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.values.front()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.positions.contains_key(value)
+    }
+
+    /// How many places clockwise of the current value `value` is.
+    pub fn position(&self, value: &T) -> Option<usize> {
+        self.positions
+            .get(value)
+            .map(|position| (position - self.front) as usize)
+    }
+
+    /// Moves the current value `n` places clockwise, or anticlockwise if `n` is negative.
+    pub fn rotate(&mut self, n: isize) {
+        if self.values.is_empty() {
+            return;
+        }
+
+        let len = self.values.len();
+        let forwards = n.rem_euclid(len as isize) as usize;
+        if forwards <= len / 2 {
+            for _ in 0..forwards {
+                let value = self.values.pop_front().unwrap();
+                *self.positions.get_mut(&value).unwrap() = self.front + len as isize;
+                self.front += 1;
+                self.values.push_back(value);
+            }
+        } else {
+            for _ in 0..len - forwards {
+                let value = self.values.pop_back().unwrap();
+                self.front -= 1;
+                *self.positions.get_mut(&value).unwrap() = self.front;
+                self.values.push_front(value);
+            }
+        }
+    }
+
+    /// Inserts a value clockwise of the current one, or as the current value if the ring is empty.
+    /// The value must not already be in the ring.
+    pub fn insert_after(&mut self, value: T) {
+        assert!(!self.contains(&value), "The ring already holds this value");
+        match self.pop_front() {
+            Some(current) => {
+                self.push_front(value);
+                self.push_front(current);
+            }
+            None => self.push_front(value),
+        }
+    }
+
+    /// Removes and returns the value clockwise of the current one.
+    pub fn remove_after(&mut self) -> Option<T> {
+        if self.values.len() < 2 {
+            return None;
+        }
+        let current = self.pop_front()?;
+        let removed = self.pop_front();
+        self.push_front(current);
+        removed
+    }
+
+    /// Removes and returns the current value; the value clockwise of it becomes current.
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    /// Iterates once around the ring, clockwise from the current value.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    fn push_front(&mut self, value: T) {
+        self.front -= 1;
+        self.positions.insert(value.clone(), self.front);
+        self.values.push_front(value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        let position = self.front + self.values.len() as isize;
+        self.positions.insert(value.clone(), position);
+        self.values.push_back(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        let value = self.values.pop_front()?;
+        self.positions.remove(&value);
+        self.front += 1;
+        Some(value)
+    }
+}
+
+impl<T: Clone + Eq + Hash> FromIterator<T> for DequeRing<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(it: I) -> DequeRing<T> {
+        let mut ring = DequeRing::new();
+        for value in it {
+            ring.push_back(value);
+        }
+        ring
+    }
+}
+
+/// The 2018 day 9 marble game played on each ring structure, shared with the `ring` benchmark.
+#[cfg(test)]
+#[path = "../../benches/marbles/mod.rs"]
+mod marbles;
+
+#[cfg(test)]
+use crate::aoc::linked_list::UsefulLinkedList;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn successor_ring_operations() {
+        let mut ring = SuccessorRing::new(vec![3, 8, 9, 1, 2, 5, 4, 6, 7], 0);
+
+        assert_eq!(Some(3), ring.current());
+        assert_eq!(8, ring.remove_after(3));
+        assert_eq!(9, ring.remove_after(3));
+        assert!(!ring.contains(8));
+
+        ring.insert_after(2, 8);
+        ring.insert_after(8, 9);
+        ring.rotate(1);
+
+        assert_eq!(Some(1), ring.current());
+        assert_eq!(
+            vec![3, 1, 2, 8, 9, 5, 4, 6, 7],
+            ring.iter_from(3).collect::<Vec<_>>()
+        );
+
+        ring.rotate(-3);
+        assert_eq!(Some(6), ring.current());
+        ring.rotate(18);
+        assert_eq!(Some(6), ring.current());
+
+        ring.remove(6);
+        assert_eq!(Some(7), ring.current());
+        assert_eq!(8, ring.len());
+    }
+
+    #[test]
+    fn successor_ring_grows() {
+        let mut ring = SuccessorRing::with_capacity(0);
+        ring.push_first(0);
+        ring.insert_after(0, 10);
+
+        assert_eq!(vec![10, 0], ring.iter_from(10).collect::<Vec<_>>());
+        assert_eq!(0, ring.next(10));
+        assert_eq!(10, ring.prev(0));
+    }
+
+    #[test]
+    fn deque_ring_operations() {
+        let mut ring: DequeRing<i32> = (0..5).collect();
+
+        ring.rotate(2);
+        assert_eq!(Some(&2), ring.current());
+        ring.insert_after(10);
+        assert_eq!(
+            vec![&2, &10, &3, &4, &0, &1],
+            ring.iter().collect::<Vec<_>>()
+        );
+
+        ring.rotate(-1);
+        assert_eq!(Some(&1), ring.current());
+        assert_eq!(Some(2), ring.remove_after());
+        assert_eq!(Some(1), ring.remove_current());
+        assert_eq!(Some(&10), ring.current());
+        assert_eq!(Some(3), ring.position(&0));
+        assert_eq!(None, ring.position(&2));
+
+        ring.rotate(-2);
+        assert_eq!(Some(&4), ring.current());
+        assert_eq!(Some(2), ring.position(&10));
+        assert_eq!(Some(0), ring.remove_after());
+        assert_eq!(None, ring.position(&0));
+        assert_eq!(Some(1), ring.position(&10));
+        assert_eq!(vec![&4, &10, &3], ring.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn marble_game() {
+        // 2018 day 9, where every ring should agree with the example high scores.
+        for (players, last_marble, expected) in [(9, 25, 32), (10, 1618, 8317), (13, 7999, 146373)]
+        {
+            assert_eq!(expected, marbles::linked_list(players, last_marble));
+            assert_eq!(expected, marbles::successor_ring(players, last_marble));
+            assert_eq!(expected, marbles::deque_ring(players, last_marble));
+        }
+    }
+}
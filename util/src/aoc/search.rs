@@ -0,0 +1,231 @@
+use num_traits::PrimInt;
+use std::ops::{Range, RangeInclusive};
+
+/// The smallest value in `range` for which `predicate` holds, given that it's false up to some
+/// point and true from then on.
+pub fn first_true<T, F>(range: Range<T>, mut predicate: F) -> Option<T>
+where
+    T: PrimInt,
+    F: FnMut(T) -> bool,
+{
+    let (mut lo, mut hi) = (range.start, range.end);
+    while lo < hi {
+        let mid = midpoint(lo, hi);
+        if predicate(mid) {
+            hi = mid;
+        } else {
+            lo = mid + T::one();
+        }
+    }
+
+    (lo < range.end).then_some(lo)
+}
+
+/// The largest value in `range` for which `predicate` holds, given that it's true up to some point
+/// and false from then on.
+pub fn last_true<T, F>(range: Range<T>, mut predicate: F) -> Option<T>
+where
+    T: PrimInt,
+    F: FnMut(T) -> bool,
+{
+    match first_true(range.clone(), |x| !predicate(x)) {
+        Some(first_false) if first_false == range.start => None,
+        Some(first_false) => Some(first_false - T::one()),
+        None if range.is_empty() => None,
+        None => Some(range.end - T::one()),
+    }
+}
+
+/// Like `first_true`, but with no known upper bound. Probes `start + 1`, `start + 2`,
+/// `start + 4`, ... until `predicate` holds, then binary searches the last gap. Once the probes
+/// would overflow, the rest of the way up to `T::max_value()` is searched instead, returning
+/// `None` if `predicate` never holds.
+pub fn first_true_from<T, F>(start: T, mut predicate: F) -> Option<T>
+where
+    T: PrimInt,
+    F: FnMut(T) -> bool,
+{
+    if predicate(start) {
+        return Some(start);
+    }
+
+    let mut below = start;
+    let mut step = T::one();
+    while let Some(probe) = start.checked_add(&step) {
+        if predicate(probe) {
+            return first_true(below + T::one()..probe, &mut predicate).or(Some(probe));
+        }
+        below = probe;
+        match step.checked_mul(&two()) {
+            Some(next) => step = next,
+            None => break,
+        }
+    }
+
+    let max = T::max_value();
+    if below == max || !predicate(max) {
+        return None;
+    }
+    first_true(below + T::one()..max, &mut predicate).or(Some(max))
+}
+
+/// Like `last_true`, but with no known upper bound: the largest value from `start` onwards for
+/// which `predicate` holds.
+pub fn last_true_from<T, F>(start: T, mut predicate: F) -> Option<T>
+where
+    T: PrimInt,
+    F: FnMut(T) -> bool,
+{
+    if !predicate(start) {
+        return None;
+    }
+    match first_true_from(start, |x| !predicate(x)) {
+        Some(first_false) => Some(first_false - T::one()),
+        None => Some(T::max_value()),
+    }
+}
+
+/// The value in `range` minimising a unimodal `cost`, which falls and then rises, along with that
+/// cost. Each round discards a third of the range, so `cost` is called `O(log n)` times.
+pub fn ternary_search<T, C, F>(range: RangeInclusive<T>, mut cost: F) -> Option<(T, C)>
+where
+    T: PrimInt,
+    C: Ord,
+    F: FnMut(T) -> C,
+{
+    let (mut lo, mut hi) = range.into_inner();
+    if lo > hi {
+        return None;
+    }
+
+    let three = T::from(3).unwrap();
+    // `hi - lo` overflows when a signed range spans more than half the type, so it's always wide
+    // enough to split then.
+    while hi.checked_sub(&lo).is_none_or(|width| width >= three) {
+        let third = third_of(lo, hi);
+        let (m1, m2) = (lo + third, hi - third);
+        if cost(m1) < cost(m2) {
+            hi = m2 - T::one();
+        } else {
+            lo = m1 + T::one();
+        }
+    }
+
+    let mut best = (lo, cost(lo));
+    let mut x = lo;
+    while x < hi {
+        x = x + T::one();
+        let c = cost(x);
+        if c < best.1 {
+            best = (x, c);
+        }
+    }
+    Some(best)
+}
+
+fn two<T: PrimInt>() -> T {
+    T::one() + T::one()
+}
+
+/// `(lo + hi) / 2` rounded down, for `lo <= hi`, without overflowing even when `hi - lo` would.
+fn midpoint<T: PrimInt>(lo: T, hi: T) -> T {
+    (lo & hi) + ((lo ^ hi) >> 1)
+}
+
+/// Roughly a third of `hi - lo`, for `lo <= hi`, without overflowing even when `hi - lo` would.
+fn third_of<T: PrimInt>(lo: T, hi: T) -> T {
+    let three = T::from(3).unwrap();
+    match hi.checked_sub(&lo) {
+        Some(width) => width / three,
+        None => {
+            let half = midpoint(lo, hi) - lo;
+            half / three * two() + half % three * two() / three
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_searches() {
+        let squares_over_50 = |x: i32| x * x > 50;
+
+        assert_eq!(Some(8), first_true(0..100, squares_over_50));
+        assert_eq!(Some(7), last_true(0..100, |x| !squares_over_50(x)));
+        assert_eq!(None, first_true(0..5, squares_over_50));
+        assert_eq!(Some(4), last_true(0..5, |x| !squares_over_50(x)));
+        assert_eq!(None, last_true(10..20, |x| !squares_over_50(x)));
+        assert_eq!(None, first_true(5..5, |_| true));
+        assert_eq!(Some(u8::MAX - 1), last_true(0..u8::MAX, |_| true));
+    }
+
+    #[test]
+    fn searches_ranges_wider_than_the_type_holds() {
+        assert_eq!(Some(7), first_true(i32::MIN..i32::MAX, |x| x >= 7));
+        assert_eq!(Some(i8::MIN), first_true(i8::MIN..i8::MAX, |_| true));
+        assert_eq!(Some(-3), last_true(i64::MIN..i64::MAX, |x| x <= -3));
+        assert_eq!(
+            Some((5, 0)),
+            ternary_search(i64::MIN..=i64::MAX, |x| (x as i128 - 5).abs())
+        );
+        assert_eq!(
+            Some((i8::MIN, -128)),
+            ternary_search(i8::MIN..=i8::MAX, |x| x as i32)
+        );
+    }
+
+    #[test]
+    fn unbounded_searches() {
+        // 2019 day 14: the most fuel a trillion ore makes, at 13312 ore per fuel with no
+        // leftovers to reuse.
+        let ore = |fuel: i64| fuel * 13312;
+        assert_eq!(
+            Some(1_000_000_000_000 / 13312),
+            last_true_from(0, |fuel| ore(fuel) <= 1_000_000_000_000)
+        );
+
+        assert_eq!(Some(1_000_001), first_true_from(-5, |x| x > 1_000_000));
+        assert_eq!(Some(3), first_true_from(3, |_| true));
+        assert_eq!(None, first_true_from(0u8, |_| false));
+        assert_eq!(Some(u16::MAX), last_true_from(0u16, |_| true));
+        assert_eq!(None, last_true_from(0, |x: i32| x < 0));
+    }
+
+    #[test]
+    fn unbounded_searches_reach_the_top_of_the_type() {
+        assert_eq!(Some(200), first_true_from(0u8, |x| x >= 200));
+        assert_eq!(Some(199), last_true_from(0u8, |x| x < 200));
+        assert_eq!(Some(u8::MAX), first_true_from(0u8, |x| x == u8::MAX));
+        assert_eq!(Some(u8::MAX - 1), last_true_from(0u8, |x| x < u8::MAX));
+        assert_eq!(
+            Some(i32::MAX),
+            first_true_from(i32::MAX - 1, |x| x == i32::MAX)
+        );
+        assert_eq!(None, first_true_from(u64::MAX, |_| false));
+    }
+
+    #[test]
+    fn ternary() {
+        // 2021 day 7: crabs moving with increasing fuel costs.
+        let crabs = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cost = |position: i32| {
+            crabs
+                .iter()
+                .map(|crab| {
+                    let n = (position - crab).abs();
+                    n * (n + 1) / 2
+                })
+                .sum::<i32>()
+        };
+
+        assert_eq!(Some((5, 168)), ternary_search(0..=16, cost));
+        assert_eq!(
+            Some((-3, 0)),
+            ternary_search(-10..=10, |x: i64| (x + 3).abs())
+        );
+        assert_eq!(Some((7, 0)), ternary_search(7..=7, |_| 0));
+        assert_eq!(None, ternary_search(RangeInclusive::new(1, 0), |x: i32| x));
+    }
+}
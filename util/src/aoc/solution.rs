@@ -0,0 +1,217 @@
+use crate::aoc::bench::{measure, BenchConfig, Measurement};
+use crate::aoc::{fetch, time, AocResult};
+use crate::bail;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input.
+///
+/// ```ignore
+/// pub struct Day01;
+///
+/// impl Solution for Day01 {
+///     const YEAR: u32 = 2024;
+///     const DAY: u32 = 1;
+///
+///     type Input = Vec<i32>;
+///     type Output1 = i32;
+///     type Output2 = i32;
+///
+///     fn parse(input: &str) -> AocResult<Vec<i32>> { ... }
+///     fn part1(input: &Vec<i32>) -> AocResult<i32> { ... }
+///     fn part2(input: &Vec<i32>) -> AocResult<i32> { ... }
+/// }
+/// ```
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    /// The parts that have been solved.
+    const PARTS: &'static [u32] = &[1, 2];
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+
+    fn part1(input: &Self::Input) -> AocResult<Self::Output1>;
+
+    fn part2(input: &Self::Input) -> AocResult<Self::Output2>;
+}
+
+/// One day of one year, as dispatched by the `aoc` runner.
+pub trait Day {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    /// The parts `run` can be asked for.
+    fn parts(&self) -> Vec<u32> {
+        vec![1, 2]
+    }
+
+    /// Runs `part`, or every part when it's `None`, printing each answer as `result` does.
+    fn run(&self, part: Option<u32>) -> AocResult<()>;
+
+    /// Times parsing and each part repeatedly, without printing answers.
+    fn bench(&self, config: &BenchConfig) -> AocResult<Vec<Measurement>>;
+
+    /// Solves one part for the given input rather than the puzzle input, returning the answer as
+    /// `run` would print it.
+    fn solve(&self, input: &str, part: u32) -> AocResult<String>;
+}
+
+/// Finds a day among a list of them.
+pub fn find(days: &[Box<dyn Day>], year: u32, day: u32) -> Option<&dyn Day> {
+    days.iter()
+        .find(|candidate| candidate.year() == year && candidate.day() == day)
+        .map(|candidate| candidate.as_ref())
+}
+
+/// Runs a `Solution`, parsing the puzzle input found by `fetch::load_or_fetch` once for both
+/// parts.
+pub struct SolutionDay<S> {
+    solution: PhantomData<S>,
+}
+
+impl<S: Solution> SolutionDay<S> {
+    pub fn new() -> SolutionDay<S> {
+        SolutionDay {
+            solution: PhantomData,
+        }
+    }
+}
+
+impl<S: Solution> Default for SolutionDay<S> {
+    fn default() -> Self {
+        SolutionDay::new()
+    }
+}
+
+impl<S: Solution> Day for SolutionDay<S> {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> Vec<u32> {
+        S::PARTS.to_vec()
+    }
+
+    fn run(&self, part: Option<u32>) -> AocResult<()> {
+        if let Some(part) = part {
+            if !S::PARTS.contains(&part) {
+                bail!("{} day {} has no part {}", S::YEAR, S::DAY, part);
+            }
+        }
+
+        let input = fetch::load_or_fetch(S::YEAR, S::DAY)?;
+        let input = time("Parse", || S::parse(&input))?;
+        for &n in S::PARTS {
+            if part.is_some_and(|part| part != n) {
+                continue;
+            }
+            let answer = time(format!("Part {}", n), || answer::<S>(&input, n))?;
+            println!(" => {}", answer);
+        }
+
+        Ok(())
+    }
+
+    fn bench(&self, config: &BenchConfig) -> AocResult<Vec<Measurement>> {
+        let text = fetch::load_or_fetch(S::YEAR, S::DAY)?;
+        let input = S::parse(&text)?;
+        for &n in S::PARTS {
+            answer::<S>(&input, n)?;
+        }
+
+        let mut measurements = vec![measure("Parse", config, || S::parse(&text))];
+        for &n in S::PARTS {
+            measurements.push(match n {
+                1 => measure("Part 1", config, || S::part1(&input)),
+                _ => measure("Part 2", config, || S::part2(&input)),
+            });
+        }
+        Ok(measurements)
+    }
+
+    fn solve(&self, input: &str, part: u32) -> AocResult<String> {
+        if !S::PARTS.contains(&part) {
+            bail!("{} day {} has no part {}", S::YEAR, S::DAY, part);
+        }
+        answer::<S>(&S::parse(input)?, part)
+    }
+}
+
+/// Solves one part of a parsed input, as it's printed.
+fn answer<S: Solution>(input: &S::Input, part: u32) -> AocResult<String> {
+    match part {
+        1 => Ok(S::part1(input)?.to_string()),
+        2 => Ok(S::part2(input)?.to_string()),
+        _ => bail!("{} day {} has no part {}", S::YEAR, S::DAY, part),
+    }
+}
+
+/// Lists a crate's `Solution`s as `Day`s, given the type implementing each:
+///
+/// ```ignore
+/// pub fn solutions() -> Vec<Box<dyn Day>> {
+///     solution_days![day01::Day01, day02::Day02]
+/// }
+/// ```
+#[macro_export]
+macro_rules! solution_days {
+    ($($solution:ty),* $(,)?) => {
+        vec![$(
+            Box::new($crate::aoc::solution::SolutionDay::<$solution>::new())
+                as Box<dyn $crate::aoc::solution::Day>
+        ),*]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 3;
+
+        type Input = Vec<i64>;
+        type Output1 = i64;
+        type Output2 = usize;
+
+        fn parse(input: &str) -> AocResult<Vec<i64>> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Vec<i64>) -> AocResult<i64> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Vec<i64>) -> AocResult<usize> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn registered_solutions_are_found_by_year_and_day() {
+        let days: Vec<Box<dyn Day>> = crate::solution_days![Sum];
+
+        let day = find(&days, 2015, 3).unwrap();
+        assert_eq!((day.year(), day.day(), day.parts()), (2015, 3, vec![1, 2]));
+        assert!(find(&days, 2015, 4).is_none());
+    }
+
+    #[test]
+    fn parse_errors_propagate() {
+        assert!(Sum::parse("1\nx").is_err());
+        assert_eq!(Sum::part1(&Sum::parse("1\n2").unwrap()).unwrap(), 3);
+    }
+}
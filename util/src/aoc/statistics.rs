@@ -0,0 +1,225 @@
+use crate::aoc::frequency::FrequencyMap;
+use noisy_float::prelude::*;
+use num_traits::ToPrimitive;
+use stats::{MinMax, OnlineStats};
+use std::hash::Hash;
+
+/// The count, mean, population variance and range of a sequence, gathered in one pass.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: R64,
+    pub variance: R64,
+    pub min: R64,
+    pub max: R64,
+}
+
+impl Summary {
+    pub fn stddev(&self) -> R64 {
+        self.variance.sqrt()
+    }
+}
+
+/// Statistics over an iterator. The numeric ones convert each item to a finite `f64` and skip the
+/// items that don't convert, like NaN or infinity, so they don't count towards the result.
+pub trait Statistics: Iterator {
+    /// The arithmetic mean, in constant space.
+    fn mean(self) -> Option<R64>
+    where
+        Self: Sized,
+        Self::Item: ToPrimitive,
+    {
+        self.summary().map(|summary| summary.mean)
+    }
+
+    /// The population variance, in constant space.
+    fn variance(self) -> Option<R64>
+    where
+        Self: Sized,
+        Self::Item: ToPrimitive,
+    {
+        self.summary().map(|summary| summary.variance)
+    }
+
+    /// The count, mean, variance, min and max, in constant space.
+    fn summary(self) -> Option<Summary>
+    where
+        Self: Sized,
+        Self::Item: ToPrimitive,
+    {
+        let mut online = OnlineStats::new();
+        let mut min_max = MinMax::new();
+        self.filter_map(|item| to_r64(&item)).for_each(|value| {
+            online.add(value.raw());
+            min_max.add(value);
+        });
+
+        match (min_max.min(), min_max.max()) {
+            (Some(&min), Some(&max)) => Some(Summary {
+                count: online.len(),
+                mean: r64(online.mean()),
+                variance: r64(online.variance()),
+                min,
+                max,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The middle value, or the mean of the two middle values for an even count.
+    fn median(self) -> Option<R64>
+    where
+        Self: Sized,
+        Self::Item: ToPrimitive,
+    {
+        self.percentile(50.0)
+    }
+
+    /// The `p`th percentile for `p` in `0..=100`, interpolating linearly between the closest
+    /// ranks. Needs space for the whole sequence.
+    fn percentile(self, p: f64) -> Option<R64>
+    where
+        Self: Sized,
+        Self::Item: ToPrimitive,
+    {
+        assert!((0.0..=100.0).contains(&p), "Percentile {} out of range", p);
+
+        let mut values = self.filter_map(|item| to_r64(&item)).collect::<Vec<_>>();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable();
+
+        let rank = p / 100.0 * (values.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let fraction = r64(rank - lower as f64);
+        Some(values[lower] + (values[upper] - values[lower]) * fraction)
+    }
+
+    /// The most frequent item, with ties going to the smallest.
+    fn mode(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash + Ord + Clone,
+    {
+        let frequency: FrequencyMap<Self::Item> = self.collect();
+        frequency
+            .most_common(1)
+            .first()
+            .map(|(item, _)| (*item).clone())
+    }
+
+    /// Yields each item alongside the smallest and largest items seen so far, itself included.
+    fn running_min_max(self) -> RunningMinMax<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialOrd + Clone,
+    {
+        RunningMinMax {
+            iter: self,
+            min_max: MinMax::new(),
+        }
+    }
+}
+
+impl<T: ?Sized> Statistics for T where T: Iterator {}
+
+pub struct RunningMinMax<I: Iterator> {
+    iter: I,
+    min_max: MinMax<I::Item>,
+}
+
+impl<I> Iterator for RunningMinMax<I>
+where
+    I: Iterator,
+    I::Item: PartialOrd + Clone,
+{
+    type Item = (I::Item, I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.min_max.add(item.clone());
+
+        let min = self.min_max.min().unwrap().clone();
+        let max = self.min_max.max().unwrap().clone();
+        Some((item, min, max))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// The value as an `R64`, or `None` if it's NaN, infinite or has no `f64` representation.
+fn to_r64<T: ToPrimitive>(value: &T) -> Option<R64> {
+    value.to_f64().and_then(R64::try_new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary() {
+        let summary = vec![2, 4, 4, 4, 5, 5, 7, 9].into_iter().summary().unwrap();
+
+        assert_eq!(8, summary.count);
+        assert_eq!(r64(5.0), summary.mean);
+        assert_eq!(r64(4.0), summary.variance);
+        assert_eq!(r64(2.0), summary.stddev());
+        assert_eq!(r64(2.0), summary.min);
+        assert_eq!(r64(9.0), summary.max);
+        assert_eq!(None, Vec::<i32>::new().into_iter().summary());
+    }
+
+    #[test]
+    fn median_and_percentiles() {
+        // 2021 day 7: the cheapest position for constant fuel costs is the median.
+        let crabs = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+        assert_eq!(Some(r64(2.0)), crabs.iter().copied().median());
+        assert_eq!(Some(r64(4.9)), crabs.iter().copied().mean());
+        assert_eq!(Some(r64(2.5)), vec![1, 2, 3, 4].into_iter().median());
+        assert_eq!(Some(r64(0.0)), crabs.iter().copied().percentile(0.0));
+        assert_eq!(Some(r64(16.0)), crabs.iter().copied().percentile(100.0));
+        assert_eq!(
+            Some(r64(1.75)),
+            vec![1, 2, 3, 4].into_iter().percentile(25.0)
+        );
+    }
+
+    #[test]
+    fn skips_values_that_arent_finite() {
+        let values = [1.0, f64::NAN, 3.0, f64::INFINITY];
+
+        let summary = values.iter().copied().summary().unwrap();
+        assert_eq!(2, summary.count);
+        assert_eq!(r64(2.0), summary.mean);
+        assert_eq!(r64(3.0), summary.max);
+        assert_eq!(Some(r64(2.0)), values.iter().copied().median());
+        assert_eq!(None, vec![f64::NAN].into_iter().mean());
+    }
+
+    #[test]
+    fn mode() {
+        assert_eq!(
+            Some(2),
+            vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14].into_iter().mode()
+        );
+        assert_eq!(Some('a'), "abab".chars().mode());
+        assert_eq!(None, "".chars().mode());
+    }
+
+    #[test]
+    fn running_min_max() {
+        let running = vec![3, 1, 4, 1, 5]
+            .into_iter()
+            .running_min_max()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![(3, 3, 3), (1, 1, 3), (4, 1, 4), (1, 1, 4), (5, 1, 5)],
+            running
+        );
+    }
+}
@@ -1,33 +1,132 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use std::cmp::Ordering;
 
+/// Streaming selection of the best `k` items from an iterator, in `O(k)` space. Results are sorted
+/// best first, and items that compare equal keep the order they arrived in.
 pub trait TopK: Iterator {
-    /// Calculates a rolling Top-K set of items from an iterator in constant space.
-    /// Returns a sorted vector of the top-k elements, sorted descending.
+    /// The `k` largest items, sorted descending.
     fn topk(self, k: usize) -> Vec<Self::Item>
     where
         Self: Sized,
         Self::Item: Ord,
     {
-        let mut heap = BinaryHeap::<Reverse<Self::Item>>::with_capacity(k + 1);
+        select(self, k, false, Ord::cmp)
+    }
 
-        self.for_each(|item| {
-            heap.push(Reverse(item));
+    /// The `k` largest items according to `cmp`, sorted descending.
+    fn topk_by<F>(self, k: usize, cmp: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        select(self, k, false, cmp)
+    }
 
-            if heap.len() == k + 1 {
-                heap.pop();
-            }
-        });
+    /// The `k` items with the largest keys, sorted descending by key.
+    fn topk_by_key<K, F>(self, k: usize, mut key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        select(self, k, false, |a, b| key(a).cmp(&key(b)))
+    }
 
-        let mut vec: Vec<Self::Item> =
-            std::iter::from_fn(move || heap.pop().map(|item| item.0)).collect();
-        vec.reverse();
-        vec
+    /// The `k` smallest items, sorted ascending.
+    fn bottomk(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        select(self, k, false, |a, b| b.cmp(a))
+    }
+
+    /// The `k` smallest items according to `cmp`, sorted ascending.
+    fn bottomk_by<F>(self, k: usize, mut cmp: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        select(self, k, false, |a, b| cmp(b, a))
+    }
+
+    /// The `k` items with the smallest keys, sorted ascending by key.
+    fn bottomk_by_key<K, F>(self, k: usize, mut key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        select(self, k, false, |a, b| key(b).cmp(&key(a)))
+    }
+
+    /// The `k` largest items, sorted descending, plus any others equal to the `k`th. Space grows
+    /// with the number of tied items as well as with `k`.
+    fn topk_with_ties(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        select(self, k, true, Ord::cmp)
     }
 }
 
 impl<T: ?Sized> TopK for T where T: Iterator {}
 
+/// Buffers items, tagged with their arrival order, and whenever the buffer doubles in size cuts it
+/// back down to the best `k`, so each item costs amortised constant time plus the final sort.
+fn select<I, F>(items: I, k: usize, ties: bool, mut cmp: F) -> Vec<I::Item>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut buffer = Vec::with_capacity(2 * k);
+    let mut limit = 2 * k;
+    for item in items.enumerate() {
+        buffer.push(item);
+        if buffer.len() >= limit {
+            keep_best(&mut buffer, k, ties, &mut cmp);
+            limit = 2 * buffer.len().max(k);
+        }
+    }
+
+    keep_best(&mut buffer, k, ties, &mut cmp);
+    buffer.sort_unstable_by(|a, b| best_first(&mut cmp, a, b));
+    buffer.into_iter().map(|(_, item)| item).collect()
+}
+
+fn keep_best<T, F>(buffer: &mut Vec<(usize, T)>, k: usize, ties: bool, cmp: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if buffer.len() <= k {
+        return;
+    }
+
+    buffer.select_nth_unstable_by(k - 1, |a, b| best_first(cmp, a, b));
+
+    let mut kept = k;
+    if ties {
+        for i in k..buffer.len() {
+            if cmp(&buffer[i].1, &buffer[k - 1].1) == Ordering::Equal {
+                buffer.swap(kept, i);
+                kept += 1;
+            }
+        }
+    }
+    buffer.truncate(kept);
+}
+
+fn best_first<T, F>(cmp: &mut F, a: &(usize, T), b: &(usize, T)) -> Ordering
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    cmp(&b.1, &a.1).then(a.0.cmp(&b.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::TopK;
@@ -35,7 +134,7 @@ mod tests {
     #[test]
     fn it_works() {
         let seq = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-        let expected_vec = vec![10, 9, 8, 7, 6];
+        let expected_vec = [10, 9, 8, 7, 6];
         let expected: Vec<&i32> = expected_vec.iter().collect();
         let actual: Vec<&i32> = seq.iter().topk(5);
 
@@ -48,7 +147,7 @@ mod tests {
 
         seq.reverse();
 
-        let expected_vec = vec![10, 9, 8, 7, 6];
+        let expected_vec = [10, 9, 8, 7, 6];
         let expected: Vec<&i32> = expected_vec.iter().collect();
         let actual: Vec<&i32> = seq.iter().topk(5);
 
@@ -61,10 +160,71 @@ mod tests {
 
         seq.reverse();
 
-        let expected_vec = vec![10, 9, 8, 7, 6];
+        let expected_vec = [10, 9, 8, 7, 6];
         let expected: Vec<&i32> = expected_vec.iter().collect();
         let actual: Vec<&i32> = seq.iter().topk(5);
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn custom_orderings() {
+        let words = ["pear", "fig", "banana", "kiwi", "apple", "date"];
+
+        assert_eq!(
+            vec!["banana", "apple"],
+            words.iter().copied().topk_by_key(2, |w| w.len())
+        );
+        assert_eq!(
+            vec!["fig", "pear", "kiwi"],
+            words.iter().copied().bottomk_by_key(3, |w| w.len())
+        );
+        assert_eq!(
+            vec!["apple", "banana"],
+            words.iter().copied().bottomk_by(2, |a, b| a.cmp(b))
+        );
+        assert_eq!(
+            vec!["pear", "kiwi"],
+            words.iter().copied().topk_by(2, |a, b| a.cmp(b))
+        );
+    }
+
+    #[test]
+    fn ties_keep_arrival_order() {
+        let words = ["pear", "fig", "kiwi", "date", "plum", "lime"];
+
+        assert_eq!(
+            vec!["pear", "kiwi"],
+            words.iter().copied().topk_by_key(2, |w| w.len())
+        );
+        assert_eq!(
+            vec!["fig", "pear", "kiwi", "date"],
+            words.iter().copied().bottomk_by_key(4, |w| w.len())
+        );
+    }
+
+    #[test]
+    fn bottomk() {
+        let seq = [5, 3, 9, 1, 7, 3];
+
+        assert_eq!(vec![1, 3, 3], seq.iter().copied().bottomk(3));
+        assert_eq!(seq.len(), seq.iter().bottomk(10).len());
+        assert!(seq.iter().bottomk(0).is_empty());
+    }
+
+    #[test]
+    fn with_ties() {
+        let seq = vec![4, 9, 7, 9, 7, 1, 7, 2, 7];
+
+        assert_eq!(
+            vec![9, 9, 7, 7, 7, 7],
+            seq.iter().copied().topk_with_ties(3)
+        );
+        assert_eq!(vec![9, 9], seq.iter().copied().topk_with_ties(2));
+        assert_eq!(vec![9, 9], seq.iter().copied().topk_with_ties(1));
+
+        // Enough items to force the buffer to be cut back several times.
+        let many = (0..1000).map(|i| i % 10).collect::<Vec<_>>();
+        assert_eq!(vec![9; 100], many.iter().copied().topk_with_ties(5));
+    }
 }